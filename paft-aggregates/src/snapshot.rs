@@ -8,7 +8,9 @@
 use chrono::{DateTime, Utc};
 #[cfg(feature = "dataframe")]
 use df_derive_macros::ToDataFrame;
-use paft_domain::{Instrument, MarketState};
+use paft_domain::{
+    FieldMerger, Instrument, MarketState, MergeError, MergePolicy, MergeSource, Merged,
+};
 use paft_money::{Currency, PriceAmount, QuantityAmount};
 use serde::{Deserialize, Serialize};
 
@@ -80,5 +82,45 @@ impl<M: Default> GenericSnapshot<M> {
     }
 }
 
+impl<M: Clone> GenericSnapshot<M> {
+    /// Merge partial snapshots for one instrument from several sources.
+    ///
+    /// Every optional field is taken from the highest-ranked source that
+    /// populates it under `policy`; `instrument` and `provider` come from the
+    /// highest-ranked source. The returned [`Merged::sources`] records which
+    /// source supplied each field.
+    ///
+    /// # Errors
+    /// Returns [`MergeError`] when `sources` is empty or has duplicate names,
+    /// when the sources disagree on [`Instrument::unique_key`] or on
+    /// `currency`, or when `policy` names a field a snapshot does not have.
+    pub fn merge(
+        sources: &[MergeSource<'_, Self>],
+        policy: &MergePolicy,
+    ) -> Result<Merged<Self>, MergeError> {
+        let mut merger = FieldMerger::new(sources, policy, |snapshot| snapshot.as_of)?;
+        merger.ensure_same_instrument(|snapshot| &snapshot.instrument)?;
+        merger.ensure_same_currency(None, |snapshot| Some(&snapshot.currency))?;
+
+        let record = Self {
+            instrument: merger.required("instrument", |snapshot| &snapshot.instrument),
+            name: merger.field("name", |snapshot| snapshot.name.as_ref()),
+            market_state: merger.field("market_state", |snapshot| snapshot.market_state.as_ref()),
+            as_of: merger.field("as_of", |snapshot| snapshot.as_of.as_ref()),
+            currency: merger.required("currency", |snapshot| &snapshot.currency),
+            last: merger.field("last", |snapshot| snapshot.last.as_ref()),
+            previous_close: merger.field("previous_close", |snapshot| {
+                snapshot.previous_close.as_ref()
+            }),
+            open: merger.field("open", |snapshot| snapshot.open.as_ref()),
+            day_high: merger.field("day_high", |snapshot| snapshot.day_high.as_ref()),
+            day_low: merger.field("day_low", |snapshot| snapshot.day_low.as_ref()),
+            volume: merger.field("volume", |snapshot| snapshot.volume.as_ref()),
+            provider: merger.required("provider", |snapshot| &snapshot.provider),
+        };
+        merger.finish(record)
+    }
+}
+
 /// Standard `Snapshot` with no extra provider metadata.
 pub type Snapshot = GenericSnapshot<()>;
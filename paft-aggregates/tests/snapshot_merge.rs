@@ -0,0 +1,71 @@
+use chrono::{TimeZone, Utc};
+use paft_aggregates::Snapshot;
+use paft_decimal::Decimal;
+use paft_domain::{AssetKind, Instrument, MarketState, MergeError, MergePolicy, MergeSource};
+use paft_money::{Currency, IsoCurrency, PriceAmount};
+use pretty_assertions::assert_eq;
+
+const fn usd() -> Currency {
+    Currency::Iso(IsoCurrency::USD)
+}
+
+fn amount(value: i64) -> PriceAmount {
+    PriceAmount::new(Decimal::from(value))
+}
+
+fn snapshot() -> Snapshot {
+    Snapshot::new(
+        Instrument::from_symbol("AAPL", AssetKind::Equity).unwrap(),
+        usd(),
+    )
+}
+
+#[test]
+fn snapshot_merge_takes_each_field_from_best_source() {
+    let mut a = snapshot();
+    a.name = Some("Apple Inc.".to_string());
+    a.market_state = Some(MarketState::Regular);
+    a.last = Some(amount(150));
+    a.as_of = Some(Utc.timestamp_opt(1_700_000_000, 0).unwrap());
+    let mut b = snapshot();
+    b.last = Some(amount(151));
+    b.open = Some(amount(149));
+    b.as_of = Some(Utc.timestamp_opt(1_700_000_060, 0).unwrap());
+    let sources = [MergeSource::new("a", &a), MergeSource::new("b", &b)];
+
+    let merged = Snapshot::merge(&sources, &MergePolicy::precedence()).unwrap();
+    let mut expected = a.clone();
+    expected.open = Some(amount(149));
+    assert_eq!(merged.record, expected);
+    assert_eq!(merged.source_of("open"), Some("b"));
+
+    let fresh = Snapshot::merge(&sources, &MergePolicy::freshness()).unwrap();
+    assert_eq!(fresh.record.last, Some(amount(151)));
+    assert_eq!(fresh.record.name.as_deref(), Some("Apple Inc."));
+    assert_eq!(fresh.source_of("last"), Some("b"));
+    assert_eq!(fresh.source_of("market_state"), Some("a"));
+}
+
+#[test]
+fn snapshot_merge_refuses_differing_identity() {
+    let a = snapshot();
+    let mut b = snapshot();
+    b.currency = Currency::Iso(IsoCurrency::GBP);
+    assert!(matches!(
+        Snapshot::merge(
+            &[MergeSource::new("a", &a), MergeSource::new("b", &b)],
+            &MergePolicy::precedence(),
+        ),
+        Err(MergeError::CurrencyMismatch { field: None, .. })
+    ));
+
+    let mut c = snapshot();
+    c.instrument = Instrument::from_symbol("AAPL", AssetKind::Crypto).unwrap();
+    assert!(matches!(
+        Snapshot::merge(
+            &[MergeSource::new("a", &a), MergeSource::new("c", &c)],
+            &MergePolicy::precedence(),
+        ),
+        Err(MergeError::InstrumentMismatch { .. })
+    ));
+}
@@ -2,7 +2,8 @@
 //!
 //! This crate defines strongly-typed primitives for instruments, exchanges,
 //! market sessions, security identifiers (`Symbol`, `Figi`, `Isin`), financial
-//! periods, and lookback horizons used across the paft ecosystem. Types are
+//! periods, and lookback horizons used across the paft ecosystem, plus the
+//! field-level [`merge`] machinery shared by multi-provider records. Types are
 //! designed to be:
 //! - Canonical and stable in string form (for serde, display, and storage)
 //! - Liberal in what they accept when parsing (aliases, case-insensitivity),
@@ -52,6 +53,7 @@ pub mod horizon;
 pub mod identifiers;
 pub mod instrument;
 pub mod market_state;
+pub mod merge;
 pub mod period;
 
 pub use error::DomainError;
@@ -60,6 +62,7 @@ pub use horizon::{Horizon, OtherHorizon};
 pub use identifiers::{Figi, Isin, Symbol};
 pub use instrument::{AssetKind, Instrument, OtherAssetKind};
 pub use market_state::{MarketState, OtherMarketState};
+pub use merge::{FieldMerger, MergeError, MergeOrder, MergePolicy, MergeSource, Merged};
 pub use period::{
     CalendarPeriod, OtherPeriod, PeriodDate, PeriodYear, QuarterOfYear, ReportingPeriod,
 };
@@ -0,0 +1,369 @@
+//! Field-level merging of partially populated records from several sources.
+//!
+//! Providers rarely populate the same subset of optional fields: one source may
+//! carry the display name and session state while another has a tighter top of
+//! book. [`FieldMerger`] ranks a set of named source records according to a
+//! [`MergePolicy`] and picks every field from the highest-ranked source that
+//! supplies it, recording the winning source per field in [`Merged::sources`].
+//!
+//! Record types such as quotes, snapshots, and key statistics expose `merge`
+//! constructors built on [`FieldMerger`]; provider crates can use it the same
+//! way for their own `Generic*` records.
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::Instrument;
+
+/// Errors produced while merging records from several sources.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MergeError {
+    /// No source records were supplied.
+    #[error("cannot merge an empty set of sources")]
+    NoSources,
+
+    /// Two sources share the same name, so provenance would be ambiguous.
+    #[error("duplicate merge source name: '{name}'")]
+    DuplicateSource {
+        /// The repeated source name.
+        name: String,
+    },
+
+    /// A source describes a different instrument than the highest-ranked one.
+    #[error("source '{source_name}' has instrument '{found}', expected '{expected}'")]
+    InstrumentMismatch {
+        /// Source whose record disagreed.
+        source_name: String,
+        /// `Instrument::unique_key` of the highest-ranked source.
+        expected: String,
+        /// `Instrument::unique_key` of the disagreeing source.
+        found: String,
+    },
+
+    /// A source reports a value in a different currency than the others.
+    ///
+    /// `field` is `None` when the record carries a single record-wide
+    /// currency and names the monetary field otherwise.
+    #[error("source '{source_name}' has currency '{found}', expected '{expected}'")]
+    CurrencyMismatch {
+        /// Monetary field that disagreed, if the check is per field.
+        field: Option<String>,
+        /// Source whose record disagreed.
+        source_name: String,
+        /// Currency of the highest-ranked source supplying the value.
+        expected: String,
+        /// Currency of the disagreeing source.
+        found: String,
+    },
+
+    /// The policy configures precedence for a field the record does not have.
+    #[error("merge policy names unknown field '{field}'")]
+    UnknownField {
+        /// The unrecognised field name.
+        field: String,
+    },
+}
+
+/// Default ranking of sources before per-field overrides are applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeOrder {
+    /// Sources rank in the order they are supplied; earlier wins.
+    #[default]
+    Precedence,
+    /// Sources with a more recent `as_of` rank first. Sources without an
+    /// `as_of` rank after all timestamped ones; ties keep the supplied order.
+    Freshness,
+}
+
+/// How [`FieldMerger`] ranks sources, globally and per field.
+///
+/// Field names are the record's serialized field names (for example
+/// `"previous_close"`). A per-field precedence list is tried first, in the
+/// given order; sources not listed follow in the default [`MergeOrder`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MergePolicy {
+    /// Default ranking of sources.
+    pub order: MergeOrder,
+    field_precedence: BTreeMap<String, Vec<String>>,
+}
+
+impl MergePolicy {
+    /// Policy ranking sources in the order they are supplied.
+    #[must_use]
+    pub const fn precedence() -> Self {
+        Self {
+            order: MergeOrder::Precedence,
+            field_precedence: BTreeMap::new(),
+        }
+    }
+
+    /// Policy ranking sources by their `as_of` timestamp, most recent first.
+    #[must_use]
+    pub const fn freshness() -> Self {
+        Self {
+            order: MergeOrder::Freshness,
+            field_precedence: BTreeMap::new(),
+        }
+    }
+
+    /// Prefer `sources`, in order, for `field`.
+    ///
+    /// Names that do not match a supplied source are ignored at merge time,
+    /// so one policy can be reused while some providers are unavailable.
+    #[must_use]
+    pub fn with_field_precedence<I, S>(mut self, field: impl Into<String>, sources: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.field_precedence
+            .insert(field.into(), sources.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Per-field precedence configured for `field`, if any.
+    #[must_use]
+    pub fn field_precedence(&self, field: &str) -> Option<&[String]> {
+        self.field_precedence.get(field).map(Vec::as_slice)
+    }
+}
+
+/// A record together with the name of the source that produced it.
+#[derive(Debug)]
+pub struct MergeSource<'a, T> {
+    /// Source name recorded as provenance (for example a provider id).
+    pub name: &'a str,
+    /// The (partially populated) record supplied by the source.
+    pub record: &'a T,
+}
+
+impl<'a, T> MergeSource<'a, T> {
+    /// Pair `record` with the source `name`.
+    #[must_use]
+    pub const fn new(name: &'a str, record: &'a T) -> Self {
+        Self { name, record }
+    }
+}
+
+impl<T> Clone for MergeSource<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for MergeSource<'_, T> {}
+
+/// A merged record with the source that supplied each populated field.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Merged<T> {
+    /// The merged record.
+    pub record: T,
+    /// Source name per populated field, keyed by serialized field name.
+    /// Fields that no source populated are absent.
+    pub sources: BTreeMap<String, String>,
+}
+
+impl<T> Merged<T> {
+    /// Name of the source that supplied `field`, if it was populated.
+    #[must_use]
+    pub fn source_of(&self, field: &str) -> Option<&str> {
+        self.sources.get(field).map(String::as_str)
+    }
+}
+
+/// Ranks named source records and picks fields from them one at a time.
+///
+/// Build it with [`FieldMerger::new`], run the identity checks that apply to
+/// the record type, pick every field with [`FieldMerger::field`] or
+/// [`FieldMerger::required`], and finish with [`FieldMerger::finish`].
+#[derive(Debug)]
+pub struct FieldMerger<'a, T> {
+    ranked: Vec<MergeSource<'a, T>>,
+    policy: &'a MergePolicy,
+    sources: BTreeMap<String, String>,
+    visited: Vec<&'static str>,
+}
+
+impl<'a, T> FieldMerger<'a, T> {
+    /// Rank `sources` according to `policy`, reading each record's
+    /// observation time through `as_of` for [`MergeOrder::Freshness`].
+    ///
+    /// # Errors
+    /// Returns [`MergeError::NoSources`] for an empty slice and
+    /// [`MergeError::DuplicateSource`] when two sources share a name.
+    pub fn new(
+        sources: &[MergeSource<'a, T>],
+        policy: &'a MergePolicy,
+        as_of: impl Fn(&T) -> Option<DateTime<Utc>>,
+    ) -> Result<Self, MergeError> {
+        if sources.is_empty() {
+            return Err(MergeError::NoSources);
+        }
+        for (index, source) in sources.iter().enumerate() {
+            if sources[..index].iter().any(|seen| seen.name == source.name) {
+                return Err(MergeError::DuplicateSource {
+                    name: source.name.to_string(),
+                });
+            }
+        }
+
+        let mut ranked = sources.to_vec();
+        if policy.order == MergeOrder::Freshness {
+            // Stable sort: `None` sorts before any `Some`, so reversing puts
+            // the most recent first and undated sources last.
+            ranked.sort_by_key(|source| Reverse(as_of(source.record)));
+        }
+
+        Ok(Self {
+            ranked,
+            policy,
+            sources: BTreeMap::new(),
+            visited: Vec::new(),
+        })
+    }
+
+    /// The highest-ranked source under the default order.
+    #[must_use]
+    pub fn primary(&self) -> MergeSource<'a, T> {
+        self.ranked[0]
+    }
+
+    /// All sources in default rank order.
+    #[must_use]
+    pub fn ranked(&self) -> &[MergeSource<'a, T>] {
+        &self.ranked
+    }
+
+    /// Require every source to describe the same instrument as the
+    /// highest-ranked one, compared by [`Instrument::unique_key`].
+    ///
+    /// # Errors
+    /// Returns [`MergeError::InstrumentMismatch`] for the first disagreeing
+    /// source in rank order.
+    pub fn ensure_same_instrument(
+        &self,
+        instrument: impl Fn(&T) -> &Instrument,
+    ) -> Result<(), MergeError> {
+        let expected = instrument(self.primary().record).unique_key();
+        for source in &self.ranked[1..] {
+            let found = instrument(source.record).unique_key();
+            if found != expected {
+                return Err(MergeError::InstrumentMismatch {
+                    source_name: source.name.to_string(),
+                    expected,
+                    found,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Require every source that reports a currency to report the same one.
+    ///
+    /// Pass `field: None` for a record-wide currency and the monetary field's
+    /// name otherwise; sources yielding `None` are not checked.
+    ///
+    /// # Errors
+    /// Returns [`MergeError::CurrencyMismatch`] for the first disagreeing
+    /// source in rank order.
+    pub fn ensure_same_currency<C>(
+        &self,
+        field: Option<&str>,
+        currency: impl Fn(&T) -> Option<&C>,
+    ) -> Result<(), MergeError>
+    where
+        C: PartialEq + fmt::Display + ?Sized,
+    {
+        let mut expected: Option<&C> = None;
+        for source in &self.ranked {
+            let Some(found) = currency(source.record) else {
+                continue;
+            };
+            match expected {
+                None => expected = Some(found),
+                Some(expected) if expected != found => {
+                    return Err(MergeError::CurrencyMismatch {
+                        field: field.map(str::to_string),
+                        source_name: source.name.to_string(),
+                        expected: expected.to_string(),
+                        found: found.to_string(),
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Take `field` from the highest-ranked source that populates it.
+    pub fn field<V: Clone>(
+        &mut self,
+        field: &'static str,
+        value: impl Fn(&T) -> Option<&V>,
+    ) -> Option<V> {
+        self.visited.push(field);
+        let (name, value) = self
+            .ranked_for(field)
+            .find_map(|source| value(source.record).map(|value| (source.name, value)))?;
+        self.sources.insert(field.to_string(), name.to_string());
+        Some(value.clone())
+    }
+
+    /// Take a field every source populates (identity fields or provider
+    /// metadata) from the highest-ranked source for `field`.
+    pub fn required<V: Clone>(&mut self, field: &'static str, value: impl Fn(&T) -> &V) -> V {
+        self.visited.push(field);
+        let source = self
+            .ranked_for(field)
+            .next()
+            .unwrap_or_else(|| self.primary());
+        self.sources
+            .insert(field.to_string(), source.name.to_string());
+        value(source.record).clone()
+    }
+
+    /// Wrap the assembled `record` with the recorded provenance.
+    ///
+    /// # Errors
+    /// Returns [`MergeError::UnknownField`] when the policy configures
+    /// precedence for a field that was never picked.
+    pub fn finish<R>(self, record: R) -> Result<Merged<R>, MergeError> {
+        if let Some(field) = self
+            .policy
+            .field_precedence
+            .keys()
+            .find(|field| !self.visited.contains(&field.as_str()))
+        {
+            return Err(MergeError::UnknownField {
+                field: field.clone(),
+            });
+        }
+        Ok(Merged {
+            record,
+            sources: self.sources,
+        })
+    }
+
+    fn ranked_for(&self, field: &str) -> impl Iterator<Item = MergeSource<'a, T>> + '_ {
+        let preferred = self.policy.field_precedence(field).unwrap_or_default();
+        let listed = preferred.iter().filter_map(|name| {
+            self.ranked
+                .iter()
+                .copied()
+                .find(|source| source.name == name.as_str())
+        });
+        let rest = self
+            .ranked
+            .iter()
+            .copied()
+            .filter(move |source| !preferred.iter().any(|name| name == source.name));
+        listed.chain(rest)
+    }
+}
@@ -0,0 +1,124 @@
+use chrono::{DateTime, Utc};
+use paft_domain::{FieldMerger, MergeError, MergeOrder, MergePolicy, MergeSource, Merged};
+
+/// A provider-defined partial record merged with the shared machinery.
+#[derive(Debug, Clone, PartialEq)]
+struct Fundamentals {
+    as_of: Option<DateTime<Utc>>,
+    sector: Option<String>,
+    employees: Option<u32>,
+    reporting_currency: Option<String>,
+}
+
+impl Fundamentals {
+    fn merge(
+        sources: &[MergeSource<'_, Self>],
+        policy: &MergePolicy,
+    ) -> Result<Merged<Self>, MergeError> {
+        let mut merger = FieldMerger::new(sources, policy, |record| record.as_of)?;
+        merger.ensure_same_currency(None, |record| record.reporting_currency.as_deref())?;
+        let record = Self {
+            as_of: merger.field("as_of", |record| record.as_of.as_ref()),
+            sector: merger.field("sector", |record| record.sector.as_ref()),
+            employees: merger.field("employees", |record| record.employees.as_ref()),
+            reporting_currency: merger.field("reporting_currency", |record| {
+                record.reporting_currency.as_ref()
+            }),
+        };
+        merger.finish(record)
+    }
+}
+
+const fn ts(secs: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(secs, 0).unwrap()
+}
+
+fn record(secs: Option<i64>, sector: Option<&str>, employees: Option<u32>) -> Fundamentals {
+    Fundamentals {
+        as_of: secs.map(ts),
+        sector: sector.map(str::to_string),
+        employees,
+        reporting_currency: None,
+    }
+}
+
+#[test]
+fn freshness_ranks_undated_sources_last_and_keeps_ties_stable() {
+    let undated = record(None, Some("Undated"), Some(1));
+    let old = record(Some(10), Some("Old"), Some(2));
+    let new_a = record(Some(20), None, Some(3));
+    let new_b = record(Some(20), Some("New"), Some(4));
+    let sources = [
+        MergeSource::new("undated", &undated),
+        MergeSource::new("old", &old),
+        MergeSource::new("new_a", &new_a),
+        MergeSource::new("new_b", &new_b),
+    ];
+
+    let policy = MergePolicy::freshness();
+    let ranking = FieldMerger::new(&sources, &policy, |record| record.as_of).unwrap();
+    let ranked: Vec<_> = ranking.ranked().iter().map(|source| source.name).collect();
+    assert_eq!(ranked, ["new_a", "new_b", "old", "undated"]);
+
+    let merged = Fundamentals::merge(&sources, &policy).unwrap();
+    assert_eq!(merged.record, record(Some(20), Some("New"), Some(3)));
+    assert_eq!(merged.source_of("sector"), Some("new_b"));
+    assert_eq!(merged.source_of("employees"), Some("new_a"));
+    assert_eq!(merged.source_of("reporting_currency"), None);
+}
+
+#[test]
+fn field_precedence_lists_are_tried_before_default_order() {
+    let a = record(None, Some("A"), Some(1));
+    let b = record(None, Some("B"), None);
+    let c = record(None, None, Some(3));
+    let sources = [
+        MergeSource::new("a", &a),
+        MergeSource::new("b", &b),
+        MergeSource::new("c", &c),
+    ];
+    let policy = MergePolicy::precedence()
+        .with_field_precedence("sector", ["c", "b"])
+        .with_field_precedence("employees", ["b", "c"]);
+    assert_eq!(policy.order, MergeOrder::Precedence);
+    assert_eq!(
+        policy.field_precedence("sector"),
+        Some(&["c".to_string(), "b".to_string()][..])
+    );
+
+    let merged = Fundamentals::merge(&sources, &policy).unwrap();
+    assert_eq!(merged.source_of("sector"), Some("b"));
+    assert_eq!(merged.source_of("employees"), Some("c"));
+}
+
+#[test]
+fn record_wide_currency_must_agree_when_present() {
+    let mut a = record(None, None, None);
+    a.reporting_currency = Some("USD".to_string());
+    let b = record(None, Some("B"), None);
+    let mut c = record(None, None, None);
+    c.reporting_currency = Some("EUR".to_string());
+
+    let policy = MergePolicy::precedence();
+    assert!(
+        Fundamentals::merge(
+            &[MergeSource::new("a", &a), MergeSource::new("b", &b)],
+            &policy
+        )
+        .is_ok()
+    );
+
+    let err = Fundamentals::merge(
+        &[
+            MergeSource::new("a", &a),
+            MergeSource::new("b", &b),
+            MergeSource::new("c", &c),
+        ],
+        &policy,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "source 'c' has currency 'EUR', expected 'USD'"
+    );
+}
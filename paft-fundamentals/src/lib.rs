@@ -47,6 +47,7 @@ pub mod analysis;
 pub mod error;
 pub mod esg;
 pub mod holders;
+pub mod point_in_time;
 pub mod profile;
 pub mod statements;
 pub mod statistics;
@@ -64,6 +65,7 @@ pub use holders::{
     InsiderPosition, InsiderRosterHolder, InsiderTransaction, InstitutionalHolder, MajorHolder,
     NetSharePurchaseActivity, OtherInsiderPosition, OtherTransactionType, TransactionType,
 };
+pub use point_in_time::{AsOfRow, Published, as_of_join};
 pub use profile::{
     Address, CompanyProfile, FundKind, FundProfile, OtherFundKind, Profile, ShareCount,
 };
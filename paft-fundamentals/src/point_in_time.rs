@@ -0,0 +1,126 @@
+//! Point-in-time (as-of) joins between market timestamps and statement rows.
+//!
+//! Statement rows are keyed by their [`ReportingPeriod`](paft_domain::ReportingPeriod),
+//! but a period's figures only become usable once they are published, often
+//! weeks after the period ends. Joining on `period` therefore leaks future
+//! information into backtests. [`as_of_join`] instead pairs each timestamp
+//! (typically every `Candle::ts` of a history response) with the latest row
+//! whose `published_at` is at or before it.
+//!
+//! ```rust
+//! use chrono::{DateTime, Utc};
+//! use paft_domain::ReportingPeriod;
+//! use paft_fundamentals::IncomeStatementRow;
+//! use paft_fundamentals::point_in_time::as_of_join;
+//!
+//! let ts = |secs| DateTime::<Utc>::from_timestamp(secs, 0).unwrap();
+//! let row = IncomeStatementRow {
+//!     period: ReportingPeriod::quarterly(2023, 4).unwrap(),
+//!     published_at: Some(ts(1_706_745_600)), // 2024-02-01
+//!     total_revenue: None,
+//!     gross_profit: None,
+//!     operating_income: None,
+//!     net_income: None,
+//!     interest_expense: None,
+//!     income_tax_expense: None,
+//!     depreciation_and_amortization: None,
+//! };
+//! let rows = [row];
+//!
+//! // In practice: `history.candles.iter().map(|candle| candle.ts)`.
+//! let joined = as_of_join([ts(1_704_067_200), ts(1_709_251_200)], &rows);
+//! assert!(joined[0].row.is_none()); // 2024-01-01: Q4 not yet published
+//! assert_eq!(joined[1].row, Some(&rows[0])); // 2024-03-01
+//! ```
+
+use chrono::{DateTime, Utc};
+#[cfg(feature = "dataframe")]
+use df_derive_macros::ToDataFrame;
+#[cfg(feature = "dataframe")]
+use paft_utils::dataframe::{Columnar, ToDataFrame, ToDataFrameVec};
+
+use crate::statements::{BalanceSheetRow, CashflowRow, IncomeStatementRow};
+
+/// Records that carry the time they became publicly known.
+pub trait Published {
+    /// Publication time, or `None` when the provider did not report it.
+    fn published_at(&self) -> Option<DateTime<Utc>>;
+}
+
+impl Published for IncomeStatementRow {
+    fn published_at(&self) -> Option<DateTime<Utc>> {
+        self.published_at
+    }
+}
+
+impl Published for BalanceSheetRow {
+    fn published_at(&self) -> Option<DateTime<Utc>> {
+        self.published_at
+    }
+}
+
+impl Published for CashflowRow {
+    fn published_at(&self) -> Option<DateTime<Utc>> {
+        self.published_at
+    }
+}
+
+/// One output row of [`as_of_join`].
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "dataframe", derive(ToDataFrame))]
+pub struct AsOfRow<'a, R> {
+    /// The timestamp being joined.
+    pub ts: DateTime<Utc>,
+    /// Latest row published at or before `ts`, if any.
+    pub row: Option<&'a R>,
+}
+
+/// For each timestamp, find the latest row published at or before it.
+///
+/// Output rows follow the order of `timestamps`, which need not be sorted.
+/// Rows without `published_at` are never matched, since their availability
+/// cannot be established. When several rows share the latest publication
+/// time, the one appearing last in `rows` wins, so restatements should be
+/// passed after the rows they replace.
+pub fn as_of_join<R, I>(timestamps: I, rows: &[R]) -> Vec<AsOfRow<'_, R>>
+where
+    R: Published,
+    I: IntoIterator<Item = DateTime<Utc>>,
+{
+    let mut published: Vec<(DateTime<Utc>, &R)> = rows
+        .iter()
+        .filter_map(|row| row.published_at().map(|at| (at, row)))
+        .collect();
+    // Stable sort keeps input order among equal publication times.
+    published.sort_by_key(|(at, _)| *at);
+
+    timestamps
+        .into_iter()
+        .map(|ts| {
+            let known = published.partition_point(|(at, _)| *at <= ts);
+            AsOfRow {
+                ts,
+                row: known.checked_sub(1).map(|index| published[index].1),
+            }
+        })
+        .collect()
+}
+
+/// [`as_of_join`] exported as a `DataFrame`.
+///
+/// The frame has a `ts` column followed by the row's columns prefixed with
+/// `row.`; timestamps with no published row yield nulls in the row columns.
+///
+/// # Errors
+/// Returns a Polars error if the `DataFrame` cannot be built.
+#[cfg(feature = "dataframe")]
+pub fn as_of_join_dataframe<R, I>(
+    timestamps: I,
+    rows: &[R],
+) -> polars::prelude::PolarsResult<polars::prelude::DataFrame>
+where
+    R: Published + ToDataFrame + Columnar,
+    I: IntoIterator<Item = DateTime<Utc>>,
+{
+    as_of_join(timestamps, rows).as_slice().to_dataframe()
+}
@@ -15,6 +15,12 @@ pub struct IncomeStatementRow {
     /// Financial period with structured variants and extensible fallback.
     #[cfg_attr(feature = "dataframe", df_derive(as_string))]
     pub period: ReportingPeriod,
+    /// When the row became publicly known (for example the filing or press
+    /// release time), if the provider reports it. Point-in-time consumers use
+    /// this rather than `period` to avoid look-ahead bias; see
+    /// [`crate::point_in_time`].
+    #[serde(default, with = "chrono::serde::ts_milliseconds_option")]
+    pub published_at: Option<DateTime<Utc>>,
     /// Total revenue.
     pub total_revenue: Option<Money>,
     /// Gross profit.
@@ -38,6 +44,12 @@ pub struct BalanceSheetRow {
     /// Financial period with structured variants and extensible fallback.
     #[cfg_attr(feature = "dataframe", df_derive(as_string))]
     pub period: ReportingPeriod,
+    /// When the row became publicly known (for example the filing or press
+    /// release time), if the provider reports it. Point-in-time consumers use
+    /// this rather than `period` to avoid look-ahead bias; see
+    /// [`crate::point_in_time`].
+    #[serde(default, with = "chrono::serde::ts_milliseconds_option")]
+    pub published_at: Option<DateTime<Utc>>,
     /// Total assets.
     pub total_assets: Option<Money>,
     /// Total liabilities.
@@ -75,6 +87,12 @@ pub struct CashflowRow {
     /// Financial period with structured variants and extensible fallback.
     #[cfg_attr(feature = "dataframe", df_derive(as_string))]
     pub period: ReportingPeriod,
+    /// When the row became publicly known (for example the filing or press
+    /// release time), if the provider reports it. Point-in-time consumers use
+    /// this rather than `period` to avoid look-ahead bias; see
+    /// [`crate::point_in_time`].
+    #[serde(default, with = "chrono::serde::ts_milliseconds_option")]
+    pub published_at: Option<DateTime<Utc>>,
     /// Operating cashflow.
     pub operating_cashflow: Option<Money>,
     /// Capital expenditures.
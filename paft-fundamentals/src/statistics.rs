@@ -13,6 +13,7 @@ use chrono::{DateTime, NaiveDate, Utc};
 #[cfg(feature = "dataframe")]
 use df_derive_macros::ToDataFrame;
 use paft_decimal::Decimal;
+use paft_domain::{FieldMerger, MergeError, MergePolicy, MergeSource, Merged};
 use paft_money::{Money, Price};
 use serde::{Deserialize, Serialize};
 
@@ -75,3 +76,83 @@ pub struct KeyStatistics {
     #[serde(default, with = "paft_decimal::serde::option_canonical_str")]
     pub beta: Option<Decimal>,
 }
+
+impl KeyStatistics {
+    /// Merge partial key statistics for one instrument from several sources.
+    ///
+    /// Key statistics do not carry their instrument, so callers must only
+    /// pass records describing the same one. Every field is taken from the
+    /// highest-ranked source that populates it under `policy`, and the
+    /// returned [`Merged::sources`] records which source supplied each field.
+    ///
+    /// Monetary fields are checked per field: sources reporting the same
+    /// field in different currencies are refused rather than mixed.
+    ///
+    /// # Errors
+    /// Returns [`MergeError`] when `sources` is empty or has duplicate names,
+    /// when a monetary field is reported in different currencies, or when
+    /// `policy` names a field key statistics do not have.
+    pub fn merge(
+        sources: &[MergeSource<'_, Self>],
+        policy: &MergePolicy,
+    ) -> Result<Merged<Self>, MergeError> {
+        let mut merger = FieldMerger::new(sources, policy, |stats| stats.as_of)?;
+        merger.ensure_same_currency(Some("market_cap"), |stats| {
+            stats.market_cap.as_ref().map(Money::currency)
+        })?;
+        merger.ensure_same_currency(Some("eps_trailing_twelve_months"), |stats| {
+            stats
+                .eps_trailing_twelve_months
+                .as_ref()
+                .map(Price::currency)
+        })?;
+        merger.ensure_same_currency(Some("dividend_per_share_forward"), |stats| {
+            stats
+                .dividend_per_share_forward
+                .as_ref()
+                .map(Price::currency)
+        })?;
+        merger.ensure_same_currency(Some("fifty_two_week_high"), |stats| {
+            stats.fifty_two_week_high.as_ref().map(Price::currency)
+        })?;
+        merger.ensure_same_currency(Some("fifty_two_week_low"), |stats| {
+            stats.fifty_two_week_low.as_ref().map(Price::currency)
+        })?;
+
+        let record = Self {
+            as_of: merger.field("as_of", |stats| stats.as_of.as_ref()),
+            market_cap: merger.field("market_cap", |stats| stats.market_cap.as_ref()),
+            shares_outstanding: merger.field("shares_outstanding", |stats| {
+                stats.shares_outstanding.as_ref()
+            }),
+            eps_trailing_twelve_months: merger.field("eps_trailing_twelve_months", |stats| {
+                stats.eps_trailing_twelve_months.as_ref()
+            }),
+            pe_trailing_twelve_months: merger.field("pe_trailing_twelve_months", |stats| {
+                stats.pe_trailing_twelve_months.as_ref()
+            }),
+            dividend_per_share_forward: merger.field("dividend_per_share_forward", |stats| {
+                stats.dividend_per_share_forward.as_ref()
+            }),
+            dividend_yield_trailing: merger.field("dividend_yield_trailing", |stats| {
+                stats.dividend_yield_trailing.as_ref()
+            }),
+            dividend_yield_forward: merger.field("dividend_yield_forward", |stats| {
+                stats.dividend_yield_forward.as_ref()
+            }),
+            ex_dividend_date: merger
+                .field("ex_dividend_date", |stats| stats.ex_dividend_date.as_ref()),
+            fifty_two_week_high: merger.field("fifty_two_week_high", |stats| {
+                stats.fifty_two_week_high.as_ref()
+            }),
+            fifty_two_week_low: merger.field("fifty_two_week_low", |stats| {
+                stats.fifty_two_week_low.as_ref()
+            }),
+            average_daily_volume_3m: merger.field("average_daily_volume_3m", |stats| {
+                stats.average_daily_volume_3m.as_ref()
+            }),
+            beta: merger.field("beta", |stats| stats.beta.as_ref()),
+        };
+        merger.finish(record)
+    }
+}
@@ -287,6 +287,7 @@ fn earnings_trend_row_to_dataframe() {
 fn statements_row_to_dataframe() {
     let row = IncomeStatementRow {
         period: ReportingPeriod::annual(2024).unwrap(),
+        published_at: None,
         total_revenue: None,
         gross_profit: None,
         operating_income: None,
@@ -303,6 +304,7 @@ fn statements_row_to_dataframe() {
 fn balance_sheet_row_to_dataframe() {
     let row = BalanceSheetRow {
         period: ReportingPeriod::annual(2024).unwrap(),
+        published_at: Some(sample_ts(1_706_745_600)),
         total_assets: Some(usd(5_000)),
         total_liabilities: Some(usd(3_000)),
         total_equity: Some(usd(2_000)),
@@ -327,6 +329,7 @@ fn balance_sheet_row_to_dataframe() {
 fn cashflow_row_to_dataframe() {
     let row = CashflowRow {
         period: ReportingPeriod::annual(2024).unwrap(),
+        published_at: None,
         operating_cashflow: Some(usd(1_200)),
         capital_expenditures: Some(usd(300)),
         free_cash_flow: Some(usd(900)),
@@ -596,3 +599,29 @@ fn profile_vec_to_dataframe() {
     let columns = df.get_column_names();
     assert!(columns.iter().any(|c| c.as_str() == "profile_type"));
 }
+
+#[test]
+fn as_of_join_to_dataframe() {
+    use paft_fundamentals::point_in_time::as_of_join_dataframe;
+
+    let row = CashflowRow {
+        period: ReportingPeriod::quarterly(2024, 1).unwrap(),
+        published_at: Some(sample_ts(1_000)),
+        operating_cashflow: Some(usd(1_200)),
+        capital_expenditures: None,
+        free_cash_flow: None,
+        net_income: None,
+        depreciation_and_amortization: None,
+    };
+
+    let df = as_of_join_dataframe([sample_ts(500), sample_ts(1_500)], &[row]).unwrap();
+    assert_eq!(df.height(), 2);
+    let columns = df.get_column_names();
+    assert_eq!(columns[0].as_str(), "ts");
+    assert!(columns.iter().any(|c| c.as_str() == "row.period"));
+    assert!(columns.iter().any(|c| c.as_str() == "row.published_at"));
+
+    let period = df.column("row.period").unwrap().str().unwrap();
+    assert_eq!(period.get(0), None);
+    assert_eq!(period.get(1), Some("2024Q1"));
+}
@@ -0,0 +1,98 @@
+use chrono::{DateTime, Utc};
+use paft_decimal::Decimal;
+use paft_domain::ReportingPeriod;
+use paft_fundamentals::{BalanceSheetRow, IncomeStatementRow, Published, as_of_join};
+use paft_money::{Currency, IsoCurrency, Money};
+use serde_json::json;
+
+fn usd(amount: i64) -> Money {
+    Money::new(Decimal::from(amount), Currency::Iso(IsoCurrency::USD)).unwrap()
+}
+
+const fn ts(secs: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(secs, 0).unwrap()
+}
+
+fn income(quarter: u8, published: Option<i64>, revenue: i64) -> IncomeStatementRow {
+    IncomeStatementRow {
+        period: ReportingPeriod::quarterly(2024, quarter).unwrap(),
+        published_at: published.map(ts),
+        total_revenue: Some(usd(revenue)),
+        gross_profit: None,
+        operating_income: None,
+        net_income: None,
+        interest_expense: None,
+        income_tax_expense: None,
+        depreciation_and_amortization: None,
+    }
+}
+
+#[test]
+fn join_returns_latest_row_published_at_or_before_each_timestamp() {
+    let rows = [
+        income(2, Some(300), 20),
+        income(1, Some(100), 10),
+        income(3, None, 30),
+    ];
+
+    let joined = as_of_join([ts(50), ts(100), ts(250), ts(1_000)], &rows);
+    let matched: Vec<_> = joined
+        .iter()
+        .map(|entry| entry.row.map(|row| row.total_revenue.clone()))
+        .collect();
+    assert_eq!(
+        matched,
+        vec![
+            None,
+            Some(Some(usd(10))),
+            Some(Some(usd(10))),
+            Some(Some(usd(20))),
+        ]
+    );
+    assert_eq!(joined[2].ts, ts(250));
+}
+
+#[test]
+fn join_preserves_timestamp_order_and_prefers_later_restatements() {
+    let original = income(1, Some(100), 10);
+    let restated = income(1, Some(100), 11);
+    let rows = [original, restated];
+
+    let joined = as_of_join([ts(500), ts(0), ts(100)], &rows);
+    let stamps: Vec<_> = joined.iter().map(|entry| entry.ts).collect();
+    assert_eq!(stamps, vec![ts(500), ts(0), ts(100)]);
+    assert_eq!(joined[0].row, Some(&rows[1]));
+    assert_eq!(joined[1].row, None);
+    assert_eq!(joined[2].row, Some(&rows[1]));
+
+    assert!(
+        as_of_join([ts(1)], &[] as &[BalanceSheetRow])[0]
+            .row
+            .is_none()
+    );
+    assert!(as_of_join(std::iter::empty(), &rows).is_empty());
+}
+
+#[test]
+fn published_at_is_optional_on_the_wire() {
+    let legacy = json!({
+        "period": "2024Q1",
+        "total_revenue": null,
+        "gross_profit": null,
+        "operating_income": null,
+        "net_income": null,
+        "interest_expense": null,
+        "income_tax_expense": null,
+        "depreciation_and_amortization": null
+    });
+    let row: IncomeStatementRow = serde_json::from_value(legacy).unwrap();
+    assert_eq!(row.published_at(), None);
+
+    let row = income(1, Some(1_714_521_600), 10);
+    let value = serde_json::to_value(&row).unwrap();
+    assert_eq!(value["published_at"], 1_714_521_600_000_i64);
+    assert_eq!(
+        serde_json::from_value::<IncomeStatementRow>(value).unwrap(),
+        row
+    );
+}
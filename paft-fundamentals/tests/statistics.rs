@@ -73,3 +73,70 @@ fn key_statistics_serde_roundtrip_empty() {
     let decoded: KeyStatistics = from_str(&encoded).unwrap();
     assert_eq!(s, decoded);
 }
+
+#[test]
+fn key_statistics_merge_combines_sources_with_provenance() {
+    use paft_domain::{MergePolicy, MergeSource};
+
+    let a = KeyStatistics {
+        as_of: Some(Utc.timestamp_opt(1_700_000_000, 0).unwrap()),
+        market_cap: Some(usd(3_000_000_000)),
+        beta: Some(dec("1.2")),
+        ..KeyStatistics::default()
+    };
+    let b = KeyStatistics {
+        as_of: Some(Utc.timestamp_opt(1_700_086_400, 0).unwrap()),
+        market_cap: Some(usd(3_100_000_000)),
+        fifty_two_week_high: Some(usd_price(200)),
+        ex_dividend_date: Some(date(2024, 2, 9)),
+        ..KeyStatistics::default()
+    };
+    let sources = [MergeSource::new("a", &a), MergeSource::new("b", &b)];
+
+    let merged = KeyStatistics::merge(&sources, &MergePolicy::precedence()).unwrap();
+    assert_eq!(merged.record.market_cap, Some(usd(3_000_000_000)));
+    assert_eq!(merged.record.beta, Some(dec("1.2")));
+    assert_eq!(merged.record.fifty_two_week_high, Some(usd_price(200)));
+    assert_eq!(merged.record.ex_dividend_date, Some(date(2024, 2, 9)));
+    assert_eq!(merged.source_of("market_cap"), Some("a"));
+    assert_eq!(merged.source_of("fifty_two_week_high"), Some("b"));
+    assert_eq!(merged.source_of("shares_outstanding"), None);
+
+    let fresh = KeyStatistics::merge(&sources, &MergePolicy::freshness()).unwrap();
+    assert_eq!(fresh.record.market_cap, Some(usd(3_100_000_000)));
+    assert_eq!(fresh.record.as_of, b.as_of);
+    assert_eq!(fresh.source_of("beta"), Some("a"));
+}
+
+#[test]
+fn key_statistics_merge_refuses_mixed_currencies_per_field() {
+    use paft_domain::{MergeError, MergePolicy, MergeSource};
+
+    let a = KeyStatistics {
+        eps_trailing_twelve_months: Some(usd_price(6)),
+        ..KeyStatistics::default()
+    };
+    let b = KeyStatistics {
+        market_cap: Some(Money::new(Decimal::from(90), Currency::Iso(IsoCurrency::EUR)).unwrap()),
+        eps_trailing_twelve_months: Some(Price::new(
+            Decimal::from(5),
+            Currency::Iso(IsoCurrency::EUR),
+        )),
+        ..KeyStatistics::default()
+    };
+
+    let err = KeyStatistics::merge(
+        &[MergeSource::new("a", &a), MergeSource::new("b", &b)],
+        &MergePolicy::precedence(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        MergeError::CurrencyMismatch {
+            field: Some("eps_trailing_twelve_months".to_string()),
+            source_name: "b".to_string(),
+            expected: "USD".to_string(),
+            found: "EUR".to_string(),
+        }
+    );
+}
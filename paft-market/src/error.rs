@@ -1,8 +1,9 @@
-//! Error types specific to `paft-market` request validation.
+//! Error types specific to `paft-market` request and market-data validation.
 
+use paft_decimal::Decimal;
 use thiserror::Error;
 
-/// Errors returned when validating market requests before execution.
+/// Errors returned when validating market requests and market data.
 #[derive(Debug, Error, Clone, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum MarketError {
@@ -38,4 +39,40 @@ pub enum MarketError {
         /// Rejected input value.
         value: String,
     },
+
+    /// Invalid tick-size table structure.
+    #[error("Invalid tick size table: {reason}")]
+    InvalidTickSizeTable {
+        /// Validation failure reason.
+        reason: &'static str,
+    },
+
+    /// `MiFID` II liquidity band must be between 1 and 6.
+    #[error("MiFID liquidity band must be between 1 and 6, but was {0}")]
+    InvalidLiquidityBand(u8),
+
+    /// Price is not covered by the tick-size table.
+    #[error("Price {price} is outside the tick size table")]
+    PriceOutsideTickTable {
+        /// Rejected price.
+        price: Decimal,
+    },
+
+    /// Price is not a whole multiple of its tick.
+    #[error("Price {price} is not on the tick size grid")]
+    PriceOffTick {
+        /// Off-tick price.
+        price: Decimal,
+    },
+
+    /// Order-book level price is not a whole multiple of its tick.
+    #[error("Order book {side} level {index} price {price} is not on the tick size grid")]
+    OffTickBookLevel {
+        /// Book side holding the level, `"asks"` or `"bids"`.
+        side: &'static str,
+        /// Position of the level within its side.
+        index: usize,
+        /// Off-tick price.
+        price: Decimal,
+    },
 }
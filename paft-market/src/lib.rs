@@ -1,8 +1,10 @@
 //! Market data types, requests, and responses for paft.
 //!
 //! This crate provides strongly-typed market data models (quotes, options,
-//! news), request builders (search, history), and response types that are
-//! consistent across providers. It aims to:
+//! news, tick-size tables), request builders (search, history), response
+//! types that are consistent across providers, and cross-provider
+//! reconciliation reports.
+//! It aims to:
 //! - Offer validated builders to avoid invalid request states
 //! - Encode canonical, serde-stable string forms for interop
 //! - Integrate with `paft-domain` and `paft-money` for identifiers and values
@@ -42,6 +44,7 @@
 
 pub mod error;
 pub mod market;
+pub mod reconciliation;
 pub mod requests;
 pub mod responses;
 
@@ -55,7 +58,13 @@ pub use market::{
         OptionContract, OptionContractKey, OptionGreeks, OptionSide, OptionUpdate,
     },
     orderbook::{BookLevel, GenericBookLevel, GenericOrderBook, OrderBook},
-    quote::{GenericQuote, GenericQuoteUpdate, Quote, QuoteUpdate},
+    quote::{GenericQuote, GenericQuoteUpdate, Quote, QuoteFreshnessPolicy, QuoteUpdate},
+    tick_size::{TickBand, TickSizeTable, TickSizeTables},
+};
+pub use reconciliation::{
+    Deviation, Discrepancy, DiscrepancyKind, ReconciledField, ReconciliationConfig,
+    ReconciliationError, ReconciliationReport, ReconciliationSide, Tolerance, reconcile_history,
+    reconcile_quotes,
 };
 pub use requests::history::{
     HistoryFlags, HistoryRequest, HistoryRequestBuilder, Interval, Range, TimeSpec,
@@ -5,6 +5,7 @@ pub mod news;
 pub mod options;
 pub mod orderbook;
 pub mod quote;
+pub mod tick_size;
 
 pub use action::Action;
 pub use news::{GenericNewsArticle, NewsArticle};
@@ -13,4 +14,5 @@ pub use options::{
     OptionContractKey, OptionGreeks, OptionSide, OptionUpdate,
 };
 pub use orderbook::{BookLevel, GenericBookLevel, GenericOrderBook, OrderBook};
-pub use quote::{GenericQuote, GenericQuoteUpdate, Quote, QuoteUpdate};
+pub use quote::{GenericQuote, GenericQuoteUpdate, Quote, QuoteFreshnessPolicy, QuoteUpdate};
+pub use tick_size::{TickBand, TickSizeTable, TickSizeTables};
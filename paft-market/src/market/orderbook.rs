@@ -5,6 +5,9 @@ use paft_domain::Instrument;
 use paft_money::{Currency, PriceAmount, QuantityAmount};
 use serde::{Deserialize, Serialize};
 
+use crate::error::MarketError;
+use crate::market::tick_size::TickSizeTable;
+
 #[cfg(feature = "dataframe")]
 use df_derive_macros::ToDataFrame;
 
@@ -145,6 +148,34 @@ impl<B, L> GenericOrderBook<B, L> {
         self.bids
             .sort_by(|lhs, rhs| rhs.price.as_decimal().cmp(lhs.price.as_decimal()));
     }
+
+    /// Validate that every ask and bid level price is on the tick grid of
+    /// `table`, checking asks before bids in book order.
+    ///
+    /// Look up the table for this book's instrument with
+    /// [`crate::market::tick_size::TickSizeTables::for_instrument`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MarketError::OffTickBookLevel`] for the first level whose
+    /// price is off tick.
+    pub fn validate_ticks(&self, table: &TickSizeTable) -> Result<(), MarketError> {
+        let sides = [("asks", &self.asks), ("bids", &self.bids)];
+        for (side, levels) in sides {
+            if let Some((index, level)) = levels
+                .iter()
+                .enumerate()
+                .find(|(_, level)| !table.is_on_tick(&level.price))
+            {
+                return Err(MarketError::OffTickBookLevel {
+                    side,
+                    index,
+                    price: paft_decimal::clone_decimal(level.price.as_decimal()),
+                });
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Standard `OrderBook` with no extra provider metadata.
@@ -2,10 +2,13 @@
 
 use serde::{Deserialize, Serialize};
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeDelta, Utc};
 #[cfg(feature = "dataframe")]
 use df_derive_macros::ToDataFrame;
-use paft_domain::{Instrument, MarketState};
+use paft_decimal::{self as decimal, Decimal};
+use paft_domain::{
+    FieldMerger, Instrument, MarketState, MergeError, MergePolicy, MergeSource, Merged,
+};
 use paft_money::{Currency, PriceAmount, QuantityAmount};
 
 use crate::market::orderbook::GenericBookLevel;
@@ -73,6 +76,196 @@ impl<Q: Default, L> GenericQuote<Q, L> {
     }
 }
 
+impl<Q: Clone, L: Clone> GenericQuote<Q, L> {
+    /// Merge partial quotes for one instrument from several sources.
+    ///
+    /// Every optional field is taken from the highest-ranked source that
+    /// populates it under `policy`; `bid` and `ask` move as whole levels so a
+    /// price is never paired with another source's size. `instrument` and
+    /// `provider` come from the highest-ranked source. The returned
+    /// [`Merged::sources`] records which source supplied each field.
+    ///
+    /// # Errors
+    /// Returns [`MergeError`] when `sources` is empty or has duplicate names,
+    /// when the sources disagree on [`Instrument::unique_key`] or on
+    /// `currency`, or when `policy` names a field a quote does not have.
+    pub fn merge(
+        sources: &[MergeSource<'_, Self>],
+        policy: &MergePolicy,
+    ) -> Result<Merged<Self>, MergeError> {
+        let mut merger = FieldMerger::new(sources, policy, |quote| quote.as_of)?;
+        merger.ensure_same_instrument(|quote| &quote.instrument)?;
+        merger.ensure_same_currency(None, |quote| Some(&quote.currency))?;
+
+        let record = Self {
+            instrument: merger.required("instrument", |quote| &quote.instrument),
+            name: merger.field("name", |quote| quote.name.as_ref()),
+            currency: merger.required("currency", |quote| &quote.currency),
+            price: merger.field("price", |quote| quote.price.as_ref()),
+            bid: merger.field("bid", |quote| quote.bid.as_ref()),
+            ask: merger.field("ask", |quote| quote.ask.as_ref()),
+            previous_close: merger.field("previous_close", |quote| quote.previous_close.as_ref()),
+            day_volume: merger.field("day_volume", |quote| quote.day_volume.as_ref()),
+            market_state: merger.field("market_state", |quote| quote.market_state.as_ref()),
+            as_of: merger.field("as_of", |quote| quote.as_of.as_ref()),
+            provider: merger.required("provider", |quote| &quote.provider),
+        };
+        merger.finish(record)
+    }
+}
+
+impl<Q, L> GenericQuote<Q, L> {
+    /// Absolute change of `price` against `previous_close`.
+    ///
+    /// Returns `None` when either input is missing or the difference
+    /// overflows the active decimal backend.
+    #[must_use]
+    pub fn change(&self) -> Option<PriceAmount> {
+        let price = self.price.as_ref()?;
+        let previous_close = self.previous_close.as_ref()?;
+        decimal::checked_sub(price.as_decimal(), previous_close.as_decimal()).map(PriceAmount::new)
+    }
+
+    /// Change of `price` against `previous_close` in percent (`1.5` for +1.5%).
+    ///
+    /// The result is exact up to the precision of the active decimal backend;
+    /// no rounding is applied. Returns `None` when either input is missing,
+    /// when `previous_close` is zero, or when the computation overflows.
+    #[must_use]
+    pub fn change_percent(&self) -> Option<Decimal> {
+        let change = self.change()?;
+        let previous_close = self.previous_close.as_ref()?;
+        ratio_scaled(change.as_decimal(), previous_close.as_decimal(), 100)
+    }
+
+    /// Midpoint between the best bid and best ask prices.
+    ///
+    /// Returns `None` when either side of the top of book is missing or the
+    /// computation overflows.
+    #[must_use]
+    pub fn mid(&self) -> Option<PriceAmount> {
+        let (bid, ask) = self.top_of_book_prices()?;
+        let sum = decimal::checked_add(bid, ask)?;
+        decimal::checked_div(&sum, &Decimal::from(2_i64)).map(PriceAmount::new)
+    }
+
+    /// Quoted spread (`ask - bid`).
+    ///
+    /// A crossed book yields a negative spread; no validation of the book
+    /// shape is performed. Returns `None` when either side of the top of book
+    /// is missing or the difference overflows.
+    #[must_use]
+    pub fn spread(&self) -> Option<PriceAmount> {
+        let (bid, ask) = self.top_of_book_prices()?;
+        decimal::checked_sub(ask, bid).map(PriceAmount::new)
+    }
+
+    /// Quoted spread relative to [`Self::mid`], in basis points.
+    ///
+    /// Returns `None` when either side of the top of book is missing, when
+    /// the midpoint is zero, or when the computation overflows.
+    #[must_use]
+    pub fn spread_bps(&self) -> Option<Decimal> {
+        let spread = self.spread()?;
+        let mid = self.mid()?;
+        ratio_scaled(spread.as_decimal(), mid.as_decimal(), 10_000)
+    }
+
+    /// Age of this quote at `now`, measured from `as_of`.
+    ///
+    /// An `as_of` later than `now` (clock skew between provider and consumer)
+    /// yields a zero age rather than a negative one. Returns `None` when
+    /// `as_of` is missing.
+    #[must_use]
+    pub fn age(&self, now: DateTime<Utc>) -> Option<TimeDelta> {
+        let as_of = self.as_of?;
+        Some((now - as_of).max(TimeDelta::zero()))
+    }
+
+    /// Check whether this quote is fresh at `now` for the given market state.
+    ///
+    /// `market_state` is supplied by the caller (for example from a session
+    /// calendar) rather than read from [`Self::market_state`], because the
+    /// state carried by a stale quote is itself stale. The applicable
+    /// threshold is selected with [`MarketState::is_trading`].
+    ///
+    /// Returns `None` when `as_of` is missing, since freshness cannot be
+    /// established without an observation timestamp.
+    #[must_use]
+    pub fn is_fresh(
+        &self,
+        now: DateTime<Utc>,
+        market_state: &MarketState,
+        policy: &QuoteFreshnessPolicy,
+    ) -> Option<bool> {
+        let age = self.age(now)?;
+        Some(
+            policy
+                .max_age_for(market_state)
+                .is_none_or(|max_age| age <= max_age),
+        )
+    }
+
+    fn top_of_book_prices(&self) -> Option<(&Decimal, &Decimal)> {
+        let bid = self.bid.as_ref()?;
+        let ask = self.ask.as_ref()?;
+        Some((bid.price.as_decimal(), ask.price.as_decimal()))
+    }
+}
+
+/// Computes `numerator / denominator * scale`, returning `None` for a zero
+/// denominator or on overflow.
+fn ratio_scaled(numerator: &Decimal, denominator: &Decimal, scale: i64) -> Option<Decimal> {
+    if denominator == &decimal::zero() {
+        return None;
+    }
+    let scaled = decimal::checked_mul(numerator, &Decimal::from(scale))?;
+    decimal::checked_div(&scaled, denominator)
+}
+
+/// Maximum quote ages used by [`GenericQuote::is_fresh`].
+///
+/// Thresholds are split by session because a quote that has not moved for an
+/// hour is stale during regular trading but entirely expected over a weekend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuoteFreshnessPolicy {
+    /// Maximum age while [`MarketState::is_trading`] is `true`.
+    pub max_age_trading: TimeDelta,
+    /// Maximum age outside trading sessions; `None` never marks a quote stale
+    /// while the market is not trading.
+    pub max_age_non_trading: Option<TimeDelta>,
+}
+
+impl QuoteFreshnessPolicy {
+    /// Build a policy with a trading-session threshold that never marks
+    /// quotes stale outside trading sessions.
+    #[must_use]
+    pub const fn new(max_age_trading: TimeDelta) -> Self {
+        Self {
+            max_age_trading,
+            max_age_non_trading: None,
+        }
+    }
+
+    /// Set the threshold applied outside trading sessions.
+    #[must_use]
+    pub const fn with_max_age_non_trading(mut self, max_age: TimeDelta) -> Self {
+        self.max_age_non_trading = Some(max_age);
+        self
+    }
+
+    /// Return the threshold that applies to `market_state`, or `None` when
+    /// quotes never go stale in that state.
+    #[must_use]
+    pub const fn max_age_for(&self, market_state: &MarketState) -> Option<TimeDelta> {
+        if market_state.is_trading() {
+            Some(self.max_age_trading)
+        } else {
+            self.max_age_non_trading
+        }
+    }
+}
+
 /// Standard `Quote` with no extra provider metadata.
 pub type Quote = GenericQuote<(), ()>;
 
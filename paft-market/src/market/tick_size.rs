@@ -0,0 +1,311 @@
+//! Tiered tick-size tables under the `paft_market::market::tick_size` namespace.
+//!
+//! A [`TickSizeTable`] maps price bands to the minimum price increment that
+//! applies within them, such as the `MiFID` II tick-size regime or the US
+//! sub-penny rule. [`TickSizeTables`] keys tables by instrument and
+//! exchange so callers can look up the regime for a given book or order.
+
+use std::collections::HashMap;
+
+use paft_decimal::{Decimal, PositiveDecimal, RoundingStrategy};
+use paft_domain::{Exchange, Instrument};
+use paft_money::PriceAmount;
+use serde::{Deserialize, Deserializer, Serialize, de};
+
+use crate::error::MarketError;
+
+/// Number of `MiFID` II liquidity bands (columns of the RTS 11 table).
+const MIFID_LIQUIDITY_BANDS: u8 = 6;
+
+/// Price band of a [`TickSizeTable`]: `tick` applies from `min_price`
+/// (inclusive) up to the next band's `min_price`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TickBand {
+    /// Inclusive lower bound of the band.
+    pub min_price: PriceAmount,
+    /// Minimum price increment within the band.
+    pub tick: PositiveDecimal,
+}
+
+impl TickBand {
+    /// Build a band whose `tick` applies from `min_price` upwards.
+    #[must_use]
+    pub const fn new(min_price: PriceAmount, tick: PositiveDecimal) -> Self {
+        Self { min_price, tick }
+    }
+}
+
+/// Tiered tick-size table covering all non-negative prices.
+///
+/// Bands are ordered by `min_price`, and the first band starts at zero.
+/// Every band boundary lies on the tick grid of both adjacent bands, so
+/// snapping a price never produces a value that is off tick in the band it
+/// lands in.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TickSizeTable {
+    bands: Vec<TickBand>,
+}
+
+impl TickSizeTable {
+    /// Construct a tick-size table and validate its bands.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MarketError::InvalidTickSizeTable`] when the band list is
+    /// empty, does not start at zero, is not strictly ascending, or has a
+    /// boundary off the tick grid of an adjacent band.
+    pub fn new(bands: Vec<TickBand>) -> Result<Self, MarketError> {
+        let table = Self { bands };
+        table.validate()?;
+        Ok(table)
+    }
+
+    /// A single band with a constant `tick` for every price.
+    #[must_use]
+    pub fn uniform(tick: PositiveDecimal) -> Self {
+        Self {
+            bands: vec![TickBand::new(PriceAmount::new(paft_decimal::zero()), tick)],
+        }
+    }
+
+    /// The US Regulation NMS Rule 612 sub-penny regime: `0.0001` below
+    /// `1.00` and `0.01` from `1.00` upwards.
+    #[must_use]
+    pub fn us_sub_penny() -> Self {
+        Self {
+            bands: vec![band(0, 0, 1, 4), band(1, 0, 1, 2)],
+        }
+    }
+
+    /// The `MiFID` II tick-size regime (RTS 11 annex) for a liquidity band
+    /// from `1` (fewer than 10 average daily trades) to `6` (9000 or more).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MarketError::InvalidLiquidityBand`] when `liquidity_band` is
+    /// not between 1 and 6.
+    pub fn mifid(liquidity_band: u8) -> Result<Self, MarketError> {
+        if !(1..=MIFID_LIQUIDITY_BANDS).contains(&liquidity_band) {
+            return Err(MarketError::InvalidLiquidityBand(liquidity_band));
+        }
+        // Prices and ticks both follow the 1-2-5 series. The price bands
+        // start at 0, 0.1, 0.2, 0.5, ... 50000, and each liquidity band shifts
+        // the tick column one step finer, floored at 0.0001.
+        let bands = (0..19_u32)
+            .map(|row| {
+                let step = (row + 3).saturating_sub(u32::from(liquidity_band));
+                let min_price = if row == 0 {
+                    paft_decimal::zero()
+                } else {
+                    one_two_five(row - 1, 1)
+                };
+                builtin_band(min_price, one_two_five(step, 4))
+            })
+            .collect();
+        Ok(Self { bands })
+    }
+
+    /// Return the validated, ordered bands.
+    #[must_use]
+    pub fn bands(&self) -> &[TickBand] {
+        &self.bands
+    }
+
+    /// Consume this table and return its validated bands.
+    #[must_use]
+    pub fn into_bands(self) -> Vec<TickBand> {
+        self.bands
+    }
+
+    /// Validate the table's band structure.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MarketError::InvalidTickSizeTable`] when the band list is
+    /// empty, does not start at zero, is not strictly ascending, or has a
+    /// boundary off the tick grid of an adjacent band.
+    pub fn validate(&self) -> Result<(), MarketError> {
+        let Some(first) = self.bands.first() else {
+            return Err(MarketError::InvalidTickSizeTable {
+                reason: "at least one band is required",
+            });
+        };
+        if *first.min_price.as_decimal() != paft_decimal::zero() {
+            return Err(MarketError::InvalidTickSizeTable {
+                reason: "the first band must start at zero",
+            });
+        }
+        for pair in self.bands.windows(2) {
+            let boundary = pair[1].min_price.as_decimal();
+            if pair[0].min_price.as_decimal() >= boundary {
+                return Err(MarketError::InvalidTickSizeTable {
+                    reason: "bands must be ordered by strictly ascending min_price",
+                });
+            }
+            if !is_multiple(boundary, pair[0].tick.as_decimal())
+                || !is_multiple(boundary, pair[1].tick.as_decimal())
+            {
+                return Err(MarketError::InvalidTickSizeTable {
+                    reason: "band boundaries must lie on the tick grid of both adjacent bands",
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the tick that applies to `price`, or `None` for negative
+    /// prices.
+    #[must_use]
+    pub fn tick_for(&self, price: &PriceAmount) -> Option<&PositiveDecimal> {
+        self.bands
+            .iter()
+            .rev()
+            .find(|band| band.min_price.as_decimal() <= price.as_decimal())
+            .map(|band| &band.tick)
+    }
+
+    /// Returns `true` when `price` is a whole multiple of the tick that
+    /// applies to it.
+    #[must_use]
+    pub fn is_on_tick(&self, price: &PriceAmount) -> bool {
+        self.tick_for(price)
+            .is_some_and(|tick| is_multiple(price.as_decimal(), tick.as_decimal()))
+    }
+
+    /// Snap `price` onto the tick grid, rounding the number of ticks with
+    /// `strategy`; use [`RoundingStrategy::ToZero`] to snap a buy price down
+    /// and [`RoundingStrategy::AwayFromZero`] to snap a sell price up.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MarketError::PriceOutsideTickTable`] when `price` is
+    /// negative or too large to divide by its tick in the active decimal
+    /// backend.
+    pub fn snap(
+        &self,
+        price: &PriceAmount,
+        strategy: RoundingStrategy,
+    ) -> Result<PriceAmount, MarketError> {
+        let outside = || MarketError::PriceOutsideTickTable {
+            price: paft_decimal::clone_decimal(price.as_decimal()),
+        };
+        let tick = self.tick_for(price).ok_or_else(outside)?.as_decimal();
+        let steps = paft_decimal::checked_div(price.as_decimal(), tick).ok_or_else(outside)?;
+        let steps = paft_decimal::round_dp_with_strategy(&steps, 0, strategy);
+        let snapped = paft_decimal::checked_mul(&steps, tick).ok_or_else(outside)?;
+        Ok(PriceAmount::new(snapped))
+    }
+
+    /// Validate that `price` is on tick.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MarketError::PriceOffTick`] when it is not.
+    pub fn validate_price(&self, price: &PriceAmount) -> Result<(), MarketError> {
+        if self.is_on_tick(price) {
+            Ok(())
+        } else {
+            Err(MarketError::PriceOffTick {
+                price: paft_decimal::clone_decimal(price.as_decimal()),
+            })
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for TickSizeTable {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct TickSizeTableShadow {
+            bands: Vec<TickBand>,
+        }
+
+        let shadow = TickSizeTableShadow::deserialize(deserializer)?;
+        Self::new(shadow.bands).map_err(de::Error::custom)
+    }
+}
+
+/// Tick-size tables keyed by instrument and by exchange.
+///
+/// Lookups prefer a table registered for the exact instrument and fall back
+/// to the table of the instrument's exchange.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TickSizeTables {
+    instruments: HashMap<Instrument, TickSizeTable>,
+    exchanges: HashMap<Exchange, TickSizeTable>,
+}
+
+impl TickSizeTables {
+    /// Build an empty set of tables.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the table for every instrument on `exchange`, returning the
+    /// table it replaces.
+    pub fn set_exchange(
+        &mut self,
+        exchange: Exchange,
+        table: TickSizeTable,
+    ) -> Option<TickSizeTable> {
+        self.exchanges.insert(exchange, table)
+    }
+
+    /// Register the table for `instrument`, overriding its exchange's table,
+    /// and return the table it replaces.
+    pub fn set_instrument(
+        &mut self,
+        instrument: Instrument,
+        table: TickSizeTable,
+    ) -> Option<TickSizeTable> {
+        self.instruments.insert(instrument, table)
+    }
+
+    /// Table registered for `exchange`.
+    #[must_use]
+    pub fn for_exchange(&self, exchange: &Exchange) -> Option<&TickSizeTable> {
+        self.exchanges.get(exchange)
+    }
+
+    /// Table that applies to `instrument`: its own, or else its exchange's.
+    #[must_use]
+    pub fn for_instrument(&self, instrument: &Instrument) -> Option<&TickSizeTable> {
+        self.instruments.get(instrument).or_else(|| {
+            instrument
+                .exchange
+                .as_ref()
+                .and_then(|exchange| self.for_exchange(exchange))
+        })
+    }
+}
+
+/// `mantissa × 10^(index / 3) × 10^-scale` with the mantissa cycling
+/// through 1, 2, 5.
+fn one_two_five(index: u32, scale: u32) -> Decimal {
+    let mantissa = [1, 2, 5][index as usize % 3] * 10_i128.pow(index / 3);
+    paft_decimal::from_minor_units(mantissa, scale)
+}
+
+fn band(units: i128, units_scale: u32, tick: i128, tick_scale: u32) -> TickBand {
+    builtin_band(
+        paft_decimal::from_minor_units(units, units_scale),
+        paft_decimal::from_minor_units(tick, tick_scale),
+    )
+}
+
+fn builtin_band(min_price: Decimal, tick: Decimal) -> TickBand {
+    TickBand::new(
+        PriceAmount::new(min_price),
+        PositiveDecimal::new(tick).expect("built-in ticks are positive"),
+    )
+}
+
+fn is_multiple(value: &Decimal, tick: &Decimal) -> bool {
+    paft_decimal::checked_div(value, tick).is_some_and(|steps| {
+        paft_decimal::round_dp_with_strategy(&steps, 0, RoundingStrategy::ToZero) == steps
+    })
+}
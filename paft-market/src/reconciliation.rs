@@ -0,0 +1,702 @@
+//! Cross-provider reconciliation of quotes and history responses.
+//!
+//! Running two providers side by side is only useful if their disagreements
+//! can be inspected. The functions in this module compare two payloads for the
+//! same [`Instrument`] field by field and emit a [`ReconciliationReport`]
+//! listing every [`Discrepancy`] that falls outside the configured
+//! [`Tolerance`]. Reports serialize with serde, and the discrepancy rows
+//! export to a `DataFrame` via `ToDataFrameVec` when the `dataframe` feature
+//! is enabled.
+//!
+//! The two inputs are called `left` and `right`; neither is treated as
+//! authoritative. Relative deviations are measured against the larger
+//! magnitude of the two values so that swapping the inputs yields the same
+//! report with sides flipped.
+
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+#[cfg(feature = "dataframe")]
+use df_derive_macros::ToDataFrame;
+use paft_decimal::{self as decimal, Decimal, NonNegativeDecimal};
+use paft_domain::Instrument;
+use paft_money::{Currency, PriceAmount, QuantityAmount};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::market::orderbook::GenericBookLevel;
+use crate::market::quote::GenericQuote;
+use crate::responses::history::{GenericCandle, GenericHistoryResponse};
+
+/// Errors returned when two payloads cannot be reconciled at all.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum ReconciliationError {
+    /// The payloads describe different instruments.
+    #[error("cannot reconcile different instruments: '{left}' and '{right}'")]
+    InstrumentMismatch {
+        /// Unique key of the left instrument.
+        left: String,
+        /// Unique key of the right instrument.
+        right: String,
+    },
+}
+
+/// Accepted deviation between two values.
+///
+/// A pair of values is within tolerance when they are equal, when their
+/// absolute difference does not exceed `absolute`, or when their relative
+/// difference does not exceed `relative`. Unset bounds do not accept any
+/// deviation, so [`Tolerance::exact`] only accepts identical values.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Tolerance {
+    /// Maximum absolute difference, in the unit of the compared values.
+    #[serde(default)]
+    pub absolute: Option<NonNegativeDecimal>,
+    /// Maximum difference relative to the larger magnitude of the two values
+    /// (e.g. `0.001` for 0.1%).
+    #[serde(default)]
+    pub relative: Option<NonNegativeDecimal>,
+}
+
+impl Tolerance {
+    /// Tolerance that only accepts identical values.
+    #[must_use]
+    pub const fn exact() -> Self {
+        Self {
+            absolute: None,
+            relative: None,
+        }
+    }
+
+    /// Tolerance accepting an absolute difference up to `absolute`.
+    #[must_use]
+    pub const fn absolute(absolute: NonNegativeDecimal) -> Self {
+        Self {
+            absolute: Some(absolute),
+            relative: None,
+        }
+    }
+
+    /// Tolerance accepting a relative difference up to `relative`.
+    #[must_use]
+    pub const fn relative(relative: NonNegativeDecimal) -> Self {
+        Self {
+            absolute: None,
+            relative: Some(relative),
+        }
+    }
+
+    /// Return `true` when the measured deviation is acceptable.
+    #[must_use]
+    pub fn accepts(&self, deviation: &Deviation) -> bool {
+        if deviation.absolute == decimal::zero() {
+            return true;
+        }
+        let within_absolute = self
+            .absolute
+            .as_ref()
+            .is_some_and(|bound| &deviation.absolute <= bound.as_decimal());
+        let within_relative = match (&self.relative, &deviation.relative) {
+            (Some(bound), Some(relative)) => relative <= bound.as_decimal(),
+            _ => false,
+        };
+        within_absolute || within_relative
+    }
+}
+
+/// Tolerances applied by [`reconcile_quotes`] and [`reconcile_history`].
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ReconciliationConfig {
+    /// Tolerance for price fields (last, bid, ask, previous close, OHLC).
+    #[serde(default)]
+    pub price: Tolerance,
+    /// Tolerance for volume and size fields.
+    #[serde(default)]
+    pub volume: Tolerance,
+}
+
+impl ReconciliationConfig {
+    /// Build a configuration from price and volume tolerances.
+    #[must_use]
+    pub const fn new(price: Tolerance, volume: Tolerance) -> Self {
+        Self { price, volume }
+    }
+}
+
+/// Measured difference between two values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deviation {
+    /// Absolute difference `|left - right|`.
+    pub absolute: Decimal,
+    /// Absolute difference divided by the larger magnitude of the two values;
+    /// `None` when both values are zero or the division overflows.
+    pub relative: Option<Decimal>,
+}
+
+impl Deviation {
+    /// Measure the deviation between `left` and `right`.
+    ///
+    /// Returns `None` when the difference overflows the active decimal backend.
+    #[must_use]
+    pub fn between(left: &Decimal, right: &Decimal) -> Option<Self> {
+        let absolute = abs(&decimal::checked_sub(left, right)?)?;
+        let scale = std::cmp::max(abs(left)?, abs(right)?);
+        let relative = if scale == decimal::zero() {
+            None
+        } else {
+            decimal::checked_div(&absolute, &scale)
+        };
+        Some(Self { absolute, relative })
+    }
+}
+
+fn abs(value: &Decimal) -> Option<Decimal> {
+    let zero = decimal::zero();
+    if value < &zero {
+        decimal::checked_sub(&zero, value)
+    } else {
+        Some(decimal::clone_decimal(value))
+    }
+}
+
+/// One of the two reconciled inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReconciliationSide {
+    /// The first (left) input.
+    Left,
+    /// The second (right) input.
+    Right,
+}
+
+impl ReconciliationSide {
+    /// Returns the canonical snake-case string representation.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Left => "left",
+            Self::Right => "right",
+        }
+    }
+}
+
+impl AsRef<str> for ReconciliationSide {
+    fn as_ref(&self) -> &str {
+        (*self).as_str()
+    }
+}
+
+impl fmt::Display for ReconciliationSide {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str((*self).as_str())
+    }
+}
+
+/// Category of a reconciliation [`Discrepancy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum DiscrepancyKind {
+    /// A candle timestamp is present on only one side; `side` names the side
+    /// missing the candle.
+    MissingCandle,
+    /// Several candles share a timestamp on one side; only the first is
+    /// compared. `side` names the side carrying the duplicate.
+    DuplicateCandle,
+    /// A field is populated on only one side; `side` names the side missing
+    /// the value.
+    MissingValue,
+    /// A price field differs beyond the price tolerance.
+    PriceDeviation,
+    /// A volume or size field differs beyond the volume tolerance.
+    VolumeDeviation,
+    /// The records are denominated in different currencies; price fields of
+    /// the affected record are not compared.
+    CurrencyMismatch,
+    /// The history responses report different OHLC price bases; candle prices
+    /// are still compared but deviations may be expected.
+    PriceBasisMismatch,
+}
+
+impl DiscrepancyKind {
+    /// Returns the canonical snake-case string representation.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::MissingCandle => "missing_candle",
+            Self::DuplicateCandle => "duplicate_candle",
+            Self::MissingValue => "missing_value",
+            Self::PriceDeviation => "price_deviation",
+            Self::VolumeDeviation => "volume_deviation",
+            Self::CurrencyMismatch => "currency_mismatch",
+            Self::PriceBasisMismatch => "price_basis_mismatch",
+        }
+    }
+}
+
+impl AsRef<str> for DiscrepancyKind {
+    fn as_ref(&self) -> &str {
+        (*self).as_str()
+    }
+}
+
+impl fmt::Display for DiscrepancyKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str((*self).as_str())
+    }
+}
+
+/// Field compared during reconciliation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ReconciledField {
+    /// Quote last price.
+    Price,
+    /// Quote best bid price.
+    Bid,
+    /// Quote best ask price.
+    Ask,
+    /// Quote best bid size.
+    BidSize,
+    /// Quote best ask size.
+    AskSize,
+    /// Quote previous close.
+    PreviousClose,
+    /// Quote day volume.
+    DayVolume,
+    /// Candle open.
+    Open,
+    /// Candle high.
+    High,
+    /// Candle low.
+    Low,
+    /// Candle close.
+    Close,
+    /// Candle raw close.
+    CloseUnadj,
+    /// Candle volume.
+    Volume,
+}
+
+impl ReconciledField {
+    /// Returns the canonical snake-case string representation.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Price => "price",
+            Self::Bid => "bid",
+            Self::Ask => "ask",
+            Self::BidSize => "bid_size",
+            Self::AskSize => "ask_size",
+            Self::PreviousClose => "previous_close",
+            Self::DayVolume => "day_volume",
+            Self::Open => "open",
+            Self::High => "high",
+            Self::Low => "low",
+            Self::Close => "close",
+            Self::CloseUnadj => "close_unadj",
+            Self::Volume => "volume",
+        }
+    }
+}
+
+impl AsRef<str> for ReconciledField {
+    fn as_ref(&self) -> &str {
+        (*self).as_str()
+    }
+}
+
+impl fmt::Display for ReconciledField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str((*self).as_str())
+    }
+}
+
+/// A single disagreement between the two reconciled inputs.
+///
+/// Columns that do not apply to a discrepancy kind are `None`; for example a
+/// [`DiscrepancyKind::CurrencyMismatch`] carries currencies but no values.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "dataframe", derive(ToDataFrame))]
+pub struct Discrepancy {
+    /// Discrepancy category.
+    #[cfg_attr(feature = "dataframe", df_derive(as_str))]
+    pub kind: DiscrepancyKind,
+    /// Compared field, when the discrepancy concerns a single field.
+    #[cfg_attr(feature = "dataframe", df_derive(as_str))]
+    pub field: Option<ReconciledField>,
+    /// Side the discrepancy is attributed to, for one-sided kinds.
+    #[cfg_attr(feature = "dataframe", df_derive(as_str))]
+    pub side: Option<ReconciliationSide>,
+    /// Candle timestamp, for history discrepancies.
+    #[serde(default, with = "chrono::serde::ts_milliseconds_option")]
+    pub ts: Option<DateTime<Utc>>,
+    /// Left value.
+    #[serde(default, with = "paft_decimal::serde::option_canonical_str")]
+    pub left: Option<Decimal>,
+    /// Right value.
+    #[serde(default, with = "paft_decimal::serde::option_canonical_str")]
+    pub right: Option<Decimal>,
+    /// Absolute deviation between the values.
+    #[serde(default, with = "paft_decimal::serde::option_canonical_str")]
+    pub absolute_deviation: Option<Decimal>,
+    /// Relative deviation between the values.
+    #[serde(default, with = "paft_decimal::serde::option_canonical_str")]
+    pub relative_deviation: Option<Decimal>,
+    /// Left currency, for currency mismatches.
+    #[cfg_attr(feature = "dataframe", df_derive(as_str))]
+    pub left_currency: Option<Currency>,
+    /// Right currency, for currency mismatches.
+    #[cfg_attr(feature = "dataframe", df_derive(as_str))]
+    pub right_currency: Option<Currency>,
+}
+
+impl Discrepancy {
+    const fn new(kind: DiscrepancyKind) -> Self {
+        Self {
+            kind,
+            field: None,
+            side: None,
+            ts: None,
+            left: None,
+            right: None,
+            absolute_deviation: None,
+            relative_deviation: None,
+            left_currency: None,
+            right_currency: None,
+        }
+    }
+}
+
+/// Result of reconciling two payloads for one instrument.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReconciliationReport {
+    /// Instrument the payloads describe.
+    pub instrument: Instrument,
+    /// Number of record pairs compared (one for quotes, the number of
+    /// timestamps present on both sides for history).
+    pub compared: usize,
+    /// Discrepancies in comparison order.
+    pub discrepancies: Vec<Discrepancy>,
+}
+
+impl ReconciliationReport {
+    /// Return `true` when no discrepancy was found.
+    #[must_use]
+    pub const fn is_clean(&self) -> bool {
+        self.discrepancies.is_empty()
+    }
+
+    /// Iterate through discrepancies of the given kind.
+    pub fn of_kind(&self, kind: DiscrepancyKind) -> impl Iterator<Item = &Discrepancy> {
+        self.discrepancies
+            .iter()
+            .filter(move |discrepancy| discrepancy.kind == kind)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Measure {
+    Price,
+    Volume,
+}
+
+struct Comparer<'a> {
+    config: &'a ReconciliationConfig,
+    ts: Option<DateTime<Utc>>,
+    out: &'a mut Vec<Discrepancy>,
+}
+
+impl Comparer<'_> {
+    fn compare(
+        &mut self,
+        field: ReconciledField,
+        measure: Measure,
+        left: Option<&Decimal>,
+        right: Option<&Decimal>,
+    ) {
+        let (left, right) = match (left, right) {
+            (None, None) => return,
+            (Some(_), None) => {
+                self.missing(field, ReconciliationSide::Right);
+                return;
+            }
+            (None, Some(_)) => {
+                self.missing(field, ReconciliationSide::Left);
+                return;
+            }
+            (Some(left), Some(right)) => (left, right),
+        };
+
+        let (kind, tolerance) = match measure {
+            Measure::Price => (DiscrepancyKind::PriceDeviation, &self.config.price),
+            Measure::Volume => (DiscrepancyKind::VolumeDeviation, &self.config.volume),
+        };
+        let deviation = Deviation::between(left, right);
+        if deviation
+            .as_ref()
+            .is_some_and(|deviation| tolerance.accepts(deviation))
+        {
+            return;
+        }
+
+        let mut discrepancy = Discrepancy::new(kind);
+        discrepancy.field = Some(field);
+        discrepancy.ts = self.ts;
+        discrepancy.left = Some(decimal::clone_decimal(left));
+        discrepancy.right = Some(decimal::clone_decimal(right));
+        if let Some(deviation) = deviation {
+            discrepancy.absolute_deviation = Some(deviation.absolute);
+            discrepancy.relative_deviation = deviation.relative;
+        }
+        self.out.push(discrepancy);
+    }
+
+    fn missing(&mut self, field: ReconciledField, side: ReconciliationSide) {
+        let mut discrepancy = Discrepancy::new(DiscrepancyKind::MissingValue);
+        discrepancy.field = Some(field);
+        discrepancy.side = Some(side);
+        discrepancy.ts = self.ts;
+        self.out.push(discrepancy);
+    }
+
+    fn currencies_match(&mut self, left: &Currency, right: &Currency) -> bool {
+        if left == right {
+            return true;
+        }
+        let mut discrepancy = Discrepancy::new(DiscrepancyKind::CurrencyMismatch);
+        discrepancy.ts = self.ts;
+        discrepancy.left_currency = Some(left.clone());
+        discrepancy.right_currency = Some(right.clone());
+        self.out.push(discrepancy);
+        false
+    }
+}
+
+fn price(value: Option<&PriceAmount>) -> Option<&Decimal> {
+    value.map(PriceAmount::as_decimal)
+}
+
+fn quantity(value: Option<&QuantityAmount>) -> Option<&Decimal> {
+    value.map(QuantityAmount::as_decimal)
+}
+
+const fn level_price<M>(level: &GenericBookLevel<M>) -> &Decimal {
+    level.price.as_decimal()
+}
+
+fn level_size<M>(level: Option<&GenericBookLevel<M>>) -> Option<&Decimal> {
+    quantity(level.and_then(|level| level.size.as_ref()))
+}
+
+/// Compare two quotes for the same instrument.
+///
+/// Prices (last, bid, ask, previous close) are compared with
+/// [`ReconciliationConfig::price`] and are skipped when the quote currencies
+/// differ; sizes and day volume are compared with
+/// [`ReconciliationConfig::volume`]. Provider metadata, names, market state,
+/// and timestamps are not compared.
+///
+/// # Errors
+///
+/// Returns [`ReconciliationError::InstrumentMismatch`] when the quotes'
+/// instruments have different [`Instrument::unique_key`]s.
+pub fn reconcile_quotes<Q1, L1, Q2, L2>(
+    left: &GenericQuote<Q1, L1>,
+    right: &GenericQuote<Q2, L2>,
+    config: &ReconciliationConfig,
+) -> Result<ReconciliationReport, ReconciliationError> {
+    let left_key = left.instrument.unique_key();
+    let right_key = right.instrument.unique_key();
+    if left_key != right_key {
+        return Err(ReconciliationError::InstrumentMismatch {
+            left: left_key,
+            right: right_key,
+        });
+    }
+
+    let mut discrepancies = Vec::new();
+    let mut checks = Comparer {
+        config,
+        ts: None,
+        out: &mut discrepancies,
+    };
+
+    if checks.currencies_match(&left.currency, &right.currency) {
+        checks.compare(
+            ReconciledField::Price,
+            Measure::Price,
+            price(left.price.as_ref()),
+            price(right.price.as_ref()),
+        );
+        checks.compare(
+            ReconciledField::Bid,
+            Measure::Price,
+            left.bid.as_ref().map(level_price),
+            right.bid.as_ref().map(level_price),
+        );
+        checks.compare(
+            ReconciledField::Ask,
+            Measure::Price,
+            left.ask.as_ref().map(level_price),
+            right.ask.as_ref().map(level_price),
+        );
+        checks.compare(
+            ReconciledField::PreviousClose,
+            Measure::Price,
+            price(left.previous_close.as_ref()),
+            price(right.previous_close.as_ref()),
+        );
+    }
+    checks.compare(
+        ReconciledField::BidSize,
+        Measure::Volume,
+        level_size(left.bid.as_ref()),
+        level_size(right.bid.as_ref()),
+    );
+    checks.compare(
+        ReconciledField::AskSize,
+        Measure::Volume,
+        level_size(left.ask.as_ref()),
+        level_size(right.ask.as_ref()),
+    );
+    checks.compare(
+        ReconciledField::DayVolume,
+        Measure::Volume,
+        quantity(left.day_volume.as_ref()),
+        quantity(right.day_volume.as_ref()),
+    );
+
+    Ok(ReconciliationReport {
+        instrument: left.instrument.clone(),
+        compared: 1,
+        discrepancies,
+    })
+}
+
+fn index_candles<'a, C>(
+    candles: &'a [GenericCandle<C>],
+    side: ReconciliationSide,
+    discrepancies: &mut Vec<Discrepancy>,
+) -> BTreeMap<DateTime<Utc>, &'a GenericCandle<C>> {
+    let mut index = BTreeMap::new();
+    for candle in candles {
+        match index.entry(candle.ts) {
+            Entry::Vacant(entry) => {
+                entry.insert(candle);
+            }
+            Entry::Occupied(_) => {
+                let mut discrepancy = Discrepancy::new(DiscrepancyKind::DuplicateCandle);
+                discrepancy.side = Some(side);
+                discrepancy.ts = Some(candle.ts);
+                discrepancies.push(discrepancy);
+            }
+        }
+    }
+    index
+}
+
+/// Compare two history responses for `instrument`, matching candles by
+/// timestamp.
+///
+/// History responses do not carry their instrument, so the caller supplies
+/// it for the report. Candles present on only one side are reported as
+/// [`DiscrepancyKind::MissingCandle`]; matched candles have OHLC and raw close
+/// compared with [`ReconciliationConfig::price`] (skipped when the candle
+/// currencies differ) and volume with [`ReconciliationConfig::volume`].
+/// A [`DiscrepancyKind::PriceBasisMismatch`] and any duplicate timestamps are
+/// reported first, followed by per-timestamp discrepancies in chronological
+/// order regardless of input order.
+#[must_use]
+pub fn reconcile_history<R1, C1, R2, C2>(
+    instrument: &Instrument,
+    left: &GenericHistoryResponse<R1, C1>,
+    right: &GenericHistoryResponse<R2, C2>,
+    config: &ReconciliationConfig,
+) -> ReconciliationReport {
+    let mut discrepancies = Vec::new();
+
+    if left.price_basis != right.price_basis {
+        discrepancies.push(Discrepancy::new(DiscrepancyKind::PriceBasisMismatch));
+    }
+
+    let left_index = index_candles(&left.candles, ReconciliationSide::Left, &mut discrepancies);
+    let right_index = index_candles(
+        &right.candles,
+        ReconciliationSide::Right,
+        &mut discrepancies,
+    );
+
+    let mut timestamps: Vec<DateTime<Utc>> = left_index
+        .keys()
+        .chain(right_index.keys())
+        .copied()
+        .collect();
+    timestamps.sort_unstable();
+    timestamps.dedup();
+
+    let mut compared = 0;
+    for ts in timestamps {
+        let (left_candle, right_candle) = match (left_index.get(&ts), right_index.get(&ts)) {
+            (Some(left_candle), Some(right_candle)) => (*left_candle, *right_candle),
+            (Some(_), None) | (None, Some(_)) => {
+                let missing_side = if left_index.contains_key(&ts) {
+                    ReconciliationSide::Right
+                } else {
+                    ReconciliationSide::Left
+                };
+                let mut discrepancy = Discrepancy::new(DiscrepancyKind::MissingCandle);
+                discrepancy.side = Some(missing_side);
+                discrepancy.ts = Some(ts);
+                discrepancies.push(discrepancy);
+                continue;
+            }
+            (None, None) => continue,
+        };
+
+        compared += 1;
+        let mut checks = Comparer {
+            config,
+            ts: Some(ts),
+            out: &mut discrepancies,
+        };
+        if checks.currencies_match(&left_candle.currency, &right_candle.currency) {
+            let (lhs, rhs) = (&left_candle.ohlc, &right_candle.ohlc);
+            for (field, left_value, right_value) in [
+                (ReconciledField::Open, &lhs.open, &rhs.open),
+                (ReconciledField::High, &lhs.high, &rhs.high),
+                (ReconciledField::Low, &lhs.low, &rhs.low),
+                (ReconciledField::Close, &lhs.close, &rhs.close),
+            ] {
+                checks.compare(
+                    field,
+                    Measure::Price,
+                    Some(left_value.as_decimal()),
+                    Some(right_value.as_decimal()),
+                );
+            }
+            checks.compare(
+                ReconciledField::CloseUnadj,
+                Measure::Price,
+                price(left_candle.close_unadj.as_ref()),
+                price(right_candle.close_unadj.as_ref()),
+            );
+        }
+        checks.compare(
+            ReconciledField::Volume,
+            Measure::Volume,
+            quantity(left_candle.volume.as_ref()),
+            quantity(right_candle.volume.as_ref()),
+        );
+    }
+
+    ReconciliationReport {
+        instrument: instrument.clone(),
+        compared,
+        discrepancies,
+    }
+}
@@ -463,3 +463,33 @@ fn vec_candle_update_to_dataframe_smoke() {
     let cols = df.get_column_names();
     assert!(cols.iter().any(|c| c.as_str() == "instrument"));
 }
+
+#[test]
+fn reconciliation_discrepancies_to_dataframe() {
+    use paft_market::reconciliation::{ReconciliationConfig, reconcile_history};
+    use paft_market::responses::history::{HistoryResponse, OhlcPriceBasis};
+
+    let history = |candles| HistoryResponse {
+        candles,
+        actions: vec![],
+        price_basis: OhlcPriceBasis::raw(),
+        meta: None,
+        provider: (),
+    };
+    let left = history(vec![
+        candle(sample_ts(1_700_000_000), 150, 155, 148, 152),
+        candle(sample_ts(1_700_000_060), 152, 156, 149, 154),
+    ]);
+    let right = history(vec![candle(sample_ts(1_700_000_000), 150, 155, 148, 153)]);
+    let instrument = Instrument::from_symbol("AAPL", AssetKind::Equity).unwrap();
+
+    let report = reconcile_history(&instrument, &left, &right, &ReconciliationConfig::default());
+    let df = report.discrepancies.to_dataframe().unwrap();
+    assert_eq!(df.height(), 2);
+    let kinds = df.column("kind").unwrap().str().unwrap();
+    assert_eq!(kinds.get(0), Some("price_deviation"));
+    assert_eq!(kinds.get(1), Some("missing_candle"));
+    let fields = df.column("field").unwrap().str().unwrap();
+    assert_eq!(fields.get(0), Some("close"));
+    assert_eq!(fields.get(1), None);
+}
@@ -0,0 +1,233 @@
+use chrono::{DateTime, Utc};
+use paft_decimal::Decimal;
+use paft_domain::{
+    AssetKind, Exchange, Instrument, MarketState, MergeError, MergePolicy, MergeSource,
+};
+use paft_market::market::orderbook::BookLevel;
+use paft_market::market::quote::Quote;
+use paft_money::{Currency, IsoCurrency, PriceAmount, QuantityAmount};
+use std::str::FromStr;
+
+const fn usd() -> Currency {
+    Currency::Iso(IsoCurrency::USD)
+}
+
+fn amount(value: &str) -> PriceAmount {
+    PriceAmount::new(Decimal::from_str(value).unwrap())
+}
+
+fn quantity(value: &str) -> QuantityAmount {
+    QuantityAmount::from_decimal(Decimal::from_str(value).unwrap()).unwrap()
+}
+
+fn aapl() -> Instrument {
+    Instrument::from_symbol("AAPL", AssetKind::Equity).unwrap()
+}
+
+const fn ts(secs: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(secs, 0).unwrap()
+}
+
+/// Provider A: identity and session data, a wide top of book.
+fn provider_a() -> Quote {
+    let mut quote = Quote::new(aapl(), usd());
+    quote.name = Some("Apple Inc.".to_string());
+    quote.market_state = Some(MarketState::Regular);
+    quote.price = Some(amount("150.00"));
+    quote.bid = Some(BookLevel::new(amount("149.90"), Some(quantity("100"))));
+    quote.ask = Some(BookLevel::new(amount("150.10"), None));
+    quote.as_of = Some(ts(1_700_000_000));
+    quote
+}
+
+/// Provider B: tighter top of book and volume, newer, no name.
+fn provider_b() -> Quote {
+    let mut quote = Quote::new(aapl(), usd());
+    quote.price = Some(amount("150.02"));
+    quote.bid = Some(BookLevel::new(amount("149.99"), Some(quantity("300"))));
+    quote.ask = Some(BookLevel::new(amount("150.01"), Some(quantity("200"))));
+    quote.day_volume = Some(quantity("1000000"));
+    quote.as_of = Some(ts(1_700_000_005));
+    quote
+}
+
+#[test]
+fn precedence_fills_missing_fields_from_lower_ranked_sources() {
+    let (a, b) = (provider_a(), provider_b());
+    let merged = Quote::merge(
+        &[MergeSource::new("a", &a), MergeSource::new("b", &b)],
+        &MergePolicy::precedence(),
+    )
+    .unwrap();
+
+    let quote = &merged.record;
+    assert_eq!(quote.name.as_deref(), Some("Apple Inc."));
+    assert_eq!(quote.price, Some(amount("150.00")));
+    assert_eq!(quote.bid, a.bid);
+    assert_eq!(quote.day_volume, Some(quantity("1000000")));
+    assert_eq!(quote.as_of, Some(ts(1_700_000_000)));
+
+    assert_eq!(merged.source_of("name"), Some("a"));
+    assert_eq!(merged.source_of("price"), Some("a"));
+    assert_eq!(merged.source_of("day_volume"), Some("b"));
+    assert_eq!(merged.source_of("instrument"), Some("a"));
+    assert_eq!(merged.source_of("previous_close"), None);
+}
+
+#[test]
+fn field_precedence_overrides_default_order() {
+    let (a, b) = (provider_a(), provider_b());
+    let policy = MergePolicy::precedence()
+        .with_field_precedence("bid", ["b"])
+        .with_field_precedence("ask", ["b", "a"])
+        .with_field_precedence("name", ["unavailable", "b"]);
+    let merged = Quote::merge(
+        &[MergeSource::new("a", &a), MergeSource::new("b", &b)],
+        &policy,
+    )
+    .unwrap();
+
+    // Levels move whole: B's price comes with B's size.
+    assert_eq!(merged.record.bid, b.bid);
+    assert_eq!(merged.record.ask, b.ask);
+    assert_eq!(merged.record.price, a.price);
+    // B has no name, so the default order falls back to A.
+    assert_eq!(merged.source_of("name"), Some("a"));
+    assert_eq!(merged.source_of("bid"), Some("b"));
+    assert_eq!(merged.source_of("ask"), Some("b"));
+}
+
+#[test]
+fn freshness_prefers_most_recent_as_of() {
+    let (a, b) = (provider_a(), provider_b());
+    let mut undated = provider_a();
+    undated.as_of = None;
+    undated.previous_close = Some(amount("148.00"));
+    undated.price = Some(amount("1.00"));
+
+    let merged = Quote::merge(
+        &[
+            MergeSource::new("undated", &undated),
+            MergeSource::new("a", &a),
+            MergeSource::new("b", &b),
+        ],
+        &MergePolicy::freshness(),
+    )
+    .unwrap();
+
+    assert_eq!(merged.record.price, b.price);
+    assert_eq!(merged.record.name, a.name);
+    assert_eq!(merged.record.previous_close, Some(amount("148.00")));
+    assert_eq!(merged.record.as_of, Some(ts(1_700_000_005)));
+    assert_eq!(merged.source_of("price"), Some("b"));
+    assert_eq!(merged.source_of("name"), Some("a"));
+    assert_eq!(merged.source_of("previous_close"), Some("undated"));
+    assert_eq!(merged.source_of("provider"), Some("b"));
+}
+
+#[test]
+fn differing_instruments_are_refused() {
+    let a = provider_a();
+    let mut b = provider_b();
+    b.instrument = Instrument::from_symbol("MSFT", AssetKind::Equity).unwrap();
+
+    let err = Quote::merge(
+        &[MergeSource::new("a", &a), MergeSource::new("b", &b)],
+        &MergePolicy::precedence(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        MergeError::InstrumentMismatch {
+            source_name: "b".to_string(),
+            expected: "EQUITY|SYMBOL|4:AAPL".to_string(),
+            found: "EQUITY|SYMBOL|4:MSFT".to_string(),
+        }
+    );
+}
+
+#[test]
+fn same_symbol_on_different_venues_is_refused() {
+    let a = provider_a();
+    let mut b = provider_b();
+    b.instrument =
+        Instrument::from_symbol_and_exchange("AAPL", Exchange::NASDAQ, AssetKind::Equity).unwrap();
+
+    assert!(matches!(
+        Quote::merge(
+            &[MergeSource::new("a", &a), MergeSource::new("b", &b)],
+            &MergePolicy::precedence(),
+        ),
+        Err(MergeError::InstrumentMismatch { .. })
+    ));
+}
+
+#[test]
+fn differing_currencies_are_refused() {
+    let a = provider_a();
+    let mut b = provider_b();
+    b.currency = Currency::Iso(IsoCurrency::EUR);
+
+    let err = Quote::merge(
+        &[MergeSource::new("a", &a), MergeSource::new("b", &b)],
+        &MergePolicy::precedence(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        MergeError::CurrencyMismatch {
+            field: None,
+            source_name: "b".to_string(),
+            expected: "USD".to_string(),
+            found: "EUR".to_string(),
+        }
+    );
+}
+
+#[test]
+fn invalid_inputs_are_refused() {
+    let a = provider_a();
+
+    assert_eq!(
+        Quote::merge(&[], &MergePolicy::precedence()).unwrap_err(),
+        MergeError::NoSources
+    );
+    assert_eq!(
+        Quote::merge(
+            &[MergeSource::new("a", &a), MergeSource::new("a", &a)],
+            &MergePolicy::precedence(),
+        )
+        .unwrap_err(),
+        MergeError::DuplicateSource {
+            name: "a".to_string()
+        }
+    );
+    assert_eq!(
+        Quote::merge(
+            &[MergeSource::new("a", &a)],
+            &MergePolicy::precedence().with_field_precedence("last_price", ["a"]),
+        )
+        .unwrap_err(),
+        MergeError::UnknownField {
+            field: "last_price".to_string()
+        }
+    );
+}
+
+#[test]
+fn merged_quote_serde_roundtrip() {
+    let (a, b) = (provider_a(), provider_b());
+    let merged = Quote::merge(
+        &[MergeSource::new("a", &a), MergeSource::new("b", &b)],
+        &MergePolicy::precedence(),
+    )
+    .unwrap();
+
+    let json = serde_json::to_value(&merged).unwrap();
+    assert_eq!(json["sources"]["day_volume"], "b");
+    assert_eq!(json["record"]["name"], "Apple Inc.");
+    assert_eq!(
+        serde_json::from_value::<paft_domain::Merged<Quote>>(json).unwrap(),
+        merged
+    );
+}
@@ -1,8 +1,8 @@
-use chrono::DateTime;
+use chrono::{DateTime, TimeDelta};
 use paft_decimal::Decimal;
 use paft_domain::{AssetKind, Exchange, Instrument, MarketState};
 use paft_market::market::orderbook::BookLevel;
-use paft_market::market::quote::{Quote, QuoteUpdate};
+use paft_market::market::quote::{Quote, QuoteFreshnessPolicy, QuoteUpdate};
 use paft_money::{Currency, IsoCurrency, PriceAmount, QuantityAmount};
 use std::str::FromStr;
 
@@ -474,3 +474,122 @@ fn deserialization_handles_missing_optional_fields() {
     assert!(deserialized.bid.is_none());
     assert!(deserialized.ask.is_none());
 }
+
+fn dec(value: &str) -> Decimal {
+    Decimal::from_str(value).unwrap()
+}
+
+fn quote_with_book(bid: &str, ask: &str) -> Quote {
+    let mut quote = Quote::new(aapl(), usd());
+    quote.bid = Some(BookLevel::new(amount(dec(bid)), None));
+    quote.ask = Some(BookLevel::new(amount(dec(ask)), None));
+    quote
+}
+
+#[test]
+fn quote_change_and_change_percent_are_exact() {
+    let mut quote = Quote::new(aapl(), usd());
+    quote.price = Some(amount(dec("150.75")));
+    quote.previous_close = Some(amount(dec("150")));
+
+    assert_eq!(quote.change(), Some(amount(dec("0.75"))));
+    assert_eq!(quote.change_percent(), Some(dec("0.5")));
+
+    quote.price = Some(amount(dec("120")));
+    assert_eq!(quote.change(), Some(amount(dec("-30"))));
+    assert_eq!(quote.change_percent(), Some(dec("-20")));
+}
+
+#[test]
+fn quote_change_is_none_when_inputs_missing_or_close_is_zero() {
+    let mut quote = Quote::new(aapl(), usd());
+    assert!(quote.change().is_none());
+    assert!(quote.change_percent().is_none());
+
+    quote.price = Some(amount(10));
+    assert!(quote.change().is_none(), "previous close missing");
+
+    quote.previous_close = Some(amount(0));
+    assert_eq!(quote.change(), Some(amount(10)));
+    assert!(quote.change_percent().is_none(), "zero previous close");
+}
+
+#[test]
+fn quote_mid_spread_and_spread_bps() {
+    let quote = quote_with_book("99.95", "100.05");
+
+    assert_eq!(quote.mid(), Some(amount(dec("100"))));
+    assert_eq!(quote.spread(), Some(amount(dec("0.1"))));
+    assert_eq!(quote.spread_bps(), Some(dec("10")));
+}
+
+#[test]
+fn quote_spread_is_negative_for_crossed_book() {
+    let quote = quote_with_book("101", "100");
+    assert_eq!(quote.spread(), Some(amount(dec("-1"))));
+}
+
+#[test]
+fn quote_top_of_book_analytics_require_both_sides() {
+    let mut quote = quote_with_book("99", "101");
+    quote.ask = None;
+    assert!(quote.mid().is_none());
+    assert!(quote.spread().is_none());
+    assert!(quote.spread_bps().is_none());
+
+    let zero_mid = quote_with_book("-1", "1");
+    assert_eq!(zero_mid.spread(), Some(amount(2)));
+    assert!(zero_mid.spread_bps().is_none(), "zero midpoint");
+}
+
+#[test]
+fn quote_age_clamps_future_timestamps() {
+    let now = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+    let mut quote = Quote::new(aapl(), usd());
+    assert!(quote.age(now).is_none());
+
+    quote.as_of = Some(now - TimeDelta::seconds(30));
+    assert_eq!(quote.age(now), Some(TimeDelta::seconds(30)));
+
+    quote.as_of = Some(now + TimeDelta::seconds(5));
+    assert_eq!(quote.age(now), Some(TimeDelta::zero()));
+}
+
+#[test]
+fn quote_freshness_depends_on_market_state() {
+    let now = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+    let policy = QuoteFreshnessPolicy::new(TimeDelta::seconds(15));
+    let mut quote = Quote::new(aapl(), usd());
+    assert!(
+        quote
+            .is_fresh(now, &MarketState::Regular, &policy)
+            .is_none()
+    );
+
+    quote.as_of = Some(now - TimeDelta::seconds(15));
+    assert_eq!(
+        quote.is_fresh(now, &MarketState::Regular, &policy),
+        Some(true)
+    );
+
+    quote.as_of = Some(now - TimeDelta::minutes(10));
+    assert_eq!(
+        quote.is_fresh(now, &MarketState::Regular, &policy),
+        Some(false)
+    );
+    assert_eq!(quote.is_fresh(now, &MarketState::Pre, &policy), Some(false));
+    assert_eq!(
+        quote.is_fresh(now, &MarketState::Closed, &policy),
+        Some(true)
+    );
+
+    let strict = policy.with_max_age_non_trading(TimeDelta::minutes(5));
+    assert_eq!(
+        strict.max_age_for(&MarketState::Halted),
+        Some(TimeDelta::minutes(5))
+    );
+    assert_eq!(
+        quote.is_fresh(now, &MarketState::Closed, &strict),
+        Some(false)
+    );
+}
@@ -0,0 +1,276 @@
+use chrono::{DateTime, Utc};
+use paft_decimal::{Decimal, NonNegativeDecimal};
+use paft_domain::{AssetKind, Instrument};
+use paft_market::market::orderbook::BookLevel;
+use paft_market::market::quote::Quote;
+use paft_market::reconciliation::{
+    DiscrepancyKind, ReconciledField, ReconciliationConfig, ReconciliationError,
+    ReconciliationSide, Tolerance, reconcile_history, reconcile_quotes,
+};
+use paft_market::responses::history::{Candle, HistoryResponse, Ohlc, OhlcPriceBasis, PriceBasis};
+use paft_money::{Currency, IsoCurrency, PriceAmount, QuantityAmount};
+use std::str::FromStr;
+
+const fn usd() -> Currency {
+    Currency::Iso(IsoCurrency::USD)
+}
+
+fn dec(value: &str) -> Decimal {
+    Decimal::from_str(value).unwrap()
+}
+
+fn amount(value: &str) -> PriceAmount {
+    PriceAmount::new(dec(value))
+}
+
+fn quantity(value: &str) -> QuantityAmount {
+    QuantityAmount::from_decimal(dec(value)).unwrap()
+}
+
+fn bound(value: &str) -> NonNegativeDecimal {
+    NonNegativeDecimal::new(dec(value)).unwrap()
+}
+
+fn aapl() -> Instrument {
+    Instrument::from_symbol("AAPL", AssetKind::Equity).unwrap()
+}
+
+const fn ts(secs: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(secs, 0).unwrap()
+}
+
+fn quote(price: &str, bid: &str, ask: &str) -> Quote {
+    let mut quote = Quote::new(aapl(), usd());
+    quote.price = Some(amount(price));
+    quote.bid = Some(BookLevel::new(amount(bid), Some(quantity("100"))));
+    quote.ask = Some(BookLevel::new(amount(ask), Some(quantity("200"))));
+    quote.day_volume = Some(quantity("1000000"));
+    quote
+}
+
+fn candle(secs: i64, close: &str, volume: &str) -> Candle {
+    let mut candle = Candle::new(
+        ts(secs),
+        usd(),
+        Ohlc::new(amount("100"), amount("110"), amount("95"), amount(close)),
+    );
+    candle.volume = Some(quantity(volume));
+    candle
+}
+
+const fn history(candles: Vec<Candle>) -> HistoryResponse {
+    HistoryResponse {
+        candles,
+        actions: vec![],
+        price_basis: OhlcPriceBasis::raw(),
+        meta: None,
+        provider: (),
+    }
+}
+
+#[test]
+fn identical_quotes_reconcile_cleanly() {
+    let left = quote("150", "149.99", "150.01");
+    let report = reconcile_quotes(&left, &left.clone(), &ReconciliationConfig::default()).unwrap();
+    assert!(report.is_clean());
+    assert_eq!(report.compared, 1);
+    assert_eq!(report.instrument, aapl());
+}
+
+#[test]
+fn quote_price_deviations_respect_tolerances() {
+    let left = quote("150", "149.99", "150.01");
+    let right = quote("149.97", "149.98", "150.01");
+
+    let exact = reconcile_quotes(&left, &right, &ReconciliationConfig::default()).unwrap();
+    let fields: Vec<_> = exact
+        .of_kind(DiscrepancyKind::PriceDeviation)
+        .map(|d| d.field.unwrap())
+        .collect();
+    assert_eq!(fields, vec![ReconciledField::Price, ReconciledField::Bid]);
+
+    let price_deviation = &exact.discrepancies[0];
+    assert_eq!(price_deviation.left, Some(dec("150")));
+    assert_eq!(price_deviation.right, Some(dec("149.97")));
+    assert_eq!(price_deviation.absolute_deviation, Some(dec("0.03")));
+    assert_eq!(price_deviation.relative_deviation, Some(dec("0.0002")));
+
+    let absolute =
+        ReconciliationConfig::new(Tolerance::absolute(bound("0.01")), Tolerance::exact());
+    let report = reconcile_quotes(&left, &right, &absolute).unwrap();
+    assert_eq!(report.discrepancies.len(), 1);
+    assert_eq!(report.discrepancies[0].field, Some(ReconciledField::Price));
+
+    let relative =
+        ReconciliationConfig::new(Tolerance::relative(bound("0.0002")), Tolerance::exact());
+    assert!(
+        reconcile_quotes(&left, &right, &relative)
+            .unwrap()
+            .is_clean()
+    );
+}
+
+#[test]
+fn quote_missing_values_and_volume_deviations_are_reported() {
+    let left = quote("150", "149.99", "150.01");
+    let mut right = left.clone();
+    right.price = None;
+    right.day_volume = Some(quantity("1001000"));
+
+    let report = reconcile_quotes(&left, &right, &ReconciliationConfig::default()).unwrap();
+    assert_eq!(report.discrepancies.len(), 2);
+    let missing = &report.discrepancies[0];
+    assert_eq!(missing.kind, DiscrepancyKind::MissingValue);
+    assert_eq!(missing.field, Some(ReconciledField::Price));
+    assert_eq!(missing.side, Some(ReconciliationSide::Right));
+    let volume = &report.discrepancies[1];
+    assert_eq!(volume.kind, DiscrepancyKind::VolumeDeviation);
+    assert_eq!(volume.field, Some(ReconciledField::DayVolume));
+
+    let lenient = ReconciliationConfig::new(Tolerance::exact(), Tolerance::relative(bound("0.01")));
+    let report = reconcile_quotes(&left, &right, &lenient).unwrap();
+    assert_eq!(report.discrepancies.len(), 1);
+}
+
+#[test]
+fn quote_currency_mismatch_skips_price_fields() {
+    let left = quote("150", "149.99", "150.01");
+    let mut right = quote("140", "139.99", "140.01");
+    right.currency = Currency::Iso(IsoCurrency::EUR);
+
+    let report = reconcile_quotes(&left, &right, &ReconciliationConfig::default()).unwrap();
+    assert_eq!(report.discrepancies.len(), 1);
+    let mismatch = &report.discrepancies[0];
+    assert_eq!(mismatch.kind, DiscrepancyKind::CurrencyMismatch);
+    assert_eq!(mismatch.left_currency, Some(usd()));
+    assert_eq!(
+        mismatch.right_currency,
+        Some(Currency::Iso(IsoCurrency::EUR))
+    );
+}
+
+#[test]
+fn quote_instrument_mismatch_is_an_error() {
+    let left = quote("150", "149.99", "150.01");
+    let mut right = left.clone();
+    right.instrument = Instrument::from_symbol("MSFT", AssetKind::Equity).unwrap();
+
+    let err = reconcile_quotes(&left, &right, &ReconciliationConfig::default()).unwrap_err();
+    assert_eq!(
+        err,
+        ReconciliationError::InstrumentMismatch {
+            left: "EQUITY|SYMBOL|4:AAPL".to_string(),
+            right: "EQUITY|SYMBOL|4:MSFT".to_string(),
+        }
+    );
+}
+
+#[test]
+fn history_reports_missing_candles_and_deviations_in_time_order() {
+    let left = history(vec![
+        candle(3, "105", "1000"),
+        candle(1, "101", "1000"),
+        candle(2, "102", "1000"),
+    ]);
+    let right = history(vec![
+        candle(1, "101", "1000"),
+        candle(2, "102.5", "1200"),
+        candle(4, "104", "1000"),
+    ]);
+
+    let report = reconcile_history(&aapl(), &left, &right, &ReconciliationConfig::default());
+    assert_eq!(report.compared, 2);
+
+    let summary: Vec<_> = report
+        .discrepancies
+        .iter()
+        .map(|d| (d.kind, d.ts.unwrap().timestamp(), d.field, d.side))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (
+                DiscrepancyKind::PriceDeviation,
+                2,
+                Some(ReconciledField::Close),
+                None
+            ),
+            (
+                DiscrepancyKind::VolumeDeviation,
+                2,
+                Some(ReconciledField::Volume),
+                None
+            ),
+            (
+                DiscrepancyKind::MissingCandle,
+                3,
+                None,
+                Some(ReconciliationSide::Right)
+            ),
+            (
+                DiscrepancyKind::MissingCandle,
+                4,
+                None,
+                Some(ReconciliationSide::Left)
+            ),
+        ]
+    );
+}
+
+#[test]
+fn history_reports_basis_mismatch_duplicates_and_candle_currency() {
+    let mut left = history(vec![candle(1, "101", "1000"), candle(1, "999", "1000")]);
+    left.price_basis = OhlcPriceBasis::uniform(PriceBasis::split_adjusted_latest());
+    let mut eur_candle = candle(1, "90", "1000");
+    eur_candle.currency = Currency::Iso(IsoCurrency::EUR);
+    let right = history(vec![eur_candle]);
+
+    let report = reconcile_history(&aapl(), &left, &right, &ReconciliationConfig::default());
+    let kinds: Vec<_> = report.discrepancies.iter().map(|d| d.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            DiscrepancyKind::PriceBasisMismatch,
+            DiscrepancyKind::DuplicateCandle,
+            DiscrepancyKind::CurrencyMismatch,
+        ]
+    );
+    assert_eq!(report.discrepancies[1].side, Some(ReconciliationSide::Left));
+}
+
+#[test]
+fn report_serde_roundtrip() {
+    let left = quote("150", "149.99", "150.01");
+    let right = quote("150.5", "149.99", "150.01");
+    let report = reconcile_quotes(&left, &right, &ReconciliationConfig::default()).unwrap();
+
+    let json = serde_json::to_value(&report).unwrap();
+    let discrepancy = &json["discrepancies"][0];
+    assert_eq!(discrepancy["kind"], "price_deviation");
+    assert_eq!(discrepancy["field"], "price");
+    assert_eq!(discrepancy["absolute_deviation"], "0.5");
+
+    let decoded = serde_json::from_value(json).unwrap();
+    assert_eq!(report, decoded);
+}
+
+#[test]
+fn config_serde_roundtrip() {
+    let config = ReconciliationConfig::new(
+        Tolerance::absolute(bound("0.01")),
+        Tolerance::relative(bound("0.05")),
+    );
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(
+        json,
+        r#"{"price":{"absolute":"0.01","relative":null},"volume":{"absolute":null,"relative":"0.05"}}"#
+    );
+    assert_eq!(
+        serde_json::from_str::<ReconciliationConfig>(&json).unwrap(),
+        config
+    );
+    assert_eq!(
+        serde_json::from_str::<ReconciliationConfig>("{}").unwrap(),
+        ReconciliationConfig::default()
+    );
+}
@@ -0,0 +1,237 @@
+use paft_decimal::{Decimal, PositiveDecimal, RoundingStrategy};
+use paft_domain::{AssetKind, Exchange, Instrument};
+use paft_market::MarketError;
+use paft_market::market::orderbook::{BookLevel, OrderBook};
+use paft_market::market::tick_size::{TickBand, TickSizeTable, TickSizeTables};
+use paft_money::{Currency, IsoCurrency, PriceAmount};
+use serde_json::json;
+
+fn decimal(value: &str) -> Decimal {
+    value.parse().unwrap()
+}
+
+fn price(value: &str) -> PriceAmount {
+    PriceAmount::new(decimal(value))
+}
+
+fn tick(value: &str) -> PositiveDecimal {
+    PositiveDecimal::new(decimal(value)).unwrap()
+}
+
+fn band(min_price: &str, size: &str) -> TickBand {
+    TickBand::new(price(min_price), tick(size))
+}
+
+#[test]
+fn us_sub_penny_switches_to_cents_at_one_dollar() {
+    let table = TickSizeTable::us_sub_penny();
+    assert_eq!(table.tick_for(&price("0.9999")), Some(&tick("0.0001")));
+    assert_eq!(table.tick_for(&price("1")), Some(&tick("0.01")));
+    assert_eq!(table.tick_for(&price("-0.01")), None);
+
+    assert!(table.is_on_tick(&price("0.5123")));
+    assert!(!table.is_on_tick(&price("0.51235")));
+    assert!(table.is_on_tick(&price("1.01")));
+    assert!(!table.is_on_tick(&price("1.005")));
+    assert!(!table.is_on_tick(&price("-0.01")));
+
+    assert_eq!(
+        table
+            .snap(&price("1.005"), RoundingStrategy::ToZero)
+            .unwrap(),
+        price("1")
+    );
+    assert_eq!(
+        table
+            .snap(&price("1.005"), RoundingStrategy::MidpointNearestEven)
+            .unwrap(),
+        price("1")
+    );
+    // Snapping up across the band boundary lands on a valid upper-band tick.
+    let snapped = table
+        .snap(&price("0.99996"), RoundingStrategy::AwayFromZero)
+        .unwrap();
+    assert_eq!(snapped, price("1"));
+    assert!(table.is_on_tick(&snapped));
+
+    assert_eq!(
+        table.snap(&price("-1"), RoundingStrategy::ToZero),
+        Err(MarketError::PriceOutsideTickTable {
+            price: decimal("-1"),
+        })
+    );
+    assert_eq!(
+        table.validate_price(&price("2.345")),
+        Err(MarketError::PriceOffTick {
+            price: decimal("2.345"),
+        })
+    );
+}
+
+#[test]
+fn mifid_tables_follow_the_liquidity_bands() {
+    let least_liquid = TickSizeTable::mifid(1).unwrap();
+    let most_liquid = TickSizeTable::mifid(6).unwrap();
+    assert_eq!(least_liquid.bands().len(), 19);
+
+    assert_eq!(least_liquid.tick_for(&price("0.05")), Some(&tick("0.0005")));
+    assert_eq!(least_liquid.tick_for(&price("15.37")), Some(&tick("0.1")));
+    assert_eq!(least_liquid.tick_for(&price("75000")), Some(&tick("500")));
+    assert_eq!(most_liquid.tick_for(&price("0.15")), Some(&tick("0.0001")));
+    assert_eq!(most_liquid.tick_for(&price("1.5")), Some(&tick("0.0002")));
+    assert_eq!(most_liquid.tick_for(&price("15.37")), Some(&tick("0.002")));
+    assert_eq!(most_liquid.tick_for(&price("50000")), Some(&tick("10")));
+    assert_eq!(
+        TickSizeTable::mifid(3).unwrap().tick_for(&price("250")),
+        Some(&tick("0.5"))
+    );
+
+    assert!(!least_liquid.is_on_tick(&price("15.37")));
+    assert!(most_liquid.is_on_tick(&price("15.372")));
+    assert_eq!(
+        least_liquid
+            .snap(&price("15.37"), RoundingStrategy::MidpointAwayFromZero)
+            .unwrap(),
+        price("15.4")
+    );
+
+    for liquidity_band in 1..=6 {
+        let table = TickSizeTable::mifid(liquidity_band).unwrap();
+        assert_eq!(TickSizeTable::new(table.clone().into_bands()), Ok(table));
+    }
+    assert_eq!(
+        TickSizeTable::mifid(0),
+        Err(MarketError::InvalidLiquidityBand(0))
+    );
+    assert_eq!(
+        TickSizeTable::mifid(7),
+        Err(MarketError::InvalidLiquidityBand(7))
+    );
+}
+
+#[test]
+fn tables_reject_malformed_bands() {
+    let reason = |bands| match TickSizeTable::new(bands) {
+        Err(MarketError::InvalidTickSizeTable { reason }) => reason,
+        other => panic!("expected an invalid table, got {other:?}"),
+    };
+    assert_eq!(reason(vec![]), "at least one band is required");
+    assert_eq!(
+        reason(vec![band("1", "0.01")]),
+        "the first band must start at zero"
+    );
+    assert_eq!(
+        reason(vec![
+            band("0", "0.01"),
+            band("5", "0.05"),
+            band("2", "0.02")
+        ]),
+        "bands must be ordered by strictly ascending min_price"
+    );
+    assert_eq!(
+        reason(vec![band("0", "0.01"), band("1.005", "0.01")]),
+        "band boundaries must lie on the tick grid of both adjacent bands"
+    );
+    assert_eq!(
+        reason(vec![band("0", "0.01"), band("1", "0.3")]),
+        "band boundaries must lie on the tick grid of both adjacent bands"
+    );
+
+    let uniform = TickSizeTable::uniform(tick("0.25"));
+    assert!(uniform.is_on_tick(&price("1234.75")));
+    assert!(!uniform.is_on_tick(&price("1234.8")));
+}
+
+#[test]
+fn tables_deserialize_through_validation() {
+    let table = TickSizeTable::us_sub_penny();
+    let value = serde_json::to_value(&table).unwrap();
+    assert_eq!(
+        value,
+        json!({"bands": [
+            {"min_price": "0", "tick": "0.0001"},
+            {"min_price": "1", "tick": "0.01"},
+        ]})
+    );
+    assert_eq!(
+        serde_json::from_value::<TickSizeTable>(value).unwrap(),
+        table
+    );
+
+    assert!(
+        serde_json::from_value::<TickSizeTable>(
+            json!({"bands": [{"min_price": "0.5", "tick": "0.01"}]})
+        )
+        .is_err()
+    );
+    assert!(
+        serde_json::from_value::<TickSizeTable>(
+            json!({"bands": [{"min_price": "0", "tick": "0"}]})
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn lookups_prefer_instrument_tables_over_exchange_tables() {
+    let aapl =
+        Instrument::from_symbol_and_exchange("AAPL", Exchange::NASDAQ, AssetKind::Equity).unwrap();
+    let brk =
+        Instrument::from_symbol_and_exchange("BRK.A", Exchange::NYSE, AssetKind::Equity).unwrap();
+    let unlisted = Instrument::from_symbol("XYZ", AssetKind::Equity).unwrap();
+
+    let mut tables = TickSizeTables::new();
+    assert!(
+        tables
+            .set_exchange(Exchange::NASDAQ, TickSizeTable::us_sub_penny())
+            .is_none()
+    );
+    tables.set_exchange(Exchange::NYSE, TickSizeTable::us_sub_penny());
+    tables.set_instrument(brk.clone(), TickSizeTable::uniform(tick("1")));
+
+    assert_eq!(
+        tables.for_instrument(&aapl),
+        Some(&TickSizeTable::us_sub_penny())
+    );
+    assert_eq!(
+        tables.for_instrument(&brk),
+        Some(&TickSizeTable::uniform(tick("1")))
+    );
+    assert_eq!(tables.for_instrument(&unlisted), None);
+    assert!(tables.for_exchange(&Exchange::LSE).is_none());
+}
+
+#[test]
+fn order_book_levels_validate_against_a_table() {
+    let mut book = OrderBook::new(
+        Instrument::from_symbol("AAPL", AssetKind::Equity).unwrap(),
+        Currency::Iso(IsoCurrency::USD),
+    );
+    book.asks = vec![
+        BookLevel::new(price("187.25"), None),
+        BookLevel::new(price("187.26"), None),
+    ];
+    book.bids = vec![
+        BookLevel::new(price("187.24"), None),
+        BookLevel::new(price("187.235"), None),
+    ];
+
+    let table = TickSizeTable::us_sub_penny();
+    assert_eq!(
+        book.validate_ticks(&table),
+        Err(MarketError::OffTickBookLevel {
+            side: "bids",
+            index: 1,
+            price: decimal("187.235"),
+        })
+    );
+
+    book.bids.pop();
+    assert_eq!(book.validate_ticks(&table), Ok(()));
+    assert_eq!(
+        book.validate_ticks(&TickSizeTable::uniform(tick("0.05")))
+            .unwrap_err()
+            .to_string(),
+        "Order book asks level 1 price 187.26 is not on the tick size grid"
+    );
+}
@@ -0,0 +1,152 @@
+//! Loss-free allocation of [`Money`] into parts.
+
+use serde::{Deserialize, Serialize};
+
+use crate::decimal::{self, Decimal, RoundingStrategy, ToPrimitive};
+use crate::error::MoneyError;
+use crate::exact::{
+    checked_add_decimal, checked_div_decimal, checked_mul_decimal, checked_sub_decimal,
+    decimal_from_scaled_units,
+};
+use crate::money::Money;
+
+/// How [`Money::allocate_with`] hands out the minor units left over after
+/// every part has received its rounded-down share.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AllocationStrategy {
+    /// One extra unit each to the parts whose exact share lost the most to
+    /// rounding; ties go to the earlier part.
+    #[default]
+    LargestRemainder,
+    /// One extra unit each to parts in order, starting from the first and
+    /// skipping parts with a zero ratio.
+    RoundRobin,
+}
+
+impl Money {
+    /// Split this amount pro rata by `ratios` using
+    /// [`AllocationStrategy::LargestRemainder`].
+    ///
+    /// The parts sum exactly to `self` at its minor-unit scale. Ratios are
+    /// relative weights and need not sum to one.
+    ///
+    /// ```rust
+    /// # use paft_decimal::Decimal;
+    /// # use paft_money::{Currency, IsoCurrency, Money};
+    /// let fee = Money::from_canonical_str("100", Currency::Iso(IsoCurrency::USD)).unwrap();
+    /// let parts = fee.allocate(&[Decimal::from(1), Decimal::from(1), Decimal::from(1)]).unwrap();
+    /// let formatted: Vec<String> = parts.iter().map(Money::format).collect();
+    /// assert_eq!(formatted, ["33.34 USD", "33.33 USD", "33.33 USD"]);
+    /// ```
+    ///
+    /// # Errors
+    /// See [`Money::allocate_with`].
+    pub fn allocate(&self, ratios: &[Decimal]) -> Result<Vec<Self>, MoneyError> {
+        self.allocate_with(ratios, AllocationStrategy::LargestRemainder)
+    }
+
+    /// Split this amount pro rata by `ratios`, distributing leftover minor
+    /// units with `strategy`.
+    ///
+    /// Each part first receives its exact share rounded toward zero; the
+    /// remaining minor units (fewer than the number of parts) are then handed
+    /// out one at a time. Negative amounts allocate symmetrically. Parts with
+    /// a zero ratio always receive zero.
+    ///
+    /// # Errors
+    /// - [`MoneyError::EmptyAllocation`] when `ratios` is empty.
+    /// - [`MoneyError::NegativeAllocationRatio`] when a ratio is negative.
+    /// - [`MoneyError::ZeroAllocationTotal`] when the ratios sum to zero.
+    /// - [`MoneyError::ConversionError`] when the amount or a share cannot be
+    ///   represented in minor units.
+    pub fn allocate_with(
+        &self,
+        ratios: &[Decimal],
+        strategy: AllocationStrategy,
+    ) -> Result<Vec<Self>, MoneyError> {
+        if ratios.is_empty() {
+            return Err(MoneyError::EmptyAllocation);
+        }
+        let zero = decimal::zero();
+        let mut sum = decimal::zero();
+        for (index, ratio) in ratios.iter().enumerate() {
+            if *ratio < zero {
+                return Err(MoneyError::NegativeAllocationRatio {
+                    index,
+                    ratio: decimal::clone_decimal(ratio),
+                });
+            }
+            sum = checked_add_decimal(&sum, ratio)?;
+        }
+        if sum == zero {
+            return Err(MoneyError::ZeroAllocationTotal);
+        }
+
+        let minor = self.as_minor_units()?;
+        let total = minor.checked_abs().ok_or(MoneyError::ConversionError)?;
+        let total_decimal = decimal_from_scaled_units(total, 0)?;
+
+        let mut units = Vec::with_capacity(ratios.len());
+        let mut remainders = Vec::with_capacity(ratios.len());
+        for ratio in ratios {
+            let exact = checked_div_decimal(&checked_mul_decimal(&total_decimal, ratio)?, &sum)?;
+            let floor = decimal::round_dp_with_strategy(&exact, 0, RoundingStrategy::ToZero);
+            remainders.push(checked_sub_decimal(&exact, &floor)?);
+            units.push(floor.to_i128().ok_or(MoneyError::ConversionError)?);
+        }
+
+        let assigned: i128 = units.iter().sum();
+        let leftover =
+            usize::try_from(total - assigned).map_err(|_| MoneyError::ConversionError)?;
+        let order: Vec<usize> = match strategy {
+            AllocationStrategy::LargestRemainder => {
+                let mut order: Vec<usize> = (0..ratios.len()).collect();
+                // Stable sort keeps earlier parts first among equal remainders.
+                order.sort_by(|a, b| remainders[*b].cmp(&remainders[*a]));
+                order
+            }
+            AllocationStrategy::RoundRobin => (0..ratios.len()).collect(),
+        };
+        for index in order
+            .into_iter()
+            .filter(|index| ratios[*index] != zero)
+            .cycle()
+            .take(leftover)
+        {
+            units[index] += 1;
+        }
+
+        let scale = u32::from(self.minor_units());
+        units
+            .into_iter()
+            .map(|part| {
+                let signed = if minor < 0 { -part } else { part };
+                let amount = decimal_from_scaled_units(signed, scale)?;
+                Ok(Self::from_rounded_parts(
+                    &amount,
+                    self.currency().clone(),
+                    self.minor_units(),
+                ))
+            })
+            .collect()
+    }
+
+    /// Split this amount into `parts` equal shares, giving the leftover minor
+    /// units to the first shares.
+    ///
+    /// ```rust
+    /// # use paft_money::{Currency, IsoCurrency, Money};
+    /// let bill = Money::from_canonical_str("0.05", Currency::Iso(IsoCurrency::EUR)).unwrap();
+    /// let shares: Vec<String> = bill.split(3).unwrap().iter().map(Money::format).collect();
+    /// assert_eq!(shares, ["0.02 EUR", "0.02 EUR", "0.01 EUR"]);
+    /// ```
+    ///
+    /// # Errors
+    /// - [`MoneyError::EmptyAllocation`] when `parts` is zero.
+    /// - [`MoneyError::ConversionError`] when the amount cannot be
+    ///   represented in minor units.
+    pub fn split(&self, parts: usize) -> Result<Vec<Self>, MoneyError> {
+        self.allocate_with(&vec![decimal::one(); parts], AllocationStrategy::RoundRobin)
+    }
+}
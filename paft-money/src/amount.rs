@@ -193,3 +193,9 @@ impl From<Money> for MonetaryAmount {
         Self::new(money.amount(), money.currency().clone())
     }
 }
+
+impl From<&Money> for MonetaryAmount {
+    fn from(money: &Money) -> Self {
+        Self::new(money.amount(), money.currency().clone())
+    }
+}
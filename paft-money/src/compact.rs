@@ -0,0 +1,468 @@
+//! Compact rendering of large amounts, such as `$2.9T` or `1,2 Mrd. €`.
+
+use std::borrow::Cow;
+
+use crate::currency::Currency;
+use crate::currency_utils::MAX_DECIMAL_PRECISION;
+use crate::decimal::{self, Decimal, RoundingStrategy};
+use crate::error::MoneyError;
+use crate::exact::{checked_div_decimal, checked_mul_decimal, copy_decimal};
+use crate::format::{FormatItem, Formatter, Params};
+use crate::locale::Locale;
+use crate::money::Money;
+use crate::price::Price;
+
+/// Which built-in suffix vocabulary [`CompactFormat`] uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CompactStyle {
+    /// Abbreviations such as `K`, `M`, `B`, `T`.
+    #[default]
+    Short,
+    /// Words such as `thousand`, `million`, `billion`, `trillion`.
+    Long,
+}
+
+/// A suffix applied to values of at least `10^exponent`.
+///
+/// The suffix text is appended to the scaled number verbatim, so include any
+/// separating space in it (`" Mio."`). A singular form, used when the scaled
+/// number is exactly one, may differ from the plural (`" Million"` versus
+/// `" Millionen"`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CompactSuffix {
+    exponent: u32,
+    singular: Cow<'static, str>,
+    plural: Cow<'static, str>,
+}
+
+impl CompactSuffix {
+    /// A suffix for values of at least `10^exponent`, with the same text in
+    /// singular and plural.
+    #[must_use]
+    pub fn new(exponent: u32, text: impl Into<Cow<'static, str>>) -> Self {
+        let text = text.into();
+        Self {
+            exponent,
+            singular: text.clone(),
+            plural: text,
+        }
+    }
+
+    /// Use `text` when the scaled number is exactly one.
+    #[must_use]
+    pub fn with_singular(mut self, text: impl Into<Cow<'static, str>>) -> Self {
+        self.singular = text.into();
+        self
+    }
+
+    /// Power of ten this suffix stands for.
+    #[must_use]
+    pub const fn exponent(&self) -> u32 {
+        self.exponent
+    }
+
+    /// Suffix text used for plural scaled numbers.
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.plural
+    }
+
+    /// Suffix text used when the scaled number is singular.
+    #[must_use]
+    pub fn singular(&self) -> &str {
+        &self.singular
+    }
+}
+
+/// When a scaled number takes the singular suffix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Plural {
+    /// Only exactly one (English, German).
+    One,
+    /// Any number below two (French, Portuguese).
+    BelowTwo,
+}
+
+/// Settings for compact rendering of [`Money`], [`Price`], and [`Decimal`]
+/// values.
+///
+/// The largest suffix whose power of ten does not exceed the value is
+/// chosen, and the scaled number is rounded to the configured significant
+/// digits (two by default) with the configured [`RoundingStrategy`]
+/// ([`RoundingStrategy::MidpointAwayFromZero`] by default). Integer digits
+/// are never rounded away, so `123456` renders as `123K` even with one
+/// significant digit, and trailing fractional zeros are dropped. When
+/// rounding carries into the next suffix the value is rescaled, so `999960`
+/// renders as `1M` rather than `1000K`. Values below the smallest suffix
+/// render without one.
+///
+/// The locale selects the suffix vocabulary and supplies the decimal and
+/// grouping separators; [`CompactFormat::with_suffixes`] replaces the
+/// vocabulary. Currencies are rendered with their symbol, placed like
+/// [`Money::format_with_locale`] does.
+///
+/// ```rust
+/// # use paft_money::{CompactFormat, CompactStyle, Currency, IsoCurrency, Locale, Money};
+/// let cap = Money::from_canonical_str("2912345678901.23", Currency::Iso(IsoCurrency::USD)).unwrap();
+/// assert_eq!(cap.format_compact(&CompactFormat::new(Locale::EnUs)).unwrap(), "$2.9T");
+///
+/// let long = CompactFormat::new(Locale::EnUs)
+///     .with_style(CompactStyle::Long)
+///     .with_significant_digits(3);
+/// assert_eq!(cap.format_compact(&long).unwrap(), "$2.91 trillion");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompactFormat {
+    locale: Locale,
+    suffixes: Vec<CompactSuffix>,
+    plural: Plural,
+    significant_digits: u32,
+    rounding: RoundingStrategy,
+    include_symbol: bool,
+    include_code: bool,
+}
+
+impl CompactFormat {
+    /// Short suffixes for `locale`, two significant digits, rounding half
+    /// away from zero.
+    #[must_use]
+    pub fn new(locale: Locale) -> Self {
+        let (suffixes, plural) = builtin_suffixes(locale, CompactStyle::Short);
+        Self {
+            locale,
+            suffixes,
+            plural,
+            significant_digits: 2,
+            rounding: RoundingStrategy::MidpointAwayFromZero,
+            include_symbol: true,
+            include_code: false,
+        }
+    }
+
+    /// Use the locale's built-in suffixes of the given `style`.
+    #[must_use]
+    pub fn with_style(mut self, style: CompactStyle) -> Self {
+        (self.suffixes, self.plural) = builtin_suffixes(self.locale, style);
+        self
+    }
+
+    /// Replace the suffix vocabulary. The singular form of each suffix is
+    /// used when the scaled number is exactly one.
+    ///
+    /// # Errors
+    /// Returns [`MoneyError::InvalidCompactFormat`] when a suffix has
+    /// exponent zero or two suffixes share an exponent.
+    pub fn with_suffixes(
+        mut self,
+        suffixes: impl IntoIterator<Item = CompactSuffix>,
+    ) -> Result<Self, MoneyError> {
+        let mut suffixes: Vec<CompactSuffix> = suffixes.into_iter().collect();
+        suffixes.sort_by_key(CompactSuffix::exponent);
+        if suffixes.first().is_some_and(|suffix| suffix.exponent == 0) {
+            return Err(MoneyError::InvalidCompactFormat {
+                reason: "suffix exponent must be positive",
+            });
+        }
+        if suffixes
+            .windows(2)
+            .any(|pair| pair[0].exponent == pair[1].exponent)
+        {
+            return Err(MoneyError::InvalidCompactFormat {
+                reason: "suffix exponents must be distinct",
+            });
+        }
+        self.suffixes = suffixes;
+        self.plural = Plural::One;
+        Ok(self)
+    }
+
+    /// Round scaled numbers to `digits` significant digits.
+    #[must_use]
+    pub const fn with_significant_digits(mut self, digits: u32) -> Self {
+        self.significant_digits = digits;
+        self
+    }
+
+    /// Round scaled numbers with `rounding`.
+    #[must_use]
+    pub const fn with_rounding(mut self, rounding: RoundingStrategy) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Append the currency code (e.g. `USD`) after the number.
+    #[must_use]
+    pub const fn with_code(mut self) -> Self {
+        self.include_code = true;
+        self
+    }
+
+    /// Omit the currency symbol.
+    #[must_use]
+    pub const fn without_symbol(mut self) -> Self {
+        self.include_symbol = false;
+        self
+    }
+
+    /// The locale whose separators are used.
+    #[must_use]
+    pub const fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    /// Suffixes in ascending order of exponent.
+    #[must_use]
+    pub fn suffixes(&self) -> &[CompactSuffix] {
+        &self.suffixes
+    }
+
+    /// Significant digits scaled numbers are rounded to.
+    #[must_use]
+    pub const fn significant_digits(&self) -> u32 {
+        self.significant_digits
+    }
+
+    /// Rounding strategy applied to scaled numbers.
+    #[must_use]
+    pub const fn rounding(&self) -> RoundingStrategy {
+        self.rounding
+    }
+
+    /// Render a plain decimal compactly.
+    ///
+    /// # Errors
+    /// - [`MoneyError::InvalidCompactFormat`] when the significant digits
+    ///   are zero.
+    /// - [`MoneyError::FormatPrecisionExceeded`] when they exceed
+    ///   [`crate::MAX_DECIMAL_PRECISION`].
+    /// - [`MoneyError::ConversionError`] when scaling overflows the active
+    ///   decimal backend.
+    pub fn format_decimal(&self, value: &Decimal) -> Result<String, MoneyError> {
+        self.render(value, None)
+    }
+
+    fn render(&self, value: &Decimal, currency: Option<&Currency>) -> Result<String, MoneyError> {
+        if self.significant_digits == 0 {
+            return Err(MoneyError::InvalidCompactFormat {
+                reason: "significant digits must be positive",
+            });
+        }
+        let max_fraction_digits = u32::from(MAX_DECIMAL_PRECISION);
+        if self.significant_digits > max_fraction_digits {
+            return Err(MoneyError::FormatPrecisionExceeded {
+                actual_fraction_digits: self.significant_digits,
+                max_fraction_digits,
+            });
+        }
+
+        let (scaled, suffix) = self.scale(value)?;
+        let suffix = suffix.map(|suffix| {
+            let (integer, fraction) = abs_digits(&scaled);
+            let singular = integer == "1"
+                && match self.plural {
+                    Plural::One => fraction.is_empty(),
+                    Plural::BelowTwo => true,
+                };
+            if singular {
+                suffix.singular.to_string()
+            } else {
+                suffix.plural.to_string()
+            }
+        });
+
+        let mut params = Params::new(Vec::new());
+        let symbol = currency
+            .filter(|_| self.include_symbol)
+            .and_then(Currency::symbol)
+            .filter(|symbol| !symbol.is_empty())
+            .filter(|symbol| {
+                !(self.include_code
+                    && currency.is_some_and(|c| symbol.eq_ignore_ascii_case(c.code())))
+            });
+        let symbol_first = currency.is_none_or(Currency::symbol_first);
+        let symbol_spacing = symbol.as_ref().is_some_and(|s| s.chars().count() > 1);
+
+        params.positions.push(FormatItem::Sign);
+        if symbol.is_some() && symbol_first {
+            params.positions.push(FormatItem::Symbol);
+            if symbol_spacing {
+                params.positions.push(FormatItem::Space);
+            }
+        }
+        params.positions.push(FormatItem::Amount);
+        if let Some(suffix) = suffix {
+            params.positions.push(FormatItem::Literal(suffix));
+        }
+        if symbol.is_some() && !symbol_first {
+            if symbol_spacing {
+                params.positions.push(FormatItem::Space);
+            }
+            params.positions.push(FormatItem::Symbol);
+        }
+        if let Some(currency) = currency.filter(|_| self.include_code) {
+            params.positions.push(FormatItem::Space);
+            params.positions.push(FormatItem::Code);
+            params.code = Some(Cow::Owned(currency.code().to_string()));
+        }
+        params.symbol = symbol;
+
+        Formatter::new(scaled, self.locale, params).format()
+    }
+
+    /// Divide `value` by the chosen suffix's power of ten and round it,
+    /// moving to the next suffix when rounding carries into it.
+    fn scale(&self, value: &Decimal) -> Result<(Decimal, Option<&CompactSuffix>), MoneyError> {
+        let mut value = copy_decimal(value);
+        loop {
+            let integer_digits = abs_digits(&value).0.len();
+            let index = self
+                .suffixes
+                .iter()
+                .rposition(|suffix| (suffix.exponent as usize) < integer_digits);
+            let exponent = index.map_or(0, |index| self.suffixes[index].exponent);
+            let power = power_of_ten(exponent)?;
+            let scaled = checked_div_decimal(&value, &power)?;
+            let rounded = decimal::round_dp_with_strategy(
+                &scaled,
+                self.fraction_digits(&scaled),
+                self.rounding,
+            );
+
+            let next = index.map_or(0, |index| index + 1);
+            if let Some(next) = self.suffixes.get(next) {
+                let rounded_digits = abs_digits(&rounded).0.len();
+                if rounded_digits > (next.exponent - exponent) as usize {
+                    value = checked_mul_decimal(&rounded, &power)?;
+                    continue;
+                }
+            }
+            return Ok((rounded, index.map(|index| &self.suffixes[index])));
+        }
+    }
+
+    /// Fraction digits that leave `significant_digits` significant digits in
+    /// `scaled`, keeping every integer digit.
+    fn fraction_digits(&self, scaled: &Decimal) -> u32 {
+        let (integer, fraction) = abs_digits(scaled);
+        if integer.is_empty() {
+            let leading_zeros = fraction.chars().take_while(|c| *c == '0').count();
+            u32::try_from(leading_zeros)
+                .unwrap_or(u32::MAX)
+                .saturating_add(self.significant_digits)
+                .min(u32::from(MAX_DECIMAL_PRECISION))
+        } else {
+            let integer_digits = u32::try_from(integer.len()).unwrap_or(u32::MAX);
+            self.significant_digits.saturating_sub(integer_digits)
+        }
+    }
+}
+
+impl Money {
+    /// Render this amount compactly, such as `$2.9T`.
+    ///
+    /// # Errors
+    /// See [`CompactFormat::format_decimal`].
+    pub fn format_compact(&self, format: &CompactFormat) -> Result<String, MoneyError> {
+        format.render(&self.amount(), Some(self.currency()))
+    }
+}
+
+impl Price {
+    /// Render this price compactly, such as `$2.9T`.
+    ///
+    /// # Errors
+    /// See [`CompactFormat::format_decimal`].
+    pub fn format_compact(&self, format: &CompactFormat) -> Result<String, MoneyError> {
+        format.render(&self.amount(), Some(self.currency()))
+    }
+}
+
+/// Integer digits (empty below one) and fraction digits of `|value|`.
+fn abs_digits(value: &Decimal) -> (String, String) {
+    let canonical = decimal::to_canonical_string(value);
+    let unsigned = canonical.trim_start_matches('-');
+    let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let integer = integer.trim_start_matches('0');
+    (integer.to_string(), fraction.to_string())
+}
+
+fn power_of_ten(exponent: u32) -> Result<Decimal, MoneyError> {
+    let digits = format!("1{}", "0".repeat(exponent as usize));
+    decimal::parse_decimal(&digits).ok_or(MoneyError::ConversionError)
+}
+
+fn builtin_suffixes(locale: Locale, style: CompactStyle) -> (Vec<CompactSuffix>, Plural) {
+    type Table = &'static [(u32, &'static str, &'static str)];
+    const EN_SHORT: Table = &[(3, "K", "K"), (6, "M", "M"), (9, "B", "B"), (12, "T", "T")];
+    const EN_LONG: Table = &[
+        (3, " thousand", " thousand"),
+        (6, " million", " million"),
+        (9, " billion", " billion"),
+        (12, " trillion", " trillion"),
+    ];
+    const EN_IN_SHORT: Table = &[(3, "K", "K"), (5, "L", "L"), (7, "Cr", "Cr")];
+    const EN_IN_LONG: Table = &[
+        (3, " thousand", " thousand"),
+        (5, " lakh", " lakh"),
+        (7, " crore", " crore"),
+    ];
+    // German abbreviates from millions on, as CLDR does.
+    const DE_SHORT: Table = &[
+        (6, "\u{a0}Mio.", "\u{a0}Mio."),
+        (9, "\u{a0}Mrd.", "\u{a0}Mrd."),
+        (12, "\u{a0}Bio.", "\u{a0}Bio."),
+    ];
+    const DE_LONG: Table = &[
+        (3, " Tausend", " Tausend"),
+        (6, " Million", " Millionen"),
+        (9, " Milliarde", " Milliarden"),
+        (12, " Billion", " Billionen"),
+    ];
+    const FR_SHORT: Table = &[
+        (3, "\u{a0}k", "\u{a0}k"),
+        (6, "\u{a0}M", "\u{a0}M"),
+        (9, "\u{a0}Md", "\u{a0}Md"),
+        (12, "\u{a0}Bn", "\u{a0}Bn"),
+    ];
+    const FR_LONG: Table = &[
+        (3, " mille", " mille"),
+        (6, " million", " millions"),
+        (9, " milliard", " milliards"),
+        (12, " billion", " billions"),
+    ];
+    const PT_SHORT: Table = &[
+        (3, "\u{a0}mil", "\u{a0}mil"),
+        (6, "\u{a0}mi", "\u{a0}mi"),
+        (9, "\u{a0}bi", "\u{a0}bi"),
+        (12, "\u{a0}tri", "\u{a0}tri"),
+    ];
+    const PT_LONG: Table = &[
+        (3, " mil", " mil"),
+        (6, " milhão", " milhões"),
+        (9, " bilhão", " bilhões"),
+        (12, " trilhão", " trilhões"),
+    ];
+    // Japanese groups by powers of 10^4 in both styles.
+    const JA: Table = &[(4, "万", "万"), (8, "億", "億"), (12, "兆", "兆")];
+
+    let (table, plural) = match (locale, style) {
+        (Locale::EnIn, CompactStyle::Short) => (EN_IN_SHORT, Plural::One),
+        (Locale::EnIn, CompactStyle::Long) => (EN_IN_LONG, Plural::One),
+        (Locale::DeCh, CompactStyle::Short) => (DE_SHORT, Plural::One),
+        (Locale::DeCh, CompactStyle::Long) => (DE_LONG, Plural::One),
+        (Locale::FrFr, CompactStyle::Short) => (FR_SHORT, Plural::BelowTwo),
+        (Locale::FrFr, CompactStyle::Long) => (FR_LONG, Plural::BelowTwo),
+        (Locale::PtBr, CompactStyle::Short) => (PT_SHORT, Plural::BelowTwo),
+        (Locale::PtBr, CompactStyle::Long) => (PT_LONG, Plural::BelowTwo),
+        (Locale::JaJp, _) => (JA, Plural::One),
+        (_, CompactStyle::Short) => (EN_SHORT, Plural::One),
+        (_, CompactStyle::Long) => (EN_LONG, Plural::One),
+    };
+    let suffixes = table
+        .iter()
+        .map(|(exponent, singular, plural)| {
+            CompactSuffix::new(*exponent, *plural).with_singular(*singular)
+        })
+        .collect();
+    (suffixes, plural)
+}
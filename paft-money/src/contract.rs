@@ -0,0 +1,315 @@
+//! Unit-aware quantities and contract specifications for notional totals.
+//!
+//! [`QuantityAmount`] is a bare number whose unit comes from context. A
+//! [`Quantity`] tags it with a [`QuantityUnit`], and a [`ContractSpec`]
+//! describes how those units relate to the underlying: a futures or options
+//! contract covers `multiplier` units of it, and a lot is `lot_size`
+//! contracts. Notionals are then `price × quantity in underlying units`.
+
+use std::fmt;
+use std::str::FromStr;
+
+use paft_decimal::PositiveDecimal;
+use serde::{Deserialize, Serialize};
+
+use crate::amount::MonetaryAmount;
+use crate::decimal::{self, Decimal, RoundingStrategy};
+use crate::error::{MoneyError, MoneyParseError};
+use crate::exact::{checked_div_decimal, checked_mul_decimal};
+use crate::price::Price;
+use crate::quantity::QuantityAmount;
+
+/// Unit a [`Quantity`] is counted in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum QuantityUnit {
+    /// Shares or other units of the underlying itself.
+    Shares,
+    /// Exchange-traded contracts, each covering the spec's `multiplier`
+    /// units of the underlying.
+    Contracts,
+    /// Trading lots, each `lot_size` contracts.
+    Lots,
+    /// Units of a base asset, such as BTC on a BTC/USD pair.
+    BaseUnits,
+}
+
+impl QuantityUnit {
+    /// Wire name of the unit, such as `"contracts"`.
+    #[must_use]
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::Shares => "shares",
+            Self::Contracts => "contracts",
+            Self::Lots => "lots",
+            Self::BaseUnits => "base_units",
+        }
+    }
+}
+
+impl fmt::Display for QuantityUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl FromStr for QuantityUnit {
+    type Err = MoneyParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let normalized = input.trim().to_ascii_lowercase();
+        Ok(match normalized.as_str() {
+            "shares" | "share" => Self::Shares,
+            "contracts" | "contract" => Self::Contracts,
+            "lots" | "lot" => Self::Lots,
+            "base_units" | "base" => Self::BaseUnits,
+            _ => {
+                return Err(MoneyParseError::InvalidEnumValue {
+                    enum_name: "QuantityUnit",
+                    value: input.to_string(),
+                });
+            }
+        })
+    }
+}
+
+/// Non-negative quantity tagged with the unit it is counted in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Quantity {
+    amount: QuantityAmount,
+    unit: QuantityUnit,
+}
+
+impl Quantity {
+    /// Creates a quantity of `amount` in `unit`.
+    #[must_use]
+    pub const fn new(amount: QuantityAmount, unit: QuantityUnit) -> Self {
+        Self { amount, unit }
+    }
+
+    /// Creates a quantity counted in shares.
+    #[must_use]
+    pub const fn shares(amount: QuantityAmount) -> Self {
+        Self::new(amount, QuantityUnit::Shares)
+    }
+
+    /// Creates a quantity counted in contracts.
+    #[must_use]
+    pub const fn contracts(amount: QuantityAmount) -> Self {
+        Self::new(amount, QuantityUnit::Contracts)
+    }
+
+    /// Creates a quantity counted in lots.
+    #[must_use]
+    pub const fn lots(amount: QuantityAmount) -> Self {
+        Self::new(amount, QuantityUnit::Lots)
+    }
+
+    /// Creates a quantity counted in base-asset units.
+    #[must_use]
+    pub const fn base_units(amount: QuantityAmount) -> Self {
+        Self::new(amount, QuantityUnit::BaseUnits)
+    }
+
+    /// Returns the amount, in [`Quantity::unit`]s.
+    #[must_use]
+    pub const fn amount(&self) -> &QuantityAmount {
+        &self.amount
+    }
+
+    /// Returns the unit the amount is counted in.
+    #[must_use]
+    pub const fn unit(&self) -> QuantityUnit {
+        self.unit
+    }
+}
+
+impl fmt::Display for Quantity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.amount, self.unit)
+    }
+}
+
+/// How an instrument's trading units relate to its underlying.
+///
+/// Shares and base units count the underlying directly. A contract covers
+/// `multiplier` units of the underlying (`50` for an E-mini S&P 500 future,
+/// `100` for a US equity option), and a lot is `lot_size` contracts. Spot
+/// instruments use a multiplier of one, so a contract is one share or one
+/// unit of the base asset.
+///
+/// ```rust
+/// # use paft_decimal::PositiveDecimal;
+/// # use paft_money::{ContractSpec, Currency, IsoCurrency, Price, Quantity, QuantityAmount};
+/// let es = ContractSpec::new(PositiveDecimal::new(50.into()).unwrap());
+/// let price = Price::from_canonical_str("5000.25", Currency::Iso(IsoCurrency::USD)).unwrap();
+/// let two = Quantity::contracts(QuantityAmount::from_decimal(2.into()).unwrap());
+/// assert_eq!(price.try_notional(&two, &es).unwrap().format(), "500025 USD");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractSpec {
+    /// Units of the underlying per contract.
+    pub multiplier: PositiveDecimal,
+    /// Contracts per lot.
+    pub lot_size: PositiveDecimal,
+    /// Smallest tradable step, in contracts; `None` accepts any quantity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_increment: Option<PositiveDecimal>,
+}
+
+impl ContractSpec {
+    /// Creates a spec with `multiplier` units of the underlying per contract,
+    /// single-contract lots, and no minimum increment.
+    #[must_use]
+    pub fn new(multiplier: PositiveDecimal) -> Self {
+        Self {
+            multiplier,
+            lot_size: unit_decimal(),
+            min_increment: None,
+        }
+    }
+
+    /// Spec of a spot instrument, where a contract is one unit of the
+    /// underlying.
+    #[must_use]
+    pub fn spot() -> Self {
+        Self::new(unit_decimal())
+    }
+
+    /// Sets the number of contracts per lot.
+    #[must_use]
+    #[cfg_attr(
+        not(feature = "bigdecimal"),
+        expect(
+            clippy::missing_const_for_fn,
+            reason = "bigdecimal-backed decimals cannot be dropped in a const fn"
+        )
+    )]
+    pub fn with_lot_size(mut self, lot_size: PositiveDecimal) -> Self {
+        self.lot_size = lot_size;
+        self
+    }
+
+    /// Sets the smallest tradable step, in contracts.
+    #[must_use]
+    #[cfg_attr(
+        not(feature = "bigdecimal"),
+        expect(
+            clippy::missing_const_for_fn,
+            reason = "bigdecimal-backed decimals cannot be dropped in a const fn"
+        )
+    )]
+    pub fn with_min_increment(mut self, min_increment: PositiveDecimal) -> Self {
+        self.min_increment = Some(min_increment);
+        self
+    }
+
+    /// Units of the underlying covered by one `unit`.
+    ///
+    /// # Errors
+    /// Returns [`MoneyError::ConversionError`] when the active decimal
+    /// backend overflows.
+    pub fn underlying_per(&self, unit: QuantityUnit) -> Result<Decimal, MoneyError> {
+        match unit {
+            QuantityUnit::Shares | QuantityUnit::BaseUnits => Ok(decimal::one()),
+            QuantityUnit::Contracts => Ok(decimal::clone_decimal(self.multiplier.as_decimal())),
+            QuantityUnit::Lots => {
+                checked_mul_decimal(self.lot_size.as_decimal(), self.multiplier.as_decimal())
+            }
+        }
+    }
+
+    /// Restates `quantity` in units of the underlying.
+    ///
+    /// # Errors
+    /// Returns [`MoneyError::ConversionError`] when the active decimal
+    /// backend overflows.
+    pub fn underlying_quantity(&self, quantity: &Quantity) -> Result<QuantityAmount, MoneyError> {
+        let factor = self.underlying_per(quantity.unit)?;
+        let amount = checked_mul_decimal(quantity.amount.as_decimal(), &factor)?;
+        QuantityAmount::from_decimal(amount).map_err(|_| MoneyError::ConversionError)
+    }
+
+    /// Restates `quantity` in `unit`.
+    ///
+    /// Conversions into coarser units can produce fractions, such as half a
+    /// lot; pair with [`ContractSpec::validate`] when only whole steps are
+    /// acceptable.
+    ///
+    /// # Errors
+    /// Returns [`MoneyError::ConversionError`] when the active decimal
+    /// backend overflows or cannot represent the quotient exactly.
+    pub fn convert(&self, quantity: &Quantity, unit: QuantityUnit) -> Result<Quantity, MoneyError> {
+        if quantity.unit == unit {
+            return Ok(quantity.clone());
+        }
+        let underlying = self.underlying_quantity(quantity)?;
+        let amount = checked_div_decimal(underlying.as_decimal(), &self.underlying_per(unit)?)?;
+        let amount =
+            QuantityAmount::from_decimal(amount).map_err(|_| MoneyError::ConversionError)?;
+        Ok(Quantity::new(amount, unit))
+    }
+
+    /// Checks that `quantity` is a whole number of `min_increment` steps.
+    ///
+    /// # Errors
+    /// - [`MoneyError::InvalidQuantityIncrement`] when it is not.
+    /// - [`MoneyError::ConversionError`] when the active decimal backend
+    ///   overflows.
+    pub fn validate(&self, quantity: &Quantity) -> Result<(), MoneyError> {
+        let Some(increment) = &self.min_increment else {
+            return Ok(());
+        };
+        let contracts = self.convert(quantity, QuantityUnit::Contracts)?;
+        let steps = checked_div_decimal(contracts.amount.as_decimal(), increment.as_decimal())?;
+        if decimal::round_dp_with_strategy(&steps, 0, RoundingStrategy::ToZero) == steps {
+            Ok(())
+        } else {
+            Err(MoneyError::InvalidQuantityIncrement {
+                quantity: decimal::clone_decimal(contracts.amount.as_decimal()),
+                increment: decimal::clone_decimal(increment.as_decimal()),
+            })
+        }
+    }
+
+    /// Notional of `quantity` at `price`, where `price` is quoted per unit of
+    /// the underlying: `price × quantity × multiplier` for contracts.
+    ///
+    /// # Errors
+    /// Returns [`MoneyError::ConversionError`] when the active decimal
+    /// backend overflows.
+    pub fn notional(
+        &self,
+        price: &Price,
+        quantity: &Quantity,
+    ) -> Result<MonetaryAmount, MoneyError> {
+        price.try_total(&self.underlying_quantity(quantity)?)
+    }
+}
+
+impl Default for ContractSpec {
+    fn default() -> Self {
+        Self::spot()
+    }
+}
+
+fn unit_decimal() -> PositiveDecimal {
+    PositiveDecimal::new(decimal::one()).expect("one is positive")
+}
+
+impl Price {
+    /// Notional of `quantity` at this price under `spec`; see
+    /// [`ContractSpec::notional`].
+    ///
+    /// # Errors
+    /// Returns [`MoneyError::ConversionError`] when the active decimal
+    /// backend overflows.
+    pub fn try_notional(
+        &self,
+        quantity: &Quantity,
+        spec: &ContractSpec,
+    ) -> Result<MonetaryAmount, MoneyError> {
+        spec.notional(self, quantity)
+    }
+}
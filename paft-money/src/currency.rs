@@ -2,12 +2,14 @@
 
 use std::{borrow::Cow, str::FromStr};
 
+use chrono::NaiveDate;
 use paft_utils::{Canonical, StringCode, has_canonical_token_boundaries};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::IsoCurrency;
-use crate::currency_utils::{MAX_MINOR_UNIT_DECIMALS, currency_metadata};
+use crate::currency_utils::{CurrencyRegistry, MAX_MINOR_UNIT_DECIMALS};
 use crate::error::{MoneyError, MoneyParseError};
+use crate::historic::HistoricCurrency;
 #[cfg(feature = "money-formatting")]
 use crate::locale::Locale;
 
@@ -144,7 +146,8 @@ impl Currency {
     /// Policy: If ISO defines a minor unit exponent, we use it. Otherwise (for
     /// ISO currencies without a registered exponent such as `XAU`/`XDR`, for
     /// the built-in non-ISO variants, and for `Other` codes), we consult the
-    /// metadata registry by canonical code. If metadata is present, its
+    /// global metadata registry by canonical code; use
+    /// [`CurrencyRegistry::decimal_places`] to resolve against another one. If metadata is present, its
     /// `minor_units` value is used. Otherwise, an error is returned.
     ///
     /// Routing every non-ISO variant through the registry keeps the source of
@@ -157,17 +160,7 @@ impl Currency {
     ///   overlay, or an ISO code whose ISO entry has no exponent and which has
     ///   no overlay registered).
     pub fn decimal_places(&self) -> Result<u8, MoneyError> {
-        if let Self::Iso(iso) = self
-            && let Some(exp) = iso.exponent().and_then(|e| u8::try_from(e).ok())
-        {
-            return Ok(exp);
-        }
-
-        currency_metadata(self.code())
-            .map(|meta| meta.minor_units)
-            .ok_or_else(|| MoneyError::MetadataNotFound {
-                currency: self.clone(),
-            })
+        CurrencyRegistry::global().decimal_places(self)
     }
 
     /// Returns the human-readable name for this currency.
@@ -177,15 +170,21 @@ impl Currency {
     /// variants and `Other` codes consistently.
     #[must_use]
     pub fn full_name(&self) -> Cow<'static, str> {
-        match self {
-            Self::Iso(iso) => Cow::Owned(iso.name().to_string()),
-            Self::BTC | Self::ETH | Self::XMR | Self::USDC | Self::USDT | Self::Other(_) => {
-                currency_metadata(self.code()).map_or_else(
-                    || Cow::Owned(self.code().to_string()),
-                    |meta| meta.full_name,
-                )
-            }
-        }
+        CurrencyRegistry::global().full_name(self)
+    }
+
+    /// Returns the historic record of a withdrawn or redenominated currency,
+    /// such as the `EUR` successor and `1.95583` factor of `DEM`.
+    #[must_use]
+    pub fn historic(&self) -> Option<HistoricCurrency> {
+        CurrencyRegistry::global().historic(self)
+    }
+
+    /// Whether this currency was in use on `date`. Currencies without a
+    /// historic record are always considered valid.
+    #[must_use]
+    pub fn is_valid_on(&self, date: NaiveDate) -> bool {
+        CurrencyRegistry::global().is_valid_on(self, date)
     }
 
     /// Returns the scaling factor for converting between major and minor units (`10^decimal_places`).
@@ -228,29 +227,21 @@ impl Currency {
     #[cfg(feature = "money-formatting")]
     #[must_use]
     pub fn symbol(&self) -> Option<Cow<'static, str>> {
-        if let Some(meta) = currency_metadata(self.code()) {
-            let symbol = meta.symbol.clone();
-            if symbol.is_empty() {
-                return None;
-            }
-            return Some(symbol);
-        }
-
-        Some(Cow::Owned(self.code().to_string()))
+        CurrencyRegistry::global().symbol(self)
     }
 
     /// Returns whether the symbol should precede (`true`) or follow (`false`) the amount.
     #[cfg(feature = "money-formatting")]
     #[must_use]
     pub fn symbol_first(&self) -> bool {
-        currency_metadata(self.code()).is_none_or(|meta| meta.symbol_first)
+        CurrencyRegistry::global().symbol_first(self)
     }
 
     /// Returns the default locale for formatting this currency.
     #[cfg(feature = "money-formatting")]
     #[must_use]
     pub fn default_locale(&self) -> Locale {
-        currency_metadata(self.code()).map_or(Locale::EnUs, |meta| meta.default_locale)
+        CurrencyRegistry::global().default_locale(self)
     }
 }
 
@@ -2,7 +2,9 @@
 //!
 //! This module also provides the metadata overlay registry used by non-ISO
 //! currency display fields and by currencies whose scale is not fully described
-//! by ISO 4217. If ISO defines a minor-unit exponent, that exponent remains the
+//! by ISO 4217. The free functions operate on the process-wide
+//! [`CurrencyRegistry::global`]; independent [`CurrencyRegistry`] instances can
+//! be passed explicitly where isolation is needed. If ISO defines a minor-unit exponent, that exponent remains the
 //! scale source of truth; otherwise `Currency::decimal_places`
 //! consults metadata. If no metadata exists for a currency that needs it, money
 //! operations that require a scale return `MoneyError::MetadataNotFound`.
@@ -10,13 +12,16 @@
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt;
+use std::num::NonZeroU32;
 use std::sync::{LazyLock, RwLock, RwLockReadGuard, RwLockWriteGuard};
 
 use paft_utils::canonicalize;
 
 use crate::currency::Currency;
-use crate::error::MoneyParseError;
+use crate::error::{MoneyError, MoneyParseError};
+use crate::historic::HistoricCurrency;
 use crate::locale::Locale;
+use crate::metadata_file::CurrencyMetadataEntry;
 
 /// Maximum precision supported by the active decimal backend for safe scaling operations.
 ///
@@ -42,6 +47,9 @@ pub struct CurrencyMetadata {
     pub symbol_first: bool,
     /// Default locale used for grouping and separators when formatting.
     pub default_locale: crate::locale::Locale,
+    /// Smallest cash denomination in minor units when it is coarser than one
+    /// minor unit, such as `5` for CHF 0.05 or `100` for whole SEK.
+    pub cash_increment: Option<NonZeroU32>,
 }
 
 /// Errors that can occur when configuring minor-unit overrides.
@@ -72,6 +80,11 @@ pub enum MinorUnitError {
         /// Requested replacement scale.
         requested: u8,
     },
+    /// Neither metadata nor ISO 4217 defines a minor-unit scale for this code.
+    UnknownMinorUnits {
+        /// Canonical currency code.
+        code: String,
+    },
 }
 
 impl fmt::Display for MinorUnitError {
@@ -96,12 +109,96 @@ impl fmt::Display for MinorUnitError {
                 f,
                 "minor-unit scale for {code} is already registered as {existing}; requested {requested}"
             ),
+            Self::UnknownMinorUnits { code } => {
+                write!(f, "no minor-unit scale is known for {code}")
+            }
         }
     }
 }
 
 impl std::error::Error for MinorUnitError {}
 
+/// Errors that can occur when loading a batch of currency metadata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MetadataLoadError {
+    /// The metadata file could not be read.
+    Io {
+        /// Path of the file.
+        path: String,
+        /// Description of the I/O failure.
+        message: String,
+    },
+    /// The file extension does not name a supported (and enabled) format.
+    UnsupportedFormat {
+        /// Path of the file.
+        path: String,
+    },
+    /// The document is not valid metadata in the given format.
+    Parse {
+        /// Format being parsed, such as `"JSON"` or `"TOML"`.
+        format: &'static str,
+        /// Parser error message.
+        message: String,
+    },
+    /// An entry failed validation.
+    InvalidEntry {
+        /// Zero-based position of the entry in the batch.
+        index: usize,
+        /// Currency code as written in the entry.
+        code: String,
+        /// Validation failure.
+        source: MinorUnitError,
+    },
+    /// Two entries name the same currency.
+    DuplicateCode {
+        /// Canonical currency code.
+        code: String,
+        /// Position of the first entry.
+        first: usize,
+        /// Position of the repeated entry.
+        second: usize,
+    },
+}
+
+impl fmt::Display for MetadataLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, message } => {
+                write!(f, "failed to read currency metadata from {path}: {message}")
+            }
+            Self::UnsupportedFormat { path } => {
+                write!(f, "unsupported currency metadata file format: {path}")
+            }
+            Self::Parse { format, message } => {
+                write!(f, "invalid {format} currency metadata: {message}")
+            }
+            Self::InvalidEntry {
+                index,
+                code,
+                source,
+            } => write!(f, "currency metadata entry {index} ('{code}'): {source}"),
+            Self::DuplicateCode {
+                code,
+                first,
+                second,
+            } => write!(
+                f,
+                "currency metadata entries {first} and {second} both define {code}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MetadataLoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidEntry { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
 /// Built-in metadata for commonly used ISO and non-ISO currency codes.
 const BUILTIN_CURRENCY_METADATA: &[(&str, &str, u8, &str, bool, Locale)] = &[
     (
@@ -143,20 +240,95 @@ const BUILTIN_CURRENCY_METADATA: &[(&str, &str, u8, &str, bool, Locale)] = &[
     ("LTC", "Litecoin", 8, "LTC", true, Locale::EnUs),
     ("MATIC", "Polygon", 8, "MATIC", true, Locale::EnUs),
     ("UNI", "Uniswap", 8, "UNI", true, Locale::EnUs),
+    // Withdrawn ISO 4217 codes; see `historic` for their successors.
+    ("ATS", "Austrian Schilling", 2, "ATS", true, Locale::EnEu),
+    ("BEF", "Belgian Franc", 0, "BEF", true, Locale::EnEu),
+    ("DEM", "Deutsche Mark", 2, "DM", false, Locale::EnEu),
+    ("ESP", "Spanish Peseta", 0, "ESP", true, Locale::EnEu),
+    ("FIM", "Finnish Markka", 2, "FIM", true, Locale::EnEu),
+    ("FRF", "French Franc", 2, "FRF", true, Locale::EnEu),
+    ("GRD", "Greek Drachma", 0, "GRD", true, Locale::EnEu),
+    ("IEP", "Irish Pound", 2, "IEP", true, Locale::EnEu),
+    ("ITL", "Italian Lira", 0, "ITL", true, Locale::EnEu),
+    ("LUF", "Luxembourg Franc", 0, "LUF", true, Locale::EnEu),
+    ("NLG", "Dutch Guilder", 2, "NLG", true, Locale::EnEu),
+    ("PTE", "Portuguese Escudo", 0, "PTE", true, Locale::EnEu),
+    (
+        "ROL",
+        "Romanian Leu (1952-2005)",
+        2,
+        "ROL",
+        true,
+        Locale::EnUs,
+    ),
+    (
+        "RUR",
+        "Russian Ruble (1991-1997)",
+        2,
+        "RUR",
+        true,
+        Locale::EnUs,
+    ),
+    (
+        "TRL",
+        "Turkish Lira (1922-2005)",
+        0,
+        "TRL",
+        true,
+        Locale::EnUs,
+    ),
+    (
+        "VEB",
+        "Venezuelan Bolivar (1879-2007)",
+        2,
+        "VEB",
+        true,
+        Locale::EnUs,
+    ),
+    (
+        "VEF",
+        "Venezuelan Bolivar Fuerte",
+        2,
+        "VEF",
+        true,
+        Locale::EnUs,
+    ),
 ];
 
+/// Built-in cash rounding increments, in minor units, for currencies whose
+/// smallest coin is coarser than their minor unit.
+const BUILTIN_CASH_INCREMENTS: &[(&str, u32)] = &[
+    ("AUD", 5),
+    ("CAD", 5),
+    ("CHF", 5),
+    ("CZK", 100),
+    ("DKK", 50),
+    ("HUF", 500),
+    ("NOK", 100),
+    ("NZD", 10),
+    ("SEK", 100),
+];
+
+fn builtin_cash_increment(canonical: &str) -> Option<NonZeroU32> {
+    BUILTIN_CASH_INCREMENTS
+        .iter()
+        .find(|(code, _)| *code == canonical)
+        .and_then(|(_, increment)| NonZeroU32::new(*increment))
+}
+
 fn build_builtin_metadata() -> HashMap<String, CurrencyMetadata> {
     let mut map = HashMap::new();
     for (code, full_name, decimals, symbol, symbol_first, locale) in BUILTIN_CURRENCY_METADATA {
         let canonical = canonicalize(code).into_owned();
         map.insert(
-            canonical,
+            canonical.clone(),
             CurrencyMetadata {
                 full_name: Cow::Borrowed(*full_name),
                 minor_units: *decimals,
                 symbol: Cow::Borrowed(*symbol),
                 symbol_first: *symbol_first,
                 default_locale: *locale,
+                cash_increment: builtin_cash_increment(&canonical),
             },
         );
     }
@@ -166,40 +338,414 @@ fn build_builtin_metadata() -> HashMap<String, CurrencyMetadata> {
 static BUILTIN_METADATA: LazyLock<HashMap<String, CurrencyMetadata>> =
     LazyLock::new(build_builtin_metadata);
 
-static CUSTOM_METADATA: LazyLock<RwLock<HashMap<String, CurrencyMetadata>>> =
-    LazyLock::new(|| RwLock::new(HashMap::new()));
+static GLOBAL_REGISTRY: LazyLock<CurrencyRegistry> = LazyLock::new(CurrencyRegistry::new);
+
+/// Custom currency metadata layered over the built-in entries.
+///
+/// The free functions in this module ([`set_currency_metadata`],
+/// [`currency_metadata`], ...) and every API that does not take a registry
+/// use the process-wide [`CurrencyRegistry::global`] instance. Separate
+/// instances let parallel tests or tenants of one service register
+/// conflicting custom codes without seeing each other's entries; pass them to
+/// the `*_in` constructors on [`Money`](crate::Money) and, with
+/// `money-formatting`, to `LocalizedMoney::with_registry`.
+///
+/// ISO 4217 exponents, the built-in metadata and the built-in historic
+/// currencies are shared by every instance.
+///
+/// ```rust
+/// # use paft_money::{Currency, CurrencyRegistry, Locale, Money};
+/// let tenant_a = CurrencyRegistry::new();
+/// let tenant_b = CurrencyRegistry::new();
+/// tenant_a.set_metadata("PTS", "Points", 0, "pt", false, Locale::EnUs).unwrap();
+/// tenant_b.set_metadata("PTS", "Points", 2, "pt", false, Locale::EnUs).unwrap();
+///
+/// let pts = Currency::try_from_str("PTS").unwrap();
+/// let a = Money::from_canonical_str_in("12", pts.clone(), &tenant_a).unwrap();
+/// let b = Money::from_canonical_str_in("12.50", pts.clone(), &tenant_b).unwrap();
+/// assert_eq!((a.minor_units(), b.minor_units()), (0, 2));
+/// assert!(Money::from_canonical_str_in("12.5", pts, &tenant_a).is_err());
+/// ```
+#[derive(Debug, Default)]
+pub struct CurrencyRegistry {
+    custom: RwLock<HashMap<String, CurrencyMetadata>>,
+    pub(crate) historic: RwLock<HashMap<String, HistoricCurrency>>,
+}
 
-/// Acquires a read guard on the custom metadata registry, recovering from a
-/// poisoned lock instead of dropping the read silently.
+/// Acquires a read guard, recovering from a poisoned lock instead of dropping
+/// the read silently.
 ///
 /// A poisoned lock indicates a panic occurred while holding the write guard;
 /// the underlying `HashMap` is still intact, so we clear the poison and
 /// proceed. The trade-off is that we can never observe the panic from a
-/// metadata lookup; the previous implementation hid the same fact by
+/// registry lookup; the previous implementation hid the same fact by
 /// returning `None`, so callers see a strict improvement (data is still
 /// returned) without any new behaviour they can rely on.
-fn read_custom_metadata() -> RwLockReadGuard<'static, HashMap<String, CurrencyMetadata>> {
-    match CUSTOM_METADATA.read() {
+pub(crate) fn read_recovering<T>(lock: &RwLock<T>) -> RwLockReadGuard<'_, T> {
+    match lock.read() {
         Ok(guard) => guard,
         Err(poisoned) => {
-            CUSTOM_METADATA.clear_poison();
+            lock.clear_poison();
             poisoned.into_inner()
         }
     }
 }
 
-/// Acquires a write guard on the custom metadata registry, recovering from a
-/// poisoned lock so that registrations and clears never silently disappear.
-fn write_custom_metadata() -> RwLockWriteGuard<'static, HashMap<String, CurrencyMetadata>> {
-    match CUSTOM_METADATA.write() {
+/// Acquires a write guard, recovering from a poisoned lock so that
+/// registrations and clears never silently disappear.
+pub(crate) fn write_recovering<T>(lock: &RwLock<T>) -> RwLockWriteGuard<'_, T> {
+    match lock.write() {
         Ok(guard) => guard,
         Err(poisoned) => {
-            CUSTOM_METADATA.clear_poison();
+            lock.clear_poison();
             poisoned.into_inner()
         }
     }
 }
 
+impl Clone for CurrencyRegistry {
+    /// Copies the custom entries registered so far into an independent
+    /// registry.
+    fn clone(&self) -> Self {
+        Self {
+            custom: RwLock::new(self.read_custom().clone()),
+            historic: RwLock::new(read_recovering(&self.historic).clone()),
+        }
+    }
+}
+
+impl CurrencyRegistry {
+    /// An empty registry: only ISO 4217 data and built-in metadata.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The process-wide registry used by APIs that do not take one.
+    #[must_use]
+    pub fn global() -> &'static Self {
+        &GLOBAL_REGISTRY
+    }
+
+    fn read_custom(&self) -> RwLockReadGuard<'_, HashMap<String, CurrencyMetadata>> {
+        read_recovering(&self.custom)
+    }
+
+    fn write_custom(&self) -> RwLockWriteGuard<'_, HashMap<String, CurrencyMetadata>> {
+        write_recovering(&self.custom)
+    }
+
+    /// Registers `metadata`, keeping the currency's current cash increment
+    /// unless the scale changes.
+    fn insert_metadata(
+        &self,
+        code: &str,
+        mut metadata: CurrencyMetadata,
+        allow_scale_override: bool,
+    ) -> Result<Option<CurrencyMetadata>, MinorUnitError> {
+        let canonical = canonical_metadata_code(code)?;
+        validate_minor_units(metadata.minor_units)?;
+
+        let mut custom = self.write_custom();
+        check_scale_change(
+            &canonical,
+            metadata.minor_units,
+            &custom,
+            allow_scale_override,
+        )?;
+        metadata.cash_increment = match custom
+            .get(&canonical)
+            .or_else(|| BUILTIN_METADATA.get(&canonical))
+        {
+            Some(current) if current.minor_units == metadata.minor_units => current.cash_increment,
+            Some(_) => None,
+            None => builtin_cash_increment(&canonical),
+        };
+        Ok(custom.insert(canonical, metadata))
+    }
+
+    /// Registers metadata in this registry; see [`set_currency_metadata`].
+    ///
+    /// # Errors
+    /// See [`set_currency_metadata`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self, full_name, symbol), err)
+    )]
+    pub fn set_metadata(
+        &self,
+        code: &str,
+        full_name: impl Into<String>,
+        minor_units: u8,
+        symbol: impl Into<String>,
+        symbol_first: bool,
+        default_locale: Locale,
+    ) -> Result<Option<CurrencyMetadata>, MinorUnitError> {
+        let metadata = make_metadata(full_name, minor_units, symbol, symbol_first, default_locale);
+        self.insert_metadata(code, metadata, false)
+    }
+
+    /// Replaces metadata in this registry, including a non-ISO scale; see
+    /// [`override_currency_metadata`].
+    ///
+    /// # Errors
+    /// See [`override_currency_metadata`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self, full_name, symbol), err)
+    )]
+    pub fn override_metadata(
+        &self,
+        code: &str,
+        full_name: impl Into<String>,
+        minor_units: u8,
+        symbol: impl Into<String>,
+        symbol_first: bool,
+        default_locale: Locale,
+    ) -> Result<Option<CurrencyMetadata>, MinorUnitError> {
+        let metadata = make_metadata(full_name, minor_units, symbol, symbol_first, default_locale);
+        self.insert_metadata(code, metadata, true)
+    }
+
+    /// Metadata registered in this registry for `code`, falling back to the
+    /// built-in entries.
+    #[must_use]
+    pub fn metadata(&self, code: &str) -> Option<CurrencyMetadata> {
+        let canonical = canonicalize(code);
+        let custom = self.read_custom().get(canonical.as_ref()).cloned();
+        if let Some(custom) = custom {
+            return Some(custom);
+        }
+
+        BUILTIN_METADATA.get(canonical.as_ref()).cloned()
+    }
+
+    /// Removes a custom entry from this registry, returning it.
+    pub fn clear_metadata(&self, code: &str) -> Option<CurrencyMetadata> {
+        let canonical = canonicalize(code);
+        self.write_custom().remove(canonical.as_ref())
+    }
+
+    /// Registers every entry of a metadata batch in this registry, or none of
+    /// them; see [`load_currency_metadata`].
+    ///
+    /// # Errors
+    /// See [`load_currency_metadata`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self, entries), fields(entries = entries.len()), err)
+    )]
+    pub fn load(
+        &self,
+        entries: &[CurrencyMetadataEntry],
+        allow_scale_override: bool,
+    ) -> Result<usize, MetadataLoadError> {
+        let mut validated: Vec<(String, CurrencyMetadata)> = Vec::with_capacity(entries.len());
+        let mut custom = self.write_custom();
+        for (index, entry) in entries.iter().enumerate() {
+            let invalid = |source| MetadataLoadError::InvalidEntry {
+                index,
+                code: entry.code.clone(),
+                source,
+            };
+            let canonical = canonical_metadata_code(&entry.code).map_err(invalid)?;
+            if let Some(first) = validated.iter().position(|(code, _)| *code == canonical) {
+                return Err(MetadataLoadError::DuplicateCode {
+                    code: canonical,
+                    first,
+                    second: index,
+                });
+            }
+            validate_minor_units(entry.minor_units).map_err(invalid)?;
+            check_scale_change(&canonical, entry.minor_units, &custom, allow_scale_override)
+                .map_err(invalid)?;
+            validated.push((
+                canonical,
+                CurrencyMetadata {
+                    cash_increment: entry.cash_increment,
+                    ..make_metadata(
+                        entry.full_name.clone(),
+                        entry.minor_units,
+                        entry.symbol.clone(),
+                        entry.symbol_first,
+                        entry.default_locale,
+                    )
+                },
+            ));
+        }
+        let loaded = validated.len();
+        custom.extend(validated);
+        drop(custom);
+        Ok(loaded)
+    }
+
+    /// Snapshot of this registry's effective metadata, ordered by code; see
+    /// [`export_currency_metadata`].
+    #[must_use]
+    pub fn export(&self) -> Vec<CurrencyMetadataEntry> {
+        let custom = self.read_custom();
+        let builtin = BUILTIN_METADATA
+            .iter()
+            .filter(|(code, _)| !custom.contains_key(*code));
+        let mut entries: Vec<CurrencyMetadataEntry> = custom
+            .iter()
+            .chain(builtin)
+            .map(|(code, meta)| CurrencyMetadataEntry::from_metadata(code.clone(), meta))
+            .collect();
+        drop(custom);
+        entries.sort_by(|a, b| a.code.cmp(&b.code));
+        entries
+    }
+
+    /// Number of decimal places for `currency` as resolved by this registry;
+    /// see [`Currency::decimal_places`].
+    ///
+    /// # Errors
+    /// Returns `MoneyError::MetadataNotFound` when neither ISO 4217 nor this
+    /// registry defines a scale for the currency.
+    pub fn decimal_places(&self, currency: &Currency) -> Result<u8, MoneyError> {
+        if let Currency::Iso(iso) = currency
+            && let Some(exp) = iso.exponent().and_then(|e| u8::try_from(e).ok())
+        {
+            return Ok(exp);
+        }
+
+        self.metadata(currency.code())
+            .map(|meta| meta.minor_units)
+            .ok_or_else(|| MoneyError::MetadataNotFound {
+                currency: currency.clone(),
+            })
+    }
+
+    /// Human-readable name for `currency`; see [`Currency::full_name`].
+    #[must_use]
+    pub fn full_name(&self, currency: &Currency) -> Cow<'static, str> {
+        match currency {
+            Currency::Iso(iso) => Cow::Owned(iso.name().to_string()),
+            Currency::BTC
+            | Currency::ETH
+            | Currency::XMR
+            | Currency::USDC
+            | Currency::USDT
+            | Currency::Other(_) => self.metadata(currency.code()).map_or_else(
+                || Cow::Owned(currency.code().to_string()),
+                |meta| meta.full_name,
+            ),
+        }
+    }
+
+    /// Cash rounding increment of `currency` in minor units, if it is coarser
+    /// than one minor unit.
+    #[must_use]
+    pub fn cash_increment(&self, currency: &Currency) -> Option<NonZeroU32> {
+        self.metadata(currency.code()).map_or_else(
+            || builtin_cash_increment(currency.code()),
+            |meta| meta.cash_increment,
+        )
+    }
+
+    /// Sets (or with `None`, removes) the cash rounding increment of `code`,
+    /// in minor units, returning the previous one.
+    ///
+    /// ISO currencies without registered metadata get an entry with their
+    /// ISO name and exponent and the code as symbol.
+    ///
+    /// # Errors
+    /// Returns [`MinorUnitError::InvalidCurrencyCode`] when `code` is not a
+    /// valid currency code, or [`MinorUnitError::UnknownMinorUnits`] when it
+    /// has neither metadata nor an ISO 4217 exponent.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self), err)
+    )]
+    pub fn set_cash_increment(
+        &self,
+        code: &str,
+        increment: Option<NonZeroU32>,
+    ) -> Result<Option<NonZeroU32>, MinorUnitError> {
+        let canonical = canonical_metadata_code(code)?;
+        let mut custom = self.write_custom();
+        if let Some(existing) = custom.get_mut(&canonical) {
+            return Ok(std::mem::replace(&mut existing.cash_increment, increment));
+        }
+        let mut metadata = if let Some(builtin) = BUILTIN_METADATA.get(&canonical) {
+            builtin.clone()
+        } else {
+            let (iso, minor_units) = iso_currency::Currency::from_code(&canonical)
+                .zip(iso_minor_units(&canonical))
+                .ok_or_else(|| MinorUnitError::UnknownMinorUnits {
+                    code: canonical.clone(),
+                })?;
+            CurrencyMetadata {
+                cash_increment: builtin_cash_increment(&canonical),
+                ..make_metadata(
+                    iso.name(),
+                    minor_units,
+                    canonical.clone(),
+                    true,
+                    Locale::EnUs,
+                )
+            }
+        };
+        let previous = std::mem::replace(&mut metadata.cash_increment, increment);
+        custom.insert(canonical, metadata);
+        drop(custom);
+        Ok(previous)
+    }
+
+    /// Preferred symbol for `currency`; see [`Currency::symbol`].
+    #[cfg(feature = "money-formatting")]
+    #[must_use]
+    pub fn symbol(&self, currency: &Currency) -> Option<Cow<'static, str>> {
+        if let Some(meta) = self.metadata(currency.code()) {
+            let symbol = meta.symbol;
+            if symbol.is_empty() {
+                return None;
+            }
+            return Some(symbol);
+        }
+
+        Some(Cow::Owned(currency.code().to_string()))
+    }
+
+    /// Whether the symbol of `currency` precedes the amount; see
+    /// [`Currency::symbol_first`].
+    #[cfg(feature = "money-formatting")]
+    #[must_use]
+    pub fn symbol_first(&self, currency: &Currency) -> bool {
+        self.metadata(currency.code())
+            .is_none_or(|meta| meta.symbol_first)
+    }
+
+    /// Default formatting locale for `currency`; see
+    /// [`Currency::default_locale`].
+    #[cfg(feature = "money-formatting")]
+    #[must_use]
+    pub fn default_locale(&self, currency: &Currency) -> Locale {
+        self.metadata(currency.code())
+            .map_or(Locale::EnUs, |meta| meta.default_locale)
+    }
+
+    /// Currencies whose registered or built-in metadata symbol equals
+    /// `symbol` (ignoring ASCII case), ordered by code.
+    #[cfg(feature = "money-formatting")]
+    pub(crate) fn currencies_with_symbol(&self, symbol: &str) -> Vec<Currency> {
+        let custom = self.read_custom();
+        let builtin = BUILTIN_METADATA
+            .iter()
+            .filter(|(code, _)| !custom.contains_key(*code));
+        let mut currencies: Vec<Currency> = custom
+            .iter()
+            .chain(builtin)
+            .filter(|(_, meta)| !meta.symbol.is_empty() && meta.symbol.eq_ignore_ascii_case(symbol))
+            .filter_map(|(code, _)| Currency::try_from_str(code).ok())
+            .collect();
+        drop(custom);
+        currencies.sort_by(|a, b| a.code().cmp(b.code()));
+        currencies
+    }
+}
+
 /// Attempts to normalize a currency code to a canonical variant or common `Other` value.
 ///
 /// # Errors
@@ -254,50 +800,41 @@ fn make_metadata(
         symbol: Cow::Owned(symbol.into()),
         symbol_first,
         default_locale,
+        cash_increment: None,
     }
 }
 
-fn insert_currency_metadata(
-    code: &str,
-    full_name: impl Into<String>,
-    minor_units: u8,
-    symbol: impl Into<String>,
-    symbol_first: bool,
-    default_locale: Locale,
-    allow_scale_override: bool,
-) -> Result<Option<CurrencyMetadata>, MinorUnitError> {
-    let canonical = Currency::try_from_str(code)
+fn canonical_metadata_code(code: &str) -> Result<String, MinorUnitError> {
+    Currency::try_from_str(code)
         .map(|currency| currency.code().to_string())
         .map_err(|_| MinorUnitError::InvalidCurrencyCode {
             code: code.to_string(),
-        })?;
-    validate_minor_units(minor_units)?;
-
-    let metadata = make_metadata(full_name, minor_units, symbol, symbol_first, default_locale);
-    let mut custom = write_custom_metadata();
-
-    if let Some(existing) = iso_minor_units(canonical.as_ref())
-        && existing != minor_units
-    {
-        return Err(MinorUnitError::MinorUnitsAlreadyRegistered {
-            code: canonical.clone(),
-            existing,
-            requested: minor_units,
-        });
-    }
+        })
+}
 
-    if !allow_scale_override
-        && let Some(existing) = registered_minor_units(canonical.as_ref(), &custom)
-        && existing != minor_units
-    {
-        return Err(MinorUnitError::MinorUnitsAlreadyRegistered {
-            code: canonical.clone(),
-            existing,
-            requested: minor_units,
-        });
+/// Rejects `minor_units` when it differs from the ISO scale, or from any
+/// registered scale unless `allow_scale_override` is set.
+fn check_scale_change(
+    canonical: &str,
+    minor_units: u8,
+    custom: &HashMap<String, CurrencyMetadata>,
+    allow_scale_override: bool,
+) -> Result<(), MinorUnitError> {
+    let existing = if allow_scale_override {
+        iso_minor_units(canonical)
+    } else {
+        registered_minor_units(canonical, custom)
+    };
+    match existing {
+        Some(existing) if existing != minor_units => {
+            Err(MinorUnitError::MinorUnitsAlreadyRegistered {
+                code: canonical.to_string(),
+                existing,
+                requested: minor_units,
+            })
+        }
+        _ => Ok(()),
     }
-
-    Ok(custom.insert(canonical, metadata))
 }
 
 /// Registers metadata for a custom currency.
@@ -324,14 +861,13 @@ pub fn set_currency_metadata(
     symbol_first: bool,
     default_locale: Locale,
 ) -> Result<Option<CurrencyMetadata>, MinorUnitError> {
-    insert_currency_metadata(
+    CurrencyRegistry::global().set_metadata(
         code,
         full_name,
         minor_units,
         symbol,
         symbol_first,
         default_locale,
-        false,
     )
 }
 
@@ -361,27 +897,72 @@ pub fn override_currency_metadata(
     symbol_first: bool,
     default_locale: Locale,
 ) -> Result<Option<CurrencyMetadata>, MinorUnitError> {
-    insert_currency_metadata(
+    CurrencyRegistry::global().override_metadata(
         code,
         full_name,
         minor_units,
         symbol,
         symbol_first,
         default_locale,
-        true,
     )
 }
 
+/// Sets (or with `None`, removes) the cash rounding increment of `code` in
+/// the global registry, in minor units, returning the previous one.
+///
+/// [`Money::round_to_cash`](crate::Money::round_to_cash) rounds to this
+/// increment. Registering metadata for a code keeps its increment unless the
+/// registration changes the scale.
+///
+/// # Errors
+/// See [`CurrencyRegistry::set_cash_increment`].
+pub fn set_cash_increment(
+    code: &str,
+    increment: Option<NonZeroU32>,
+) -> Result<Option<NonZeroU32>, MinorUnitError> {
+    CurrencyRegistry::global().set_cash_increment(code, increment)
+}
+
 /// Retrieves metadata for a custom currency, if registered.
 #[must_use]
 pub fn currency_metadata(code: &str) -> Option<CurrencyMetadata> {
-    let canonical = canonicalize(code);
-    let custom = read_custom_metadata().get(canonical.as_ref()).cloned();
-    if let Some(custom) = custom {
-        return Some(custom);
-    }
+    CurrencyRegistry::global().metadata(code)
+}
 
-    BUILTIN_METADATA.get(canonical.as_ref()).cloned()
+/// Registers every entry of a metadata batch, or none of them.
+///
+/// All entries are validated first, under the same rules as
+/// [`set_currency_metadata`] (or [`override_currency_metadata`] when
+/// `allow_scale_override` is set), and a code may appear only once per batch.
+/// The registry is updated only when every entry passes, and it stays locked
+/// for the whole load so concurrent registrations cannot interleave. Returns
+/// the number of entries registered.
+///
+/// Use [`CurrencyMetadataFile`](crate::CurrencyMetadataFile) to read the
+/// batch from JSON or TOML.
+///
+/// # Errors
+/// Returns [`MetadataLoadError::InvalidEntry`] with the first failing entry's
+/// position and [`MinorUnitError`], or [`MetadataLoadError::DuplicateCode`]
+/// when two entries name the same currency.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip(entries), fields(entries = entries.len()), err)
+)]
+pub fn load_currency_metadata(
+    entries: &[CurrencyMetadataEntry],
+    allow_scale_override: bool,
+) -> Result<usize, MetadataLoadError> {
+    CurrencyRegistry::global().load(entries, allow_scale_override)
+}
+
+/// Snapshot of the effective metadata registry: built-in entries overlaid by
+/// custom registrations, ordered by code.
+///
+/// The result can be written back with [`load_currency_metadata`].
+#[must_use]
+pub fn export_currency_metadata() -> Vec<CurrencyMetadataEntry> {
+    CurrencyRegistry::global().export()
 }
 
 /// Removes metadata for a custom currency and any associated minor-unit overrides.
@@ -394,8 +975,7 @@ pub fn currency_metadata(code: &str) -> Option<CurrencyMetadata> {
     reason = "callers commonly clear metadata for the side effect and may ignore the previous value"
 )]
 pub fn clear_currency_metadata(code: &str) -> Option<CurrencyMetadata> {
-    let canonical = canonicalize(code);
-    write_custom_metadata().remove(canonical.as_ref())
+    CurrencyRegistry::global().clear_metadata(code)
 }
 
 #[cfg(test)]
@@ -405,7 +985,7 @@ mod tests {
     use std::sync::Mutex;
 
     // The metadata registry is global, so a poison test must run alone
-    // against the CUSTOM_METADATA lock. We use a per-process mutex to
+    // against the global registry's lock. We use a per-process mutex to
     // serialize against any other tests in the same binary that touch
     // the registry.
     static SERIALIZE: Mutex<()> = Mutex::new(());
@@ -414,16 +994,16 @@ mod tests {
         let _ = catch_unwind(AssertUnwindSafe(|| {
             // Acquire the write lock and panic while holding it. The
             // panic poisons the lock; subsequent acquisitions through
-            // `read_custom_metadata`/`write_custom_metadata` must still
-            // succeed (recovering via `clear_poison`).
-            let _guard = CUSTOM_METADATA.write().unwrap();
+            // `read_custom`/`write_custom` must still succeed (recovering
+            // via `clear_poison`).
+            let _guard = GLOBAL_REGISTRY.custom.write().unwrap();
             panic!("intentionally poisoning the metadata lock");
         }));
         // Sanity: the lock should now be poisoned. We don't assert this
         // directly because `RwLock::is_poisoned` is non-portable, but
         // any caller using `.read()`/`.write()` directly would now see
         // a poison error.
-        assert!(CUSTOM_METADATA.is_poisoned());
+        assert!(GLOBAL_REGISTRY.custom.is_poisoned());
     }
 
     #[test]
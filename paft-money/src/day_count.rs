@@ -0,0 +1,391 @@
+//! Day-count conventions and interest accrual on [`Money`].
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::decimal::{self, Decimal, RoundingStrategy, ToPrimitive};
+use crate::error::{MoneyError, MoneyParseError};
+use crate::exact::{checked_add_decimal, checked_div_decimal, checked_mul_decimal};
+use crate::money::Money;
+
+/// Fractional digits kept for intermediate compounding factors, so both
+/// decimal backends accumulate the same rounding error.
+const WORKING_SCALE: u32 = 20;
+
+/// `365 * 366`: common denominator of ACT/ACT ISDA year fractions.
+const ACT_ACT_DENOMINATOR: i64 = 133_590;
+
+/// How the time between two dates is measured as a fraction of a year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum DayCount {
+    /// Actual days over 360.
+    #[serde(rename = "ACT/360")]
+    Act360,
+    /// Actual days over a fixed 365.
+    #[serde(rename = "ACT/365F")]
+    Act365Fixed,
+    /// Actual days in each calendar year over that year's length (365 or
+    /// 366), summed.
+    #[serde(rename = "ACT/ACT ISDA")]
+    ActActIsda,
+    /// 30/360 bond basis (ISDA 2006 4.16(f)): day 31 becomes 30, and an end
+    /// day of 31 becomes 30 only when the start day is 30 or 31.
+    #[serde(rename = "30/360")]
+    Thirty360,
+    /// 30/360 US (SIA): like [`DayCount::Thirty360`], and the last day of
+    /// February counts as day 30 at the start, and at the end when the start
+    /// is also the last day of February.
+    #[serde(rename = "30/360 US")]
+    Thirty360Us,
+    /// 30E/360 Eurobond basis (ISDA 2006 4.16(g)): day 31 becomes 30 at both
+    /// ends.
+    #[serde(rename = "30E/360")]
+    ThirtyE360,
+    /// 30E/360 ISDA (ISDA 2006 4.16(h)): the last day of any month becomes
+    /// 30 at both ends.
+    ///
+    /// The convention leaves an end date on the last day of February
+    /// unchanged when it is the instrument's maturity; callers accruing up to
+    /// such a maturity should use [`DayCount::ThirtyE360`] for that period.
+    #[serde(rename = "30E/360 ISDA")]
+    ThirtyE360Isda,
+}
+
+impl DayCount {
+    /// Market name of the convention, such as `"ACT/360"`.
+    #[must_use]
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::Act360 => "ACT/360",
+            Self::Act365Fixed => "ACT/365F",
+            Self::ActActIsda => "ACT/ACT ISDA",
+            Self::Thirty360 => "30/360",
+            Self::Thirty360Us => "30/360 US",
+            Self::ThirtyE360 => "30E/360",
+            Self::ThirtyE360Isda => "30E/360 ISDA",
+        }
+    }
+
+    /// Days between `start` and `end` under this convention: actual days for
+    /// the ACT conventions, 30-day months for the 30/360 family. Negative
+    /// when `end` is before `start`.
+    #[must_use]
+    pub fn days(self, start: NaiveDate, end: NaiveDate) -> i64 {
+        match self {
+            Self::Act360 | Self::Act365Fixed | Self::ActActIsda => (end - start).num_days(),
+            Self::Thirty360 | Self::Thirty360Us | Self::ThirtyE360 | Self::ThirtyE360Isda => {
+                self.thirty_360_days(start, end)
+            }
+        }
+    }
+
+    /// Fraction of a year between `start` and `end`; negative when `end` is
+    /// before `start`.
+    ///
+    /// ```rust
+    /// # use chrono::NaiveDate;
+    /// # use paft_money::DayCount;
+    /// let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+    /// assert_eq!(DayCount::Act360.days(start, end), 90);
+    /// assert_eq!(DayCount::Act360.year_fraction(start, end).to_string(), "0.25");
+    /// assert_eq!(DayCount::Thirty360.days(start, end), 90);
+    /// ```
+    ///
+    /// # Panics
+    /// Never: the denominator is always a positive constant.
+    #[must_use]
+    pub fn year_fraction(self, start: NaiveDate, end: NaiveDate) -> Decimal {
+        let (numerator, denominator) = self.fraction(start, end);
+        decimal::checked_div(&Decimal::from(numerator), &Decimal::from(denominator))
+            .expect("day-count denominators are positive")
+    }
+
+    /// Year fraction as an exact `numerator / denominator` pair.
+    fn fraction(self, start: NaiveDate, end: NaiveDate) -> (i64, i64) {
+        match self {
+            Self::Act360
+            | Self::Thirty360
+            | Self::Thirty360Us
+            | Self::ThirtyE360
+            | Self::ThirtyE360Isda => (self.days(start, end), 360),
+            Self::Act365Fixed => (self.days(start, end), 365),
+            Self::ActActIsda if end < start => {
+                let (numerator, denominator) = act_act_isda(end, start);
+                (-numerator, denominator)
+            }
+            Self::ActActIsda => act_act_isda(start, end),
+        }
+    }
+
+    fn thirty_360_days(self, start: NaiveDate, end: NaiveDate) -> i64 {
+        let (mut d1, mut d2) = (start.day(), end.day());
+        match self {
+            Self::Thirty360 => {
+                if d1 == 31 {
+                    d1 = 30;
+                }
+                if d2 == 31 && d1 == 30 {
+                    d2 = 30;
+                }
+            }
+            Self::Thirty360Us => {
+                let start_feb_end = start.month() == 2 && is_month_end(start);
+                if start_feb_end && end.month() == 2 && is_month_end(end) {
+                    d2 = 30;
+                }
+                if start_feb_end || d1 == 31 {
+                    d1 = 30;
+                }
+                if d2 == 31 && d1 == 30 {
+                    d2 = 30;
+                }
+            }
+            Self::ThirtyE360 => {
+                d1 = d1.min(30);
+                d2 = d2.min(30);
+            }
+            Self::ThirtyE360Isda => {
+                if is_month_end(start) {
+                    d1 = 30;
+                }
+                if is_month_end(end) {
+                    d2 = 30;
+                }
+            }
+            Self::Act360 | Self::Act365Fixed | Self::ActActIsda => {
+                unreachable!("only 30/360 conventions count 30-day months")
+            }
+        }
+        360 * i64::from(end.year() - start.year())
+            + 30 * (i64::from(end.month()) - i64::from(start.month()))
+            + (i64::from(d2) - i64::from(d1))
+    }
+}
+
+fn is_month_end(date: NaiveDate) -> bool {
+    date.succ_opt()
+        .is_none_or(|next| next.month() != date.month())
+}
+
+/// ACT/ACT ISDA fraction for `start <= end` over [`ACT_ACT_DENOMINATOR`].
+fn act_act_isda(start: NaiveDate, end: NaiveDate) -> (i64, i64) {
+    let mut numerator = 0;
+    let mut from = start;
+    while from < end {
+        let to = NaiveDate::from_ymd_opt(from.year() + 1, 1, 1).map_or(end, |next| next.min(end));
+        let weight = if from.leap_year() { 365 } else { 366 };
+        numerator += (to - from).num_days() * weight;
+        from = to;
+    }
+    (numerator, ACT_ACT_DENOMINATOR)
+}
+
+impl fmt::Display for DayCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl FromStr for DayCount {
+    type Err = MoneyParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let normalized = input.trim().to_ascii_uppercase();
+        Ok(match normalized.as_str() {
+            "ACT/360" => Self::Act360,
+            "ACT/365F" | "ACT/365 FIXED" => Self::Act365Fixed,
+            "ACT/ACT ISDA" | "ACT/ACT" => Self::ActActIsda,
+            "30/360" | "30/360 BOND BASIS" => Self::Thirty360,
+            "30/360 US" => Self::Thirty360Us,
+            "30E/360" => Self::ThirtyE360,
+            "30E/360 ISDA" => Self::ThirtyE360Isda,
+            _ => {
+                return Err(MoneyParseError::InvalidEnumValue {
+                    enum_name: "DayCount",
+                    value: input.to_string(),
+                });
+            }
+        })
+    }
+}
+
+/// How often compound interest is added to the principal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum Compounding {
+    /// Once a year.
+    Annual,
+    /// Twice a year.
+    SemiAnnual,
+    /// Four times a year.
+    Quarterly,
+    /// Twelve times a year.
+    Monthly,
+}
+
+impl Compounding {
+    /// Number of compounding periods per year.
+    #[must_use]
+    pub const fn periods_per_year(self) -> u32 {
+        match self {
+            Self::Annual => 1,
+            Self::SemiAnnual => 2,
+            Self::Quarterly => 4,
+            Self::Monthly => 12,
+        }
+    }
+}
+
+impl Money {
+    /// Simple interest accrued on this principal at the annual `rate` from
+    /// `start` to `end`, rounded to the currency's minor units with
+    /// `rounding`.
+    ///
+    /// The result is the interest only; add it to the principal for the
+    /// accrued balance. The year fraction is applied exactly, so midpoints
+    /// round as `rounding` specifies.
+    ///
+    /// ```rust
+    /// # use chrono::NaiveDate;
+    /// # use paft_decimal::{Decimal, RoundingStrategy};
+    /// # use paft_money::{Currency, DayCount, IsoCurrency, Money};
+    /// let deposit = Money::from_canonical_str("1000000", Currency::Iso(IsoCurrency::USD)).unwrap();
+    /// let rate: Decimal = "0.05".parse().unwrap();
+    /// let interest = deposit
+    ///     .simple_interest(
+    ///         &rate,
+    ///         NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+    ///         NaiveDate::from_ymd_opt(2024, 4, 1).unwrap(),
+    ///         DayCount::Act360,
+    ///         RoundingStrategy::MidpointAwayFromZero,
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!(interest.format(), "12638.89 USD");
+    /// ```
+    ///
+    /// # Errors
+    /// - [`MoneyError::InvalidAccrualPeriod`] when `end` is before `start`.
+    /// - [`MoneyError::ConversionError`] when the interest overflows the
+    ///   decimal backend.
+    pub fn simple_interest(
+        &self,
+        rate: &Decimal,
+        start: NaiveDate,
+        end: NaiveDate,
+        day_count: DayCount,
+        rounding: RoundingStrategy,
+    ) -> Result<Self, MoneyError> {
+        let (numerator, denominator) = accrual_fraction(start, end, day_count)?;
+        let per_year = checked_mul_decimal(&self.amount(), rate)?;
+        let scaled = checked_mul_decimal(&per_year, &Decimal::from(numerator))?;
+        let interest = checked_div_decimal(&scaled, &Decimal::from(denominator))?;
+        Ok(self.rounded_like(&interest, rounding))
+    }
+
+    /// Compound interest accrued on this principal at the nominal annual
+    /// `rate`, compounded `compounding` times a year, from `start` to `end`,
+    /// rounded to the currency's minor units with `rounding`.
+    ///
+    /// Whole compounding periods compound; a trailing partial period accrues
+    /// simple interest at the periodic rate. The result is the interest
+    /// only; add it to the principal for the accrued balance.
+    ///
+    /// ```rust
+    /// # use chrono::NaiveDate;
+    /// # use paft_decimal::{Decimal, RoundingStrategy};
+    /// # use paft_money::{Compounding, Currency, DayCount, IsoCurrency, Money};
+    /// let principal = Money::from_canonical_str("1000", Currency::Iso(IsoCurrency::EUR)).unwrap();
+    /// let rate: Decimal = "0.04".parse().unwrap();
+    /// let interest = principal
+    ///     .compound_interest(
+    ///         &rate,
+    ///         NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+    ///         NaiveDate::from_ymd_opt(2022, 1, 1).unwrap(),
+    ///         DayCount::Thirty360,
+    ///         Compounding::SemiAnnual,
+    ///         RoundingStrategy::MidpointAwayFromZero,
+    ///     )
+    ///     .unwrap();
+    /// // 1000 * 1.02^4 - 1000
+    /// assert_eq!(interest.format(), "82.43 EUR");
+    /// ```
+    ///
+    /// # Errors
+    /// - [`MoneyError::InvalidAccrualPeriod`] when `end` is before `start`.
+    /// - [`MoneyError::ConversionError`] when the interest overflows the
+    ///   decimal backend.
+    pub fn compound_interest(
+        &self,
+        rate: &Decimal,
+        start: NaiveDate,
+        end: NaiveDate,
+        day_count: DayCount,
+        compounding: Compounding,
+        rounding: RoundingStrategy,
+    ) -> Result<Self, MoneyError> {
+        let (numerator, denominator) = accrual_fraction(start, end, day_count)?;
+        let periods_per_year = i64::from(compounding.periods_per_year());
+        let elapsed = numerator * periods_per_year;
+        let whole_periods = elapsed / denominator;
+        let stub = elapsed % denominator;
+
+        let periodic_rate = checked_div_decimal(rate, &Decimal::from(periods_per_year))?;
+        let base = checked_add_decimal(&decimal::one(), &periodic_rate)?;
+        let compounded = checked_pow(&base, whole_periods)?;
+        let stub_rate = checked_div_decimal(
+            &checked_mul_decimal(&periodic_rate, &Decimal::from(stub))?,
+            &Decimal::from(denominator),
+        )?;
+        let stub_growth = checked_add_decimal(&decimal::one(), &stub_rate)?;
+        let growth = working(&checked_mul_decimal(&compounded, &stub_growth)?);
+        let balance = checked_mul_decimal(&self.amount(), &growth)?;
+        let interest =
+            decimal::checked_sub(&balance, &self.amount()).ok_or(MoneyError::ConversionError)?;
+        Ok(self.rounded_like(&interest, rounding))
+    }
+
+    fn rounded_like(&self, amount: &Decimal, rounding: RoundingStrategy) -> Self {
+        let minor_units = self.minor_units();
+        let rounded = decimal::round_dp_with_strategy(amount, u32::from(minor_units), rounding);
+        Self::from_rounded_parts(&rounded, self.currency().clone(), minor_units)
+    }
+}
+
+fn accrual_fraction(
+    start: NaiveDate,
+    end: NaiveDate,
+    day_count: DayCount,
+) -> Result<(i64, i64), MoneyError> {
+    if end < start {
+        return Err(MoneyError::InvalidAccrualPeriod { start, end });
+    }
+    Ok(day_count.fraction(start, end))
+}
+
+fn working(value: &Decimal) -> Decimal {
+    decimal::round_dp_with_strategy(value, WORKING_SCALE, RoundingStrategy::MidpointNearestEven)
+}
+
+/// `base^exponent` by repeated squaring, rounding intermediates to
+/// [`WORKING_SCALE`].
+fn checked_pow(base: &Decimal, exponent: i64) -> Result<Decimal, MoneyError> {
+    let mut result = decimal::one();
+    let mut square = working(base);
+    let mut remaining = exponent.to_u64().ok_or(MoneyError::ConversionError)?;
+    while remaining > 0 {
+        if remaining & 1 == 1 {
+            result = working(&checked_mul_decimal(&result, &square)?);
+        }
+        remaining >>= 1;
+        if remaining > 0 {
+            square = working(&checked_mul_decimal(&square, &square)?);
+        }
+    }
+    Ok(result)
+}
@@ -1,5 +1,5 @@
 pub use paft_decimal::{
     Decimal, RoundingStrategy, ToPrimitive, checked_add, checked_div, checked_mul, checked_sub,
     clone_decimal, fractional_digit_count, max_decimal_precision, one, parse_decimal,
-    round_dp_with_strategy, to_canonical_string, try_from_scaled_units, zero,
+    round_dp_with_strategy, to_canonical_string, try_from_scaled_units, try_to_scaled_units, zero,
 };
@@ -1,5 +1,6 @@
 use crate::currency::Currency;
 use crate::decimal::Decimal;
+use chrono::NaiveDate;
 use thiserror::Error;
 
 #[cfg(feature = "money-formatting")]
@@ -69,6 +70,90 @@ pub enum MoneyError {
         /// The currency of the money being converted.
         money_currency: Currency,
     },
+    /// Occurs when no exchange rate, direct or derived, connects two currencies.
+    #[error("no exchange rate available from {from} to {to}")]
+    ExchangeRateNotFound {
+        /// The source currency.
+        from: Currency,
+        /// The target currency.
+        to: Currency,
+    },
+    /// Occurs when adding an exchange rate that contradicts one already held
+    /// for the same pair (in either direction).
+    #[error("conflicting exchange rate for {from}/{to}: already holding {existing}")]
+    ConflictingExchangeRate {
+        /// The source currency of the rejected rate.
+        from: Currency,
+        /// The target currency of the rejected rate.
+        to: Currency,
+        /// The held rate, expressed in the direction of the rejected rate.
+        existing: Decimal,
+    },
+    /// Occurs when collapsing amounts into `target` and no rate is available
+    /// for some of their currencies.
+    #[error("no exchange rate into {target} for: {}", format_currencies(missing))]
+    MissingExchangeRates {
+        /// The currency being collapsed into.
+        target: Currency,
+        /// Every currency lacking a rate into `target`.
+        missing: Vec<Currency>,
+    },
+    /// Occurs when equally short derivation paths between two currencies
+    /// yield different rates.
+    #[error("ambiguous exchange rate from {from} to {to}: equally short paths disagree")]
+    AmbiguousExchangeRate {
+        /// The source currency.
+        from: Currency,
+        /// The target currency.
+        to: Currency,
+    },
+    /// Occurs when an allocation ratio is negative.
+    #[error("allocation ratio at index {index} is negative: {ratio}")]
+    NegativeAllocationRatio {
+        /// Position of the offending ratio.
+        index: usize,
+        /// The offending ratio.
+        ratio: Decimal,
+    },
+    /// Occurs when allocating into zero parts.
+    #[error("cannot allocate into zero parts")]
+    EmptyAllocation,
+    /// Occurs when allocation ratios sum to zero, leaving no share to assign.
+    #[error("allocation ratios sum to zero")]
+    ZeroAllocationTotal,
+    /// Occurs when a rounding increment is not a positive multiple of the
+    /// value's minor unit.
+    #[error("rounding increment {increment} is not a positive multiple of 10^-{minor_units}")]
+    InvalidRoundingIncrement {
+        /// The rejected increment.
+        increment: Decimal,
+        /// Minor-unit scale of the value being rounded.
+        minor_units: u8,
+    },
+    /// Occurs when redenominating a currency that has no successor in effect
+    /// on the requested date.
+    #[error("{currency} has no successor currency in effect on {date}")]
+    NoSuccessorCurrency {
+        /// The currency being redenominated.
+        currency: Currency,
+        /// The requested conversion date.
+        date: NaiveDate,
+    },
+    /// Occurs when an interest accrual period ends before it starts.
+    #[error("accrual period ends ({end}) before it starts ({start})")]
+    InvalidAccrualPeriod {
+        /// First day of the period.
+        start: NaiveDate,
+        /// Last day of the period.
+        end: NaiveDate,
+    },
+    /// Occurs when a chain of successor currencies leads back to a currency
+    /// already in the chain.
+    #[error("successor chain of {currency} loops back to it")]
+    SuccessorCycle {
+        /// A currency on the cycle.
+        currency: Currency,
+    },
     /// Occurs when attempting to use a currency without registered metadata.
     #[error("metadata not registered for currency {currency}")]
     MetadataNotFound {
@@ -94,6 +179,24 @@ pub enum MoneyError {
         /// Fractional digits present in the supplied amount.
         actual_scale: u32,
     },
+    /// Occurs when an amount encoded as integer units at a scale does not
+    /// fit in 64 bits.
+    #[error("{amount} does not fit in 64-bit integer units at scale {scale}")]
+    UnitsOverflow {
+        /// The amount being encoded.
+        amount: Decimal,
+        /// The scale of the units.
+        scale: u32,
+    },
+    /// Occurs when a quantity is not a whole number of an instrument's
+    /// minimum increment.
+    #[error("quantity {quantity} is not a multiple of the minimum increment {increment}")]
+    InvalidQuantityIncrement {
+        /// The quantity, in contracts.
+        quantity: Decimal,
+        /// The minimum increment, in contracts.
+        increment: Decimal,
+    },
     /// Occurs when a localized amount has invalid separators or characters.
     #[cfg(feature = "money-formatting")]
     #[error("invalid localized amount format")]
@@ -125,6 +228,65 @@ pub enum MoneyError {
         /// Maximum display fractional digits supported by this build.
         max_fraction_digits: u32,
     },
+    /// Occurs when a custom locale cannot be registered.
+    #[cfg(feature = "money-formatting")]
+    #[error("invalid locale format: {reason}")]
+    InvalidLocaleFormat {
+        /// Why the locale was rejected.
+        reason: &'static str,
+    },
+    /// Occurs when a free-form money string carries text around the number
+    /// that is neither a known currency code nor a known symbol.
+    #[cfg(feature = "money-formatting")]
+    #[error("unknown currency marker '{token}'")]
+    UnknownCurrencyMarker {
+        /// The unrecognized text.
+        token: String,
+    },
+    /// Occurs when a currency symbol in a free-form money string belongs to
+    /// several currencies and no preference selects one.
+    #[cfg(feature = "money-formatting")]
+    #[error(
+        "currency symbol '{token}' is ambiguous between {}",
+        format_currencies(candidates)
+    )]
+    AmbiguousCurrency {
+        /// The symbol as written.
+        token: String,
+        /// Every currency using the symbol, ordered by code.
+        candidates: Vec<Currency>,
+    },
+    /// Occurs when a free-form money string has no currency marker and no
+    /// default currency is configured.
+    #[cfg(feature = "money-formatting")]
+    #[error("no currency symbol or code found")]
+    CurrencyNotDetected,
+    /// Occurs when the separators in a free-form money string admit two
+    /// readings and no locale decides between them.
+    #[cfg(feature = "money-formatting")]
+    #[error("separators in '{amount}' are ambiguous: could be {}", interpretations.join(" or "))]
+    AmbiguousSeparators {
+        /// The numeric part as written.
+        amount: String,
+        /// Canonical decimal readings, grouping reading first.
+        interpretations: Vec<String>,
+    },
+    /// Occurs when a [`crate::CompactFormat`] is configured inconsistently.
+    #[cfg(feature = "money-formatting")]
+    #[error("invalid compact format: {reason}")]
+    InvalidCompactFormat {
+        /// Why the configuration was rejected.
+        reason: &'static str,
+    },
+    /// Occurs when a [`crate::MoneyPattern`] cannot be compiled.
+    #[cfg(feature = "money-formatting")]
+    #[error("invalid format pattern at character {position}: {reason}")]
+    InvalidFormatPattern {
+        /// Zero-based character position of the problem.
+        position: usize,
+        /// Why the pattern was rejected.
+        reason: &'static str,
+    },
     /// Occurs when attempting to use an unsupported locale for formatting or parsing.
     #[cfg(feature = "money-formatting")]
     #[error("unsupported locale: {locale:?}")]
@@ -133,3 +295,11 @@ pub enum MoneyError {
         locale: Locale,
     },
 }
+
+fn format_currencies(currencies: &[Currency]) -> String {
+    currencies
+        .iter()
+        .map(Currency::code)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
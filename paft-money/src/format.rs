@@ -6,10 +6,12 @@ use crate::error::MoneyError;
 use crate::locale::{LocalFormat, Locale};
 
 /// Elements that can be positioned when rendering a formatted string.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum FormatItem {
     /// Negative sign for negative amounts (omitted when positive).
     Sign,
+    /// Explicit plus sign, always rendered.
+    Plus,
     /// Currency symbol (if available).
     Symbol,
     /// Formatted numeric amount.
@@ -18,6 +20,8 @@ pub enum FormatItem {
     Code,
     /// Literal space for flexible separation.
     Space,
+    /// Literal text copied verbatim.
+    Literal(String),
 }
 
 /// Parameters controlling how a money value is formatted.
@@ -25,8 +29,16 @@ pub enum FormatItem {
 pub struct Params<'a> {
     /// Ordered list describing how pieces should be rendered.
     pub positions: Vec<FormatItem>,
+    /// Layout used instead of `positions` for negative amounts.
+    pub negative_positions: Option<Vec<FormatItem>>,
     /// Desired number of fractional digits (pad with zeros when necessary).
     pub rounding_digits: Option<u32>,
+    /// Trailing fractional zeros are trimmed down to this many digits.
+    pub min_fraction_digits: Option<u32>,
+    /// Integer digits padded with leading zeros; `0` renders `0.5` as `.5`.
+    pub min_integer_digits: usize,
+    /// Grouping pattern used instead of the locale's; empty disables grouping.
+    pub grouping: Option<Vec<NonZeroUsize>>,
     /// Optional currency symbol to display.
     pub symbol: Option<Cow<'a, str>>,
     /// Optional currency code to display.
@@ -37,7 +49,11 @@ impl Params<'_> {
     pub const fn new(positions: Vec<FormatItem>) -> Self {
         Self {
             positions,
+            negative_positions: None,
             rounding_digits: None,
+            min_fraction_digits: None,
+            min_integer_digits: 1,
+            grouping: None,
             symbol: None,
             code: None,
         }
@@ -110,8 +126,26 @@ impl<'a> Formatter<'a> {
                 fraction.clear();
             }
         }
+        if let Some(min) = self.params.min_fraction_digits {
+            let min = min as usize;
+            while fraction.len() > min && fraction.ends_with('0') {
+                fraction.pop();
+            }
+        }
 
-        let grouped = apply_grouping(&integer, &self.format.grouping, self.format.group_separator);
+        if integer == "0" && self.params.min_integer_digits == 0 && !fraction.is_empty() {
+            integer.clear();
+        }
+        while integer.len() < self.params.min_integer_digits {
+            integer.insert(0, '0');
+        }
+
+        let grouping = self
+            .params
+            .grouping
+            .as_deref()
+            .unwrap_or(&self.format.grouping);
+        let grouped = apply_grouping(&integer, grouping, self.format.group_separator);
         let mut amount = grouped;
         if !fraction.is_empty() {
             amount.push(self.format.decimal_separator);
@@ -121,14 +155,20 @@ impl<'a> Formatter<'a> {
         let symbol = self.params.symbol.as_ref().map_or("", Cow::as_ref);
         let code = self.params.code.as_ref().map_or("", Cow::as_ref);
 
+        let positions = match &self.params.negative_positions {
+            Some(positions) if negative => positions,
+            _ => &self.params.positions,
+        };
+
         let mut out = String::new();
-        for item in &self.params.positions {
+        for item in positions {
             match item {
                 FormatItem::Sign => {
                     if negative {
                         out.push('-');
                     }
                 }
+                FormatItem::Plus => out.push('+'),
                 FormatItem::Symbol => {
                     if !symbol.is_empty() {
                         out.push_str(symbol);
@@ -141,6 +181,7 @@ impl<'a> Formatter<'a> {
                     }
                 }
                 FormatItem::Space => out.push(' '),
+                FormatItem::Literal(text) => out.push_str(text),
             }
         }
         Ok(out)
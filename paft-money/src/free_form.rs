@@ -0,0 +1,342 @@
+//! Parsing of scraped money strings whose currency and locale are unknown.
+
+use iso_currency::Currency as IsoCurrency;
+
+use crate::currency::Currency;
+use crate::currency_utils::{CurrencyRegistry, currency_metadata};
+use crate::decimal;
+use crate::error::MoneyError;
+use crate::locale::Locale;
+use crate::money::Money;
+
+/// Characters that only ever group digits.
+const GROUP_ONLY: [char; 5] = [' ', '\u{a0}', '\u{202f}', '\'', '\u{2019}'];
+
+/// Parser for free-form money strings such as `$1,234.56`, `1.234,56 €`,
+/// `USD 12`, `(45.00)`, or `CHF 1'000`.
+///
+/// The currency comes from a code or symbol before or after the number.
+/// Codes are recognized when ISO 4217 or the currency metadata registry knows
+/// them. Symbols are looked up in the metadata registry, so registering
+/// metadata teaches the parser new symbols. Parentheses around the amount
+/// (with or without its currency) and a `-` (or U+2212) on either side mark
+/// negative amounts.
+///
+/// Separators are inferred from the number itself:
+/// - Two distinct separators mean the last one is the decimal separator.
+/// - Spaces and apostrophes only ever group digits.
+/// - A separator that repeats groups digits.
+/// - A single `,` or `.` followed by a number of digits other than three is
+///   the decimal separator.
+///
+/// A single `,` or `.` followed by exactly three digits is the one ambiguous
+/// shape. It groups digits when three fraction digits would exceed the
+/// currency's scale. Otherwise the configured locale's separators decide.
+/// Without a locale, parsing fails with [`MoneyError::AmbiguousSeparators`].
+///
+/// ```rust
+/// # use paft_money::{Currency, IsoCurrency, Money, MoneyParser};
+/// let eur = Money::parse_free_form("1.234,56 €").unwrap();
+/// assert_eq!(eur.format(), "1234.56 EUR");
+///
+/// let parser = MoneyParser::new().with_default_currency(Currency::Iso(IsoCurrency::USD));
+/// assert_eq!(parser.parse("(45.00)").unwrap().format(), "-45 USD");
+/// assert_eq!(parser.parse("CHF 1'000").unwrap().format(), "1000 CHF");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MoneyParser {
+    default_currency: Option<Currency>,
+    preferred: Vec<Currency>,
+    locale: Option<Locale>,
+}
+
+impl MoneyParser {
+    /// A parser with no default currency, preferences, or locale.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            default_currency: None,
+            preferred: Vec::new(),
+            locale: None,
+        }
+    }
+
+    /// Use `currency` for inputs that carry no symbol or code.
+    #[must_use]
+    pub fn with_default_currency(mut self, currency: Currency) -> Self {
+        self.default_currency = Some(currency);
+        self
+    }
+
+    /// Resolve symbols shared by several currencies to `currency` when it is
+    /// one of them. Earlier preferences win.
+    #[must_use]
+    pub fn prefer(mut self, currency: Currency) -> Self {
+        self.preferred.push(currency);
+        self
+    }
+
+    /// Break separator ties with the separators of `locale`.
+    #[must_use]
+    pub const fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = Some(locale);
+        self
+    }
+
+    /// Parse `input` into [`Money`].
+    ///
+    /// # Errors
+    /// - [`MoneyError::UnknownCurrencyMarker`] when the text around the
+    ///   number is neither a known code nor a known symbol.
+    /// - [`MoneyError::AmbiguousCurrency`] when a symbol belongs to several
+    ///   currencies and none of them is preferred.
+    /// - [`MoneyError::CurrencyNotDetected`] when the input has no currency
+    ///   marker and no default currency is configured.
+    /// - [`MoneyError::MismatchedCurrencyAffix`] when the markers before and
+    ///   after the number name different currencies.
+    /// - [`MoneyError::AmbiguousSeparators`] when the separators admit two
+    ///   readings and no locale breaks the tie.
+    /// - [`MoneyError::InvalidGrouping`] when digit groups have
+    ///   inconsistent sizes.
+    /// - [`MoneyError::ScaleTooLarge`] when the amount has more fraction
+    ///   digits than the currency allows.
+    /// - [`MoneyError::InvalidAmountFormat`] for any other malformed input.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", err))]
+    pub fn parse(&self, input: &str) -> Result<Money, MoneyError> {
+        let rest = input.trim();
+        let first_digit = rest
+            .find(|c: char| c.is_ascii_digit())
+            .ok_or(MoneyError::InvalidAmountFormat)?;
+        let last_digit = rest
+            .rfind(|c: char| c.is_ascii_digit())
+            .ok_or(MoneyError::InvalidAmountFormat)?;
+        let number = &rest[first_digit..=last_digit];
+        let prefix = Affix::parse(&rest[..first_digit]);
+        let suffix = Affix::parse(&rest[last_digit + 1..]);
+
+        let parenthesized = match (prefix.open, prefix.close, suffix.open, suffix.close) {
+            (0, 0, 0, 0) => false,
+            (1, 0, 0, 1) => true,
+            _ => return Err(MoneyError::InvalidAmountFormat),
+        };
+        let minus = prefix.minus + suffix.minus;
+        let signs = minus + prefix.plus + suffix.plus;
+        if signs > 1 || (parenthesized && signs > 0) {
+            return Err(MoneyError::InvalidAmountFormat);
+        }
+        let negative = parenthesized || minus > 0;
+
+        let mut currency = None;
+        for token in [prefix.token, suffix.token] {
+            if token.is_empty() {
+                continue;
+            }
+            let detected = self.resolve_marker(&token)?;
+            match &currency {
+                Some(existing) if *existing != detected => {
+                    return Err(MoneyError::MismatchedCurrencyAffix);
+                }
+                _ => currency = Some(detected),
+            }
+        }
+        let currency = currency
+            .or_else(|| self.default_currency.clone())
+            .ok_or(MoneyError::CurrencyNotDetected)?;
+
+        let scale = currency.decimal_places().ok();
+        let (integer, fraction) = self.split_number(number, scale)?;
+        if let Some(exponent) = scale
+            && fraction.len() > usize::from(exponent)
+        {
+            return Err(MoneyError::ScaleTooLarge {
+                digits: fraction.len(),
+                exponent,
+            });
+        }
+
+        let mut canonical = integer;
+        if !fraction.is_empty() {
+            canonical.push('.');
+            canonical.push_str(&fraction);
+        }
+        if negative && canonical.chars().any(|c| c.is_ascii_digit() && c != '0') {
+            canonical.insert(0, '-');
+        }
+        let amount = decimal::parse_decimal(&canonical).ok_or(MoneyError::InvalidAmountFormat)?;
+        Money::new_exact(amount, currency)
+    }
+
+    fn resolve_marker(&self, token: &str) -> Result<Currency, MoneyError> {
+        let is_code = IsoCurrency::from_code(&token.to_ascii_uppercase()).is_some()
+            || currency_metadata(token).is_some();
+        if is_code && let Ok(currency) = Currency::try_from_str(token) {
+            return Ok(currency);
+        }
+
+        let candidates = CurrencyRegistry::global().currencies_with_symbol(token);
+        match candidates.as_slice() {
+            [] => Err(MoneyError::UnknownCurrencyMarker {
+                token: token.to_string(),
+            }),
+            [only] => Ok(only.clone()),
+            _ => self
+                .preferred
+                .iter()
+                .find(|preferred| candidates.contains(preferred))
+                .cloned()
+                .ok_or_else(|| MoneyError::AmbiguousCurrency {
+                    token: token.to_string(),
+                    candidates,
+                }),
+        }
+    }
+
+    /// Splits `number` into integer and fraction digits, inferring which
+    /// separator is decimal.
+    fn split_number(
+        &self,
+        number: &str,
+        scale: Option<u8>,
+    ) -> Result<(String, String), MoneyError> {
+        let mut separators: Vec<char> = Vec::new();
+        for c in number.chars().filter(|c| !c.is_ascii_digit()) {
+            if !(c == ',' || c == '.' || GROUP_ONLY.contains(&c)) {
+                return Err(MoneyError::InvalidAmountFormat);
+            }
+            if !separators.contains(&c) {
+                separators.push(c);
+            }
+        }
+
+        let decimal_separator = match separators.as_slice() {
+            [] => None,
+            [group, decimal] => {
+                if GROUP_ONLY.contains(decimal) || number.matches(*decimal).count() > 1 {
+                    return Err(MoneyError::InvalidAmountFormat);
+                }
+                if number.rfind(*group) > number.find(*decimal) {
+                    return Err(MoneyError::InvalidAmountFormat);
+                }
+                Some(*decimal)
+            }
+            [only] => self.single_separator_role(number, *only, scale)?,
+            _ => return Err(MoneyError::InvalidAmountFormat),
+        };
+
+        let (integer, fraction) = match decimal_separator {
+            Some(separator) => number
+                .split_once(separator)
+                .ok_or(MoneyError::InvalidAmountFormat)?,
+            None => (number, ""),
+        };
+        let groups: Vec<&str> = integer.split(|c: char| !c.is_ascii_digit()).collect();
+        if !valid_grouping(&groups) {
+            return Err(MoneyError::InvalidGrouping);
+        }
+        Ok((groups.concat(), fraction.to_string()))
+    }
+
+    /// Decides whether the only separator in `number` is the decimal
+    /// separator, returning it if so.
+    fn single_separator_role(
+        &self,
+        number: &str,
+        separator: char,
+        scale: Option<u8>,
+    ) -> Result<Option<char>, MoneyError> {
+        if GROUP_ONLY.contains(&separator) || number.matches(separator).count() > 1 {
+            return Ok(None);
+        }
+        let (integer, fraction) = number
+            .split_once(separator)
+            .ok_or(MoneyError::InvalidAmountFormat)?;
+        let could_group = fraction.len() == 3 && valid_grouping(&[integer, fraction]);
+        if !could_group || integer.chars().all(|c| c == '0') {
+            return Ok(Some(separator));
+        }
+        if scale.is_some_and(|scale| scale < 3) {
+            return Ok(None);
+        }
+        if let Some(locale) = self.locale {
+            let spec = locale.spec();
+            if separator == spec.decimal_separator {
+                return Ok(Some(separator));
+            }
+            if separator == spec.group_separator {
+                return Ok(None);
+            }
+        }
+        Err(MoneyError::AmbiguousSeparators {
+            amount: number.to_string(),
+            interpretations: vec![
+                format!("{integer}{fraction}"),
+                format!("{integer}.{fraction}"),
+            ],
+        })
+    }
+}
+
+impl Money {
+    /// Parse a free-form money string, detecting its currency and separators.
+    ///
+    /// Shorthand for [`MoneyParser::parse`] with default settings.
+    ///
+    /// # Errors
+    /// See [`MoneyParser::parse`].
+    pub fn parse_free_form(input: &str) -> Result<Self, MoneyError> {
+        MoneyParser::new().parse(input)
+    }
+}
+
+/// Text before or after the number, with signs and parentheses counted and
+/// removed from the currency token.
+struct Affix {
+    token: String,
+    minus: usize,
+    plus: usize,
+    open: usize,
+    close: usize,
+}
+
+impl Affix {
+    fn parse(affix: &str) -> Self {
+        let mut parsed = Self {
+            token: String::new(),
+            minus: 0,
+            plus: 0,
+            open: 0,
+            close: 0,
+        };
+        for c in affix.chars() {
+            match c {
+                '-' | '\u{2212}' => parsed.minus += 1,
+                '+' => parsed.plus += 1,
+                '(' => parsed.open += 1,
+                ')' => parsed.close += 1,
+                _ => parsed.token.push(c),
+            }
+        }
+        parsed.token = parsed.token.trim().to_string();
+        parsed
+    }
+}
+
+/// Whether digit groups follow the Western (`1,234,567`) or Indian
+/// (`12,34,567`) pattern. A single group is always valid.
+fn valid_grouping(groups: &[&str]) -> bool {
+    let Some((first, rest)) = groups.split_first() else {
+        return false;
+    };
+    if first.is_empty() || rest.iter().any(|group| group.is_empty()) {
+        return false;
+    }
+    let Some((last, middle)) = rest.split_last() else {
+        return true;
+    };
+    if last.len() != 3 {
+        return false;
+    }
+    let western = first.len() <= 3 && middle.iter().all(|group| group.len() == 3);
+    let indian = first.len() <= 2 && middle.iter().all(|group| group.len() == 2);
+    western || indian
+}
@@ -0,0 +1,382 @@
+//! Historic currencies and their redenomination into successor currencies.
+//!
+//! Withdrawn currencies such as `DEM` or `VEF` keep their metadata (name,
+//! scale, symbol) in the regular registry. A [`HistoricCurrency`] record adds
+//! when the currency was in use, which currency replaced it, and the fixed
+//! factor between the two, so old balances can be restated with
+//! [`Money::redenominate`].
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use chrono::NaiveDate;
+
+use crate::currency::Currency;
+use crate::currency_utils::{CurrencyRegistry, read_recovering, write_recovering};
+use crate::decimal::{self, Decimal};
+use crate::error::MoneyError;
+use crate::exact::{checked_div_decimal, checked_mul_decimal};
+use crate::money::Money;
+
+/// Validity range and successor of a withdrawn or redenominated currency.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistoricCurrency {
+    /// First day the currency was in use, when known.
+    pub valid_from: Option<NaiveDate>,
+    /// Last day the currency was legal tender or the unit of account.
+    pub valid_until: NaiveDate,
+    /// Currency that replaced it.
+    pub successor: Currency,
+    /// Units of this currency per unit of the successor, such as `1.95583`
+    /// DEM per EUR.
+    pub conversion_factor: Decimal,
+    /// First day amounts convert into the successor at `conversion_factor`.
+    pub conversion_date: NaiveDate,
+}
+
+impl HistoricCurrency {
+    /// Whether the currency was in use on `date`.
+    #[must_use]
+    pub fn is_valid_on(&self, date: NaiveDate) -> bool {
+        self.valid_from.is_none_or(|from| from <= date) && date <= self.valid_until
+    }
+
+    /// Whether the fixed conversion into the successor applies on `date`.
+    #[must_use]
+    pub fn converts_on(&self, date: NaiveDate) -> bool {
+        self.conversion_date <= date
+    }
+}
+
+/// Code, first day in use (when known), last day in use, successor, units per
+/// successor unit, and conversion date.
+type HistoricRow = (
+    &'static str,
+    Option<NaiveDate>,
+    NaiveDate,
+    &'static str,
+    &'static str,
+    NaiveDate,
+);
+
+const fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).expect("built-in historic dates are valid")
+}
+
+const BUILTIN_HISTORIC_CURRENCIES: &[HistoricRow] = &[
+    (
+        "ATS",
+        None,
+        ymd(2002, 2, 28),
+        "EUR",
+        "13.7603",
+        ymd(1999, 1, 1),
+    ),
+    (
+        "BEF",
+        None,
+        ymd(2002, 2, 28),
+        "EUR",
+        "40.3399",
+        ymd(1999, 1, 1),
+    ),
+    (
+        "DEM",
+        Some(ymd(1948, 6, 21)),
+        ymd(2001, 12, 31),
+        "EUR",
+        "1.95583",
+        ymd(1999, 1, 1),
+    ),
+    (
+        "ESP",
+        None,
+        ymd(2002, 2, 28),
+        "EUR",
+        "166.386",
+        ymd(1999, 1, 1),
+    ),
+    (
+        "FIM",
+        None,
+        ymd(2002, 2, 28),
+        "EUR",
+        "5.94573",
+        ymd(1999, 1, 1),
+    ),
+    (
+        "FRF",
+        None,
+        ymd(2002, 2, 17),
+        "EUR",
+        "6.55957",
+        ymd(1999, 1, 1),
+    ),
+    (
+        "GRD",
+        None,
+        ymd(2002, 2, 28),
+        "EUR",
+        "340.750",
+        ymd(2001, 1, 1),
+    ),
+    (
+        "IEP",
+        None,
+        ymd(2002, 2, 9),
+        "EUR",
+        "0.787564",
+        ymd(1999, 1, 1),
+    ),
+    (
+        "ITL",
+        None,
+        ymd(2002, 2, 28),
+        "EUR",
+        "1936.27",
+        ymd(1999, 1, 1),
+    ),
+    (
+        "LUF",
+        None,
+        ymd(2002, 2, 28),
+        "EUR",
+        "40.3399",
+        ymd(1999, 1, 1),
+    ),
+    (
+        "NLG",
+        None,
+        ymd(2002, 1, 28),
+        "EUR",
+        "2.20371",
+        ymd(1999, 1, 1),
+    ),
+    (
+        "PTE",
+        None,
+        ymd(2002, 2, 28),
+        "EUR",
+        "200.482",
+        ymd(1999, 1, 1),
+    ),
+    (
+        "ROL",
+        None,
+        ymd(2005, 6, 30),
+        "RON",
+        "10000",
+        ymd(2005, 7, 1),
+    ),
+    (
+        "RUR",
+        None,
+        ymd(1997, 12, 31),
+        "RUB",
+        "1000",
+        ymd(1998, 1, 1),
+    ),
+    (
+        "TRL",
+        None,
+        ymd(2004, 12, 31),
+        "TRY",
+        "1000000",
+        ymd(2005, 1, 1),
+    ),
+    (
+        "VEB",
+        None,
+        ymd(2007, 12, 31),
+        "VEF",
+        "1000",
+        ymd(2008, 1, 1),
+    ),
+    (
+        "VEF",
+        Some(ymd(2008, 1, 1)),
+        ymd(2018, 8, 19),
+        "VES",
+        "100000",
+        ymd(2018, 8, 20),
+    ),
+    (
+        "VES",
+        Some(ymd(2018, 8, 20)),
+        ymd(2021, 9, 30),
+        "VED",
+        "1000000",
+        ymd(2021, 10, 1),
+    ),
+];
+
+fn build_builtin_historic() -> HashMap<String, HistoricCurrency> {
+    BUILTIN_HISTORIC_CURRENCIES
+        .iter()
+        .map(|(code, from, until, successor, factor, conversion)| {
+            let historic = HistoricCurrency {
+                valid_from: *from,
+                valid_until: *until,
+                successor: Currency::try_from_str(successor)
+                    .expect("built-in successor codes are valid"),
+                conversion_factor: decimal::parse_decimal(factor)
+                    .expect("built-in conversion factors are valid decimals"),
+                conversion_date: *conversion,
+            };
+            ((*code).to_string(), historic)
+        })
+        .collect()
+}
+
+static BUILTIN_HISTORIC: LazyLock<HashMap<String, HistoricCurrency>> =
+    LazyLock::new(build_builtin_historic);
+
+impl CurrencyRegistry {
+    /// Historic record of `currency` registered in this registry, falling
+    /// back to the built-in records.
+    #[must_use]
+    pub fn historic(&self, currency: &Currency) -> Option<HistoricCurrency> {
+        let custom = read_recovering(&self.historic)
+            .get(currency.code())
+            .cloned();
+        custom.or_else(|| BUILTIN_HISTORIC.get(currency.code()).cloned())
+    }
+
+    /// Registers (or replaces) the historic record of `currency`, returning
+    /// the previously registered one.
+    ///
+    /// # Errors
+    /// - [`MoneyError::InvalidExchangeRate`] when the conversion factor is
+    ///   not positive.
+    /// - [`MoneyError::MetadataNotFound`] when the scale of `currency` or of
+    ///   its successor is unknown to this registry.
+    /// - [`MoneyError::SuccessorCycle`] when the successor chain would lead
+    ///   back to `currency`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(self, historic), err)
+    )]
+    pub fn set_historic(
+        &self,
+        currency: &Currency,
+        historic: HistoricCurrency,
+    ) -> Result<Option<HistoricCurrency>, MoneyError> {
+        if historic.conversion_factor <= decimal::zero() {
+            return Err(MoneyError::InvalidExchangeRate {
+                rate: historic.conversion_factor,
+            });
+        }
+        self.decimal_places(currency)?;
+        self.decimal_places(&historic.successor)?;
+
+        let mut custom = write_recovering(&self.historic);
+        let mut next = Some(historic.successor.clone());
+        while let Some(successor) = next {
+            if successor == *currency {
+                return Err(MoneyError::SuccessorCycle {
+                    currency: currency.clone(),
+                });
+            }
+            next = custom
+                .get(successor.code())
+                .or_else(|| BUILTIN_HISTORIC.get(successor.code()))
+                .map(|record| record.successor.clone());
+        }
+        let previous = custom.insert(currency.code().to_string(), historic);
+        drop(custom);
+        Ok(previous)
+    }
+
+    /// Removes a historic record registered in this registry, returning it.
+    /// Built-in records stay in effect.
+    pub fn clear_historic(&self, currency: &Currency) -> Option<HistoricCurrency> {
+        write_recovering(&self.historic).remove(currency.code())
+    }
+
+    /// Whether `currency` was in use on `date`; see [`Currency::is_valid_on`].
+    #[must_use]
+    pub fn is_valid_on(&self, currency: &Currency, date: NaiveDate) -> bool {
+        self.historic(currency)
+            .is_none_or(|historic| historic.is_valid_on(date))
+    }
+}
+
+/// Registers the historic record of `currency` in the global registry; see
+/// [`CurrencyRegistry::set_historic`].
+///
+/// # Errors
+/// See [`CurrencyRegistry::set_historic`].
+pub fn set_historic_currency(
+    currency: &Currency,
+    historic: HistoricCurrency,
+) -> Result<Option<HistoricCurrency>, MoneyError> {
+    CurrencyRegistry::global().set_historic(currency, historic)
+}
+
+impl Money {
+    /// Restate this amount in the currency that replaced its currency as of
+    /// `on`, using the fixed conversion factors of the global registry.
+    ///
+    /// Successive redenominations in effect on `on` are applied together
+    /// (for example `VEB` to `VEF` to `VES`), and the result is rounded once
+    /// to the final currency's scale with
+    /// `RoundingStrategy::MidpointAwayFromZero`, as the euro conversion rules
+    /// require.
+    ///
+    /// ```rust
+    /// # use chrono::NaiveDate;
+    /// # use paft_money::{Currency, Money};
+    /// let dem = Currency::try_from_str("DEM").unwrap();
+    /// let balance = Money::from_canonical_str("1000", dem).unwrap();
+    /// let restated = balance
+    ///     .redenominate(NaiveDate::from_ymd_opt(2001, 6, 30).unwrap())
+    ///     .unwrap();
+    /// assert_eq!(restated.format(), "511.29 EUR");
+    /// ```
+    ///
+    /// # Errors
+    /// - [`MoneyError::NoSuccessorCurrency`] when the currency has no
+    ///   successor, or its conversion date is after `on`.
+    /// - [`MoneyError::SuccessorCycle`] when the successor chain loops.
+    /// - [`MoneyError::MetadataNotFound`] when the final currency has no
+    ///   known scale.
+    /// - [`MoneyError::ConversionError`] when the conversion overflows the
+    ///   decimal backend.
+    pub fn redenominate(&self, on: NaiveDate) -> Result<Self, MoneyError> {
+        self.redenominate_in(on, CurrencyRegistry::global())
+    }
+
+    /// Like [`Money::redenominate`], reading historic records and scales
+    /// from `registry`.
+    ///
+    /// # Errors
+    /// See [`Money::redenominate`].
+    pub fn redenominate_in(
+        &self,
+        on: NaiveDate,
+        registry: &CurrencyRegistry,
+    ) -> Result<Self, MoneyError> {
+        let mut chain = vec![self.currency().clone()];
+        let mut factor = decimal::one();
+        while let Some(historic) = registry
+            .historic(&chain[chain.len() - 1])
+            .filter(|historic| historic.converts_on(on))
+        {
+            if chain.contains(&historic.successor) {
+                return Err(MoneyError::SuccessorCycle {
+                    currency: historic.successor,
+                });
+            }
+            factor = checked_mul_decimal(&factor, &historic.conversion_factor)?;
+            chain.push(historic.successor);
+        }
+        let Some(successor) = chain.pop().filter(|_| !chain.is_empty()) else {
+            return Err(MoneyError::NoSuccessorCurrency {
+                currency: self.currency().clone(),
+                date: on,
+            });
+        };
+        let amount = checked_div_decimal(&self.amount(), &factor)?;
+        Self::new_in(amount, successor, registry)
+    }
+}
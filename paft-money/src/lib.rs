@@ -107,6 +107,11 @@
 //! # run().unwrap();
 //! ```
 //!
+//! When the unit matters, a [`Quantity`] tags the amount with a
+//! [`QuantityUnit`] (shares, contracts, lots, or base units), and a
+//! [`ContractSpec`] supplies the multiplier and lot size that
+//! [`Price::try_notional`] applies.
+//!
 //! # Quickstart
 //!
 //! Create money in ISO currencies, add and subtract safely, serialize with
@@ -121,6 +126,11 @@
 //! let total = price.try_add(&tax)?;
 //! assert_eq!(total.format(), "13.57 USD");
 //!
+//! // Splitting never loses a cent: the parts sum back to the total.
+//! let shares = total.split(2)?;
+//! assert_eq!(shares[0].format(), "6.79 USD");
+//! assert_eq!(shares[1].format(), "6.78 USD");
+//!
 //! // Cross-currency addition is rejected
 //! let eur = Money::from_canonical_str("5", Currency::Iso(IsoCurrency::EUR))?;
 //! assert!(price.try_add(&eur).is_err());
@@ -147,6 +157,12 @@
 //! # Ok(()) } run().unwrap();
 //! ```
 //!
+//! A [`RateBook`] holds many rates and resolves pairs that are not quoted
+//! directly, through inverses, a pivot currency, or the shortest chain of
+//! held rates. A [`RateHistory`] stores rates over time and converts at the
+//! rate valid on a given date. A [`MoneyBag`] sums amounts in several
+//! currencies and collapses them into one through either source.
+//!
 //! # Serde
 //!
 //! Amounts serialize as strings (to avoid exponent notation), currencies
@@ -193,10 +209,27 @@
 //! exist. Serialized `Money` values carry that retained scale as `minor_units`;
 //! conflicting current metadata is rejected at deserialization instead of
 //! silently changing the value's identity.
+//! [`load_currency_metadata`] registers a whole batch atomically (every entry
+//! is validated before any is applied), and [`export_currency_metadata`]
+//! dumps the effective registry. [`CurrencyMetadataFile`] reads and writes
+//! such batches as JSON or TOML under the `metadata-json` and `metadata-toml`
+//! features.
+//! These functions all operate on the process-wide registry. A
+//! [`CurrencyRegistry`] instance holds its own custom entries (over the shared
+//! ISO and built-in data) so tenants or parallel tests can register
+//! conflicting codes; pass it to [`Money::new_in`] and the other `*_in`
+//! constructors, or to `LocalizedMoney::with_registry` when formatting.
 //! For modeled non-ISO currencies such as `BTC`, `ETH`, and `XMR`, metadata is
 //! also the source of truth for `Currency::full_name()`. ISO currency names are
 //! resolved from ISO 4217 even if metadata is registered for formatting.
 //!
+//! Withdrawn currencies such as `DEM`, `FRF`, `RUR`, and `VEF` carry built-in
+//! metadata plus a [`HistoricCurrency`] record: the dates they were in use,
+//! their successor, and the fixed conversion factor (1.95583 `DEM` per `EUR`).
+//! [`Money::redenominate`] restates a historic balance in the currency in
+//! effect on a given date, and [`set_historic_currency`] registers further
+//! redenominations.
+//!
 //! # Feature flags
 //!
 //! - `bigdecimal`: switch to arbitrary precision decimals (slower, allocates for large values).
@@ -204,6 +237,8 @@
 //! - `panicking-money-ops`: implements `Add`/`Sub`/`Mul`/`Div` for `Money` that
 //!   assert on invalid operations. Prefer the `try_*` methods for fallible APIs.
 //! - `money-formatting`: opt-in locale-aware formatting and strict parsing for [`Money`].
+//! - `metadata-json` / `metadata-toml`: parse and render [`CurrencyMetadataFile`]
+//!   documents as JSON / TOML and load them with `load_currency_metadata_file`.
 //!
 //! When `money-formatting` is enabled you opt into localized rendering explicitly:
 //! ```rust
@@ -226,45 +261,88 @@
 //! # }
 //! ```
 //!
+//! Besides the built-in [`Locale`]s, [`register_locale`] adds custom
+//! [`LocalFormat`]s at runtime for use by the same formatting and parsing APIs.
+//! [`MoneyPattern`] compiles CLDR-style patterns such as
+//! `¤#,##0.00;(¤#,##0.00)` for [`LocalizedMoney::with_pattern`], and
+//! [`CompactFormat`] renders large amounts as `$2.9T` or `$2.91 trillion`.
+//!
 //! Regardless of backend, serde and the high-level API remain stable; see
 //! [`MAX_DECIMAL_PRECISION`] and [`MAX_MINOR_UNIT_DECIMALS`] for limits that
 //! affect scaling and minor-unit conversions.
+//! Fields that must travel as integers can opt into the adapters in
+//! [`serde`](mod@serde), such as `#[serde(with = "paft_money::serde::minor_units")]`.
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![forbid(unsafe_code)]
 #![warn(missing_docs)]
 
+mod allocation;
 mod amount;
+#[cfg(feature = "money-formatting")]
+mod compact;
+mod contract;
+mod day_count;
 pub(crate) mod decimal;
 mod exact;
 #[cfg(feature = "money-formatting")]
 mod format;
+#[cfg(feature = "money-formatting")]
+mod free_form;
 mod locale;
+mod metadata_file;
 #[cfg(feature = "money-formatting")]
 mod parser;
+#[cfg(feature = "money-formatting")]
+mod pattern;
 
 pub mod currency;
 pub mod currency_utils;
 /// Error types shared across the money crate.
 pub mod error;
+mod historic;
 pub mod money;
+mod money_bag;
 mod price;
 mod quantity;
+mod rate_book;
+mod rate_history;
+mod rounding;
+pub mod serde;
 
+pub use allocation::AllocationStrategy;
 pub use amount::MonetaryAmount;
+#[cfg(feature = "money-formatting")]
+pub use compact::{CompactFormat, CompactStyle, CompactSuffix};
+pub use contract::{ContractSpec, Quantity, QuantityUnit};
 pub use currency::{Currency, OtherCurrency};
 pub use currency_utils::{
-    CurrencyMetadata, MAX_DECIMAL_PRECISION, MAX_MINOR_UNIT_DECIMALS, MinorUnitError,
-    clear_currency_metadata, currency_metadata, override_currency_metadata, set_currency_metadata,
-    try_normalize_currency_code,
+    CurrencyMetadata, CurrencyRegistry, MAX_DECIMAL_PRECISION, MAX_MINOR_UNIT_DECIMALS,
+    MetadataLoadError, MinorUnitError, clear_currency_metadata, currency_metadata,
+    export_currency_metadata, load_currency_metadata, override_currency_metadata,
+    set_cash_increment, set_currency_metadata, try_normalize_currency_code,
 };
+pub use day_count::{Compounding, DayCount};
 pub use error::{MoneyError, MoneyParseError};
+#[cfg(feature = "money-formatting")]
+pub use free_form::MoneyParser;
+pub use historic::{HistoricCurrency, set_historic_currency};
 pub use locale::Locale;
 #[cfg(feature = "money-formatting")]
+pub use locale::{CustomLocale, LocalFormat, register_locale};
+#[cfg(any(feature = "metadata-json", feature = "metadata-toml"))]
+pub use metadata_file::load_currency_metadata_file;
+pub use metadata_file::{CurrencyMetadataEntry, CurrencyMetadataFile};
+#[cfg(feature = "money-formatting")]
 pub use money::LocalizedMoney;
 pub use money::{ExchangeRate, Money};
+pub use money_bag::MoneyBag;
+#[cfg(feature = "money-formatting")]
+pub use pattern::MoneyPattern;
 pub use price::{Price, PriceAmount};
 pub use quantity::QuantityAmount;
+pub use rate_book::{RateBook, RateLeg, RateResolution, RateSource, ResolvedRate};
+pub use rate_history::{AsOfMode, HistoricalRates, RateHistory, RateObservation};
 
 /// Re-export `iso_currency::Currency` for convenience.
 pub use iso_currency::Currency as IsoCurrency;
@@ -3,8 +3,19 @@
 //! Grouping patterns are applied from the rightmost digit moving left. For
 //! example, the Indian pattern `[3, 2, 2]` renders `12345678` as
 //! `1,23,45,678`.
+//!
+//! With the `money-formatting` feature, [`register_locale`] adds custom
+//! [`LocalFormat`]s at runtime. The returned [`Locale`] works anywhere a
+//! built-in one does, including [`crate::Money::format_with_locale`] and
+//! [`crate::Money::from_str_locale`].
+use std::borrow::Cow;
 #[cfg(feature = "money-formatting")]
 use std::num::NonZeroUsize;
+#[cfg(feature = "money-formatting")]
+use std::sync::{LazyLock, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+#[cfg(feature = "money-formatting")]
+use crate::error::MoneyError;
 
 /// Supported locales for money formatting/parsing.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -18,8 +29,84 @@ pub enum Locale {
     EnEu,
     /// English (Belarus): grouping 3-3-3, space thousands, `,` decimal.
     EnBy,
+    /// German (Switzerland): grouping 3-3-3, `'` thousands, `.` decimal.
+    DeCh,
+    /// French (France): grouping 3-3-3, narrow no-break space (U+202F)
+    /// thousands, `,` decimal.
+    FrFr,
+    /// Japanese (Japan): grouping 3-3-3, `,` thousands, `.` decimal.
+    JaJp,
+    /// Portuguese (Brazil): grouping 3-3-3, `.` thousands, `,` decimal.
+    PtBr,
+    /// A locale added at runtime with [`register_locale`].
+    #[cfg(feature = "money-formatting")]
+    Custom(CustomLocale),
+}
+
+/// Built-in locales in declaration order.
+const BUILTIN_LOCALES: [Locale; 8] = [
+    Locale::EnUs,
+    Locale::EnIn,
+    Locale::EnEu,
+    Locale::EnBy,
+    Locale::DeCh,
+    Locale::FrFr,
+    Locale::JaJp,
+    Locale::PtBr,
+];
+
+impl Locale {
+    /// Locale tag, such as `"en-US"` or the tag a custom locale was
+    /// registered under.
+    #[must_use]
+    #[cfg_attr(
+        not(feature = "money-formatting"),
+        expect(
+            clippy::missing_const_for_fn,
+            reason = "custom locale tags are read from the registry under money-formatting"
+        )
+    )]
+    pub fn tag(self) -> Cow<'static, str> {
+        Cow::Borrowed(match self {
+            Self::EnUs => "en-US",
+            Self::EnIn => "en-IN",
+            Self::EnEu => "en-EU",
+            Self::EnBy => "en-BY",
+            Self::DeCh => "de-CH",
+            Self::FrFr => "fr-FR",
+            Self::JaJp => "ja-JP",
+            Self::PtBr => "pt-BR",
+            #[cfg(feature = "money-formatting")]
+            Self::Custom(custom) => return Cow::Owned(read_registry()[custom.0].0.clone()),
+        })
+    }
+
+    /// Look up a built-in or registered locale by tag, ignoring ASCII case.
+    #[must_use]
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        if let Some(locale) = BUILTIN_LOCALES
+            .into_iter()
+            .find(|locale| locale.tag().eq_ignore_ascii_case(tag))
+        {
+            return Some(locale);
+        }
+        #[cfg(feature = "money-formatting")]
+        {
+            read_registry()
+                .iter()
+                .position(|(registered, _)| registered.eq_ignore_ascii_case(tag))
+                .map(|index| Self::Custom(CustomLocale(index)))
+        }
+        #[cfg(not(feature = "money-formatting"))]
+        None
+    }
 }
 
+/// Opaque handle to a locale added with [`register_locale`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg(feature = "money-formatting")]
+pub struct CustomLocale(usize);
+
 /// Concrete formatting specification for a locale.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg(feature = "money-formatting")]
@@ -32,6 +119,58 @@ pub struct LocalFormat {
     pub grouping: Vec<NonZeroUsize>,
 }
 
+#[cfg(feature = "money-formatting")]
+impl LocalFormat {
+    /// Build a format from separators and a right-to-left grouping pattern,
+    /// whose last size repeats for the remaining digits.
+    ///
+    /// # Errors
+    /// Returns [`MoneyError::InvalidLocaleFormat`] when a grouping size is
+    /// zero or the separators are invalid (see [`LocalFormat::validate`]).
+    pub fn new(
+        group_separator: char,
+        decimal_separator: char,
+        grouping: &[usize],
+    ) -> Result<Self, MoneyError> {
+        let grouping = grouping
+            .iter()
+            .map(|size| NonZeroUsize::new(*size))
+            .collect::<Option<Vec<_>>>()
+            .ok_or(MoneyError::InvalidLocaleFormat {
+                reason: "grouping sizes must be non-zero",
+            })?;
+        let format = Self {
+            group_separator,
+            decimal_separator,
+            grouping,
+        };
+        format.validate()?;
+        Ok(format)
+    }
+
+    /// Check that the format can round-trip amounts through the parser.
+    ///
+    /// # Errors
+    /// Returns [`MoneyError::InvalidLocaleFormat`] when the grouping pattern
+    /// is empty, the two separators are equal, or either separator is a digit
+    /// or a sign.
+    pub fn validate(&self) -> Result<(), MoneyError> {
+        let invalid = |reason| Err(MoneyError::InvalidLocaleFormat { reason });
+        if self.grouping.is_empty() {
+            return invalid("grouping pattern must not be empty");
+        }
+        if self.group_separator == self.decimal_separator {
+            return invalid("group and decimal separators must differ");
+        }
+        for separator in [self.group_separator, self.decimal_separator] {
+            if separator.is_ascii_digit() || separator == '-' || separator == '+' {
+                return invalid("separators must not be digits or signs");
+            }
+        }
+        Ok(())
+    }
+}
+
 #[cfg(feature = "money-formatting")]
 const GROUP_SIZE_2: NonZeroUsize = grouping_size(2);
 #[cfg(feature = "money-formatting")]
@@ -40,28 +179,107 @@ const GROUP_SIZE_3: NonZeroUsize = grouping_size(3);
 #[cfg(feature = "money-formatting")]
 impl Locale {
     /// Maps a locale to its formatting implementation details.
-    pub(crate) fn spec(self) -> LocalFormat {
-        match self {
-            Self::EnUs => LocalFormat {
-                group_separator: ',',
-                decimal_separator: '.',
-                grouping: vec![GROUP_SIZE_3; 3],
-            },
-            Self::EnIn => LocalFormat {
-                group_separator: ',',
-                decimal_separator: '.',
-                grouping: vec![GROUP_SIZE_3, GROUP_SIZE_2, GROUP_SIZE_2],
-            },
-            Self::EnEu => LocalFormat {
-                group_separator: '.',
-                decimal_separator: ',',
-                grouping: vec![GROUP_SIZE_3; 3],
-            },
-            Self::EnBy => LocalFormat {
-                group_separator: ' ',
-                decimal_separator: ',',
-                grouping: vec![GROUP_SIZE_3; 3],
-            },
+    #[must_use]
+    pub fn spec(self) -> LocalFormat {
+        let (group_separator, decimal_separator) = match self {
+            Self::EnUs | Self::JaJp => (',', '.'),
+            Self::EnIn => {
+                return LocalFormat {
+                    group_separator: ',',
+                    decimal_separator: '.',
+                    grouping: vec![GROUP_SIZE_3, GROUP_SIZE_2, GROUP_SIZE_2],
+                };
+            }
+            Self::EnEu | Self::PtBr => ('.', ','),
+            Self::EnBy => (' ', ','),
+            Self::DeCh => ('\'', '.'),
+            Self::FrFr => ('\u{202F}', ','),
+            Self::Custom(custom) => return read_registry()[custom.0].1.clone(),
+        };
+        LocalFormat {
+            group_separator,
+            decimal_separator,
+            grouping: vec![GROUP_SIZE_3; 3],
+        }
+    }
+}
+
+/// Registers a custom locale under `tag`, returning its [`Locale`] handle.
+///
+/// Registering a tag again (ignoring ASCII case) replaces its format and
+/// returns the same handle, so values already holding it pick up the change.
+///
+/// ```rust
+/// # use paft_money::{Currency, IsoCurrency, LocalFormat, Money, register_locale};
+/// let de_de = register_locale("de-DE", LocalFormat::new('.', ',', &[3]).unwrap()).unwrap();
+/// let eur = Money::from_canonical_str("1234.5", Currency::Iso(IsoCurrency::EUR)).unwrap();
+/// assert_eq!(eur.format_with_locale(de_de).unwrap(), "€1.234,50");
+/// assert_eq!(
+///     Money::from_str_locale("€1.234,50", Currency::Iso(IsoCurrency::EUR), de_de).unwrap(),
+///     eur
+/// );
+/// ```
+///
+/// # Errors
+/// - [`MoneyError::InvalidLocaleFormat`] when `format` fails
+///   [`LocalFormat::validate`], `tag` is blank, or `tag` names a built-in
+///   locale.
+#[cfg(feature = "money-formatting")]
+pub fn register_locale(tag: impl Into<String>, format: LocalFormat) -> Result<Locale, MoneyError> {
+    let tag = tag.into().trim().to_string();
+    if tag.is_empty() {
+        return Err(MoneyError::InvalidLocaleFormat {
+            reason: "locale tag must not be empty",
+        });
+    }
+    if BUILTIN_LOCALES
+        .iter()
+        .any(|locale| locale.tag().eq_ignore_ascii_case(&tag))
+    {
+        return Err(MoneyError::InvalidLocaleFormat {
+            reason: "locale tag names a built-in locale",
+        });
+    }
+    format.validate()?;
+
+    let mut registry = write_registry();
+    if let Some(index) = registry
+        .iter()
+        .position(|(registered, _)| registered.eq_ignore_ascii_case(&tag))
+    {
+        registry[index].1 = format;
+        return Ok(Locale::Custom(CustomLocale(index)));
+    }
+    registry.push((tag, format));
+    Ok(Locale::Custom(CustomLocale(registry.len() - 1)))
+}
+
+/// Registered custom locales. Entries are never removed, so every
+/// [`CustomLocale`] index stays valid.
+#[cfg(feature = "money-formatting")]
+static LOCALE_REGISTRY: LazyLock<RwLock<Vec<(String, LocalFormat)>>> =
+    LazyLock::new(|| RwLock::new(Vec::new()));
+
+/// Acquires a read guard on the locale registry, recovering from a poisoned
+/// lock like the currency metadata registry does.
+#[cfg(feature = "money-formatting")]
+fn read_registry() -> RwLockReadGuard<'static, Vec<(String, LocalFormat)>> {
+    match LOCALE_REGISTRY.read() {
+        Ok(guard) => guard,
+        Err(poisoned) => {
+            LOCALE_REGISTRY.clear_poison();
+            poisoned.into_inner()
+        }
+    }
+}
+
+#[cfg(feature = "money-formatting")]
+fn write_registry() -> RwLockWriteGuard<'static, Vec<(String, LocalFormat)>> {
+    match LOCALE_REGISTRY.write() {
+        Ok(guard) => guard,
+        Err(poisoned) => {
+            LOCALE_REGISTRY.clear_poison();
+            poisoned.into_inner()
         }
     }
 }
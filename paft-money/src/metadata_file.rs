@@ -0,0 +1,240 @@
+//! Serializable currency metadata records for bulk loading and export.
+//!
+//! A metadata document lists currencies under a `currencies` key. In TOML:
+//!
+//! ```toml
+//! [[currencies]]
+//! code = "ACME"
+//! full_name = "Acme Reward Token"
+//! minor_units = 4
+//! symbol = "Ⓐ"
+//! symbol_first = true      # optional, defaults to true
+//! default_locale = "en-US" # optional, defaults to en-US
+//! cash_increment = 5       # optional, in minor units
+//! ```
+//!
+//! The `metadata-json` and `metadata-toml` features add parsers and writers
+//! for the two formats; without them the records still work with any serde
+//! format.
+
+#[cfg(any(feature = "metadata-json", feature = "metadata-toml"))]
+use std::path::Path;
+
+use std::num::NonZeroU32;
+
+use serde::{Deserialize, Serialize};
+
+use crate::currency_utils::{
+    CurrencyMetadata, CurrencyRegistry, MetadataLoadError, export_currency_metadata,
+    load_currency_metadata,
+};
+use crate::locale::Locale;
+
+/// One currency in a metadata document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CurrencyMetadataEntry {
+    /// Currency code, such as `"BTC"` or an internal token code.
+    pub code: String,
+    /// Human-readable name for the currency.
+    pub full_name: String,
+    /// Number of decimal places (minor units) for the currency.
+    pub minor_units: u8,
+    /// Symbol used when rendering the currency.
+    pub symbol: String,
+    /// Whether the symbol is rendered before (`true`) or after (`false`) the amount.
+    #[serde(default = "default_symbol_first")]
+    pub symbol_first: bool,
+    /// Default locale, written as its tag (for example `"en-US"`).
+    #[serde(default = "default_locale", with = "locale_tag")]
+    pub default_locale: Locale,
+    /// Cash rounding increment in minor units, if coarser than one minor unit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cash_increment: Option<NonZeroU32>,
+}
+
+impl CurrencyMetadataEntry {
+    pub(crate) fn from_metadata(code: String, metadata: &CurrencyMetadata) -> Self {
+        Self {
+            code,
+            full_name: metadata.full_name.to_string(),
+            minor_units: metadata.minor_units,
+            symbol: metadata.symbol.to_string(),
+            symbol_first: metadata.symbol_first,
+            default_locale: metadata.default_locale,
+            cash_increment: metadata.cash_increment,
+        }
+    }
+}
+
+const fn default_symbol_first() -> bool {
+    true
+}
+
+const fn default_locale() -> Locale {
+    Locale::EnUs
+}
+
+mod locale_tag {
+    use serde::de::Error as _;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::locale::Locale;
+
+    #[cfg_attr(
+        not(feature = "money-formatting"),
+        expect(
+            clippy::trivially_copy_pass_by_ref,
+            reason = "serde's `with` attribute passes fields by reference"
+        )
+    )]
+    pub fn serialize<S: Serializer>(locale: &Locale, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&locale.tag())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Locale, D::Error> {
+        let tag = String::deserialize(deserializer)?;
+        Locale::from_tag(&tag).ok_or_else(|| D::Error::custom(format!("unknown locale '{tag}'")))
+    }
+}
+
+/// A metadata document: the currencies to load, or the exported registry.
+///
+/// ```rust
+/// # #[cfg(feature = "metadata-json")]
+/// # {
+/// use paft_money::{CurrencyMetadataFile, currency_metadata};
+///
+/// let file = CurrencyMetadataFile::from_json_str(
+///     r#"{"currencies": [{"code": "ACME", "full_name": "Acme Token", "minor_units": 4, "symbol": "Ⓐ"}]}"#,
+/// )
+/// .unwrap();
+/// assert_eq!(file.load().unwrap(), 1);
+/// assert_eq!(currency_metadata("ACME").unwrap().minor_units, 4);
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CurrencyMetadataFile {
+    /// Currency entries in document order.
+    pub currencies: Vec<CurrencyMetadataEntry>,
+}
+
+impl CurrencyMetadataFile {
+    /// The effective registry (built-in metadata overlaid by custom
+    /// registrations), ordered by code.
+    #[must_use]
+    pub fn effective() -> Self {
+        Self {
+            currencies: export_currency_metadata(),
+        }
+    }
+
+    /// Registers every entry, or none of them.
+    ///
+    /// Entries must not change a currency's known minor-unit scale; see
+    /// [`load_currency_metadata`] for the override variant.
+    ///
+    /// # Errors
+    /// See [`load_currency_metadata`].
+    pub fn load(&self) -> Result<usize, MetadataLoadError> {
+        load_currency_metadata(&self.currencies, false)
+    }
+
+    /// Like [`CurrencyMetadataFile::load`], registering into `registry`
+    /// instead of the global registry.
+    ///
+    /// # Errors
+    /// See [`load_currency_metadata`].
+    pub fn load_into(&self, registry: &CurrencyRegistry) -> Result<usize, MetadataLoadError> {
+        registry.load(&self.currencies, false)
+    }
+
+    /// Parses a JSON metadata document.
+    ///
+    /// # Errors
+    /// Returns [`MetadataLoadError::Parse`] when the document is malformed.
+    #[cfg(feature = "metadata-json")]
+    pub fn from_json_str(json: &str) -> Result<Self, MetadataLoadError> {
+        serde_json::from_str(json).map_err(|err| MetadataLoadError::Parse {
+            format: "JSON",
+            message: err.to_string(),
+        })
+    }
+
+    /// Renders the document as pretty-printed JSON.
+    ///
+    /// # Panics
+    /// Never in practice: every entry field has a JSON representation.
+    #[cfg(feature = "metadata-json")]
+    #[must_use]
+    pub fn to_json_string(&self) -> String {
+        serde_json::to_string_pretty(self).expect("metadata entries always serialize to JSON")
+    }
+
+    /// Parses a TOML metadata document.
+    ///
+    /// # Errors
+    /// Returns [`MetadataLoadError::Parse`] when the document is malformed.
+    #[cfg(feature = "metadata-toml")]
+    pub fn from_toml_str(toml: &str) -> Result<Self, MetadataLoadError> {
+        toml::from_str(toml).map_err(|err| MetadataLoadError::Parse {
+            format: "TOML",
+            message: err.to_string(),
+        })
+    }
+
+    /// Renders the document as TOML.
+    ///
+    /// # Panics
+    /// Never in practice: every entry field has a TOML representation.
+    #[cfg(feature = "metadata-toml")]
+    #[must_use]
+    pub fn to_toml_string(&self) -> String {
+        toml::to_string(self).expect("metadata entries always serialize to TOML")
+    }
+
+    /// Reads a metadata document, choosing the format from the `.json` or
+    /// `.toml` extension.
+    ///
+    /// # Errors
+    /// Returns [`MetadataLoadError::Io`] when the file cannot be read,
+    /// [`MetadataLoadError::UnsupportedFormat`] for other extensions or a
+    /// format whose feature is disabled, and [`MetadataLoadError::Parse`] when
+    /// the document is malformed.
+    #[cfg(any(feature = "metadata-json", feature = "metadata-toml"))]
+    pub fn read(path: impl AsRef<Path>) -> Result<Self, MetadataLoadError> {
+        let path = path.as_ref();
+        let display = path.display().to_string();
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_ascii_lowercase);
+        let parse: fn(&str) -> Result<Self, MetadataLoadError> = match extension.as_deref() {
+            #[cfg(feature = "metadata-json")]
+            Some("json") => Self::from_json_str,
+            #[cfg(feature = "metadata-toml")]
+            Some("toml") => Self::from_toml_str,
+            _ => return Err(MetadataLoadError::UnsupportedFormat { path: display }),
+        };
+        let contents = std::fs::read_to_string(path).map_err(|err| MetadataLoadError::Io {
+            path: display,
+            message: err.to_string(),
+        })?;
+        parse(&contents)
+    }
+}
+
+/// Reads a `.json` or `.toml` metadata file and registers every entry, or
+/// none of them.
+///
+/// # Errors
+/// See [`CurrencyMetadataFile::read`] and [`load_currency_metadata`].
+#[cfg(any(feature = "metadata-json", feature = "metadata-toml"))]
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip(path), err)
+)]
+pub fn load_currency_metadata_file(path: impl AsRef<Path>) -> Result<usize, MetadataLoadError> {
+    CurrencyMetadataFile::read(path)?.load()
+}
@@ -19,7 +19,7 @@ use df_derive_macros::ToDataFrame;
 use crate::currency::Currency;
 #[cfg(feature = "money-formatting")]
 use crate::currency_utils::MAX_DECIMAL_PRECISION;
-use crate::currency_utils::MAX_MINOR_UNIT_DECIMALS;
+use crate::currency_utils::{CurrencyRegistry, MAX_MINOR_UNIT_DECIMALS};
 use crate::exact::{
     CurrencyAmount, canonical_amount_format, checked_add_decimal, checked_div_decimal,
     checked_mul_decimal, checked_sub_decimal, copy_decimal, decimal_from_scaled_units,
@@ -31,6 +31,8 @@ use crate::format::{FormatItem, Formatter, Params};
 use crate::locale::Locale;
 #[cfg(feature = "money-formatting")]
 use crate::parser;
+#[cfg(feature = "money-formatting")]
+use crate::pattern::MoneyPattern;
 
 /// Represents an exchange rate between two currencies.
 ///
@@ -225,7 +227,24 @@ impl Money {
     /// Returns `MoneyError::MetadataNotFound` when metadata is not registered for a custom currency.
     #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", err))]
     pub fn new(amount: Decimal, currency: Currency) -> Result<Self, MoneyError> {
-        let (minor_units, scale) = Self::scale_for_currency(&currency)?;
+        Self::new_in(amount, currency, CurrencyRegistry::global())
+    }
+
+    /// Like [`Money::new`], resolving the currency's scale in `registry`.
+    ///
+    /// # Errors
+    /// Returns `MoneyError::MetadataNotFound` when neither ISO 4217 nor
+    /// `registry` defines the currency's scale.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(registry), err)
+    )]
+    pub fn new_in(
+        amount: Decimal,
+        currency: Currency,
+        registry: &CurrencyRegistry,
+    ) -> Result<Self, MoneyError> {
+        let (minor_units, scale) = Self::scale_for_currency(&currency, registry)?;
         let rounded = Self::round_amount_to_scale(&amount, scale);
         Ok(Self {
             amount: rounded,
@@ -259,7 +278,27 @@ impl Money {
     // The body uses `&amount` for validation and only consumes the canonical
     // value; keep the signature consistent across backends and deserialize paths.
     pub fn new_exact(amount: Decimal, currency: Currency) -> Result<Self, MoneyError> {
-        let (minor_units, scale) = Self::scale_for_currency(&currency)?;
+        Self::new_exact_in(amount, currency, CurrencyRegistry::global())
+    }
+
+    /// Like [`Money::new_exact`], resolving the currency's scale in
+    /// `registry`.
+    ///
+    /// # Errors
+    /// - Returns `MoneyError::MetadataNotFound` when neither ISO 4217 nor
+    ///   `registry` defines the currency's scale.
+    /// - Returns `MoneyError::PrecisionExceeded` when the supplied amount has
+    ///   more fractional digits than that scale permits.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(registry), err)
+    )]
+    pub fn new_exact_in(
+        amount: Decimal,
+        currency: Currency,
+        registry: &CurrencyRegistry,
+    ) -> Result<Self, MoneyError> {
+        let (minor_units, scale) = Self::scale_for_currency(&currency, registry)?;
         let canonical = Self::canonicalize_exact_amount(&amount, &currency, scale)?;
         Ok(Self {
             amount: canonical,
@@ -276,6 +315,15 @@ impl Money {
         Self::new(decimal::zero(), currency)
     }
 
+    /// Like [`Money::zero`], resolving the currency's scale in `registry`.
+    ///
+    /// # Errors
+    /// Returns `MoneyError::MetadataNotFound` when neither ISO 4217 nor
+    /// `registry` defines the currency's scale.
+    pub fn zero_in(currency: Currency, registry: &CurrencyRegistry) -> Result<Self, MoneyError> {
+        Self::new_in(decimal::zero(), currency, registry)
+    }
+
     /// Returns the amount as a [`Decimal`].
     ///
     /// The value is cloned from the internal representation. Cloning is a
@@ -339,8 +387,25 @@ impl Money {
     /// consistent across decimal backends.
     #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", err))]
     pub fn from_canonical_str(amount: &str, currency: Currency) -> Result<Self, MoneyError> {
+        Self::from_canonical_str_in(amount, currency, CurrencyRegistry::global())
+    }
+
+    /// Like [`Money::from_canonical_str`], resolving the currency's scale in
+    /// `registry`.
+    ///
+    /// # Errors
+    /// See [`Money::from_canonical_str`]; the scale comes from `registry`.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(registry), err)
+    )]
+    pub fn from_canonical_str_in(
+        amount: &str,
+        currency: Currency,
+        registry: &CurrencyRegistry,
+    ) -> Result<Self, MoneyError> {
         let amount = parse_canonical_decimal(amount)?;
-        Self::new_exact(amount, currency)
+        Self::new_exact_in(amount, currency, registry)
     }
 
     /// Creates a new Money instance from an integer amount in the currency's minor units.
@@ -351,7 +416,26 @@ impl Money {
     /// be represented by the active decimal backend.
     #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", err))]
     pub fn from_minor_units(minor_units: i128, currency: Currency) -> Result<Self, MoneyError> {
-        let (currency_minor_units, scale) = Self::scale_for_currency(&currency)?;
+        Self::from_minor_units_in(minor_units, currency, CurrencyRegistry::global())
+    }
+
+    /// Like [`Money::from_minor_units`], resolving the currency's scale in
+    /// `registry`.
+    ///
+    /// # Errors
+    /// See [`Money::from_minor_units`]; also returns
+    /// `MoneyError::MetadataNotFound` when `registry` has no scale for the
+    /// currency.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip(registry), err)
+    )]
+    pub fn from_minor_units_in(
+        minor_units: i128,
+        currency: Currency,
+        registry: &CurrencyRegistry,
+    ) -> Result<Self, MoneyError> {
+        let (currency_minor_units, scale) = Self::scale_for_currency(&currency, registry)?;
         let amount = decimal_from_scaled_units(minor_units, scale)?;
         Ok(Self {
             amount,
@@ -436,7 +520,14 @@ impl Money {
         locale: Locale,
         fraction_digits: u32,
     ) -> Result<String, MoneyError> {
-        self.render_with_locale(locale, false, false, None, fraction_digits)
+        self.render_with_locale(
+            CurrencyRegistry::global(),
+            locale,
+            false,
+            false,
+            None,
+            fraction_digits,
+        )
     }
 
     /// Addition that returns an error for currency mismatch.
@@ -580,7 +671,7 @@ impl Money {
             return Ok(self.clone());
         }
 
-        let (minor_units, scale) = Self::scale_for_currency(&rate.to)?;
+        let (minor_units, scale) = Self::scale_for_currency(&rate.to, CurrencyRegistry::global())?;
         let product = checked_mul_decimal(&self.amount, &rate.rate)?;
         let converted_amount = decimal::round_dp_with_strategy(&product, scale, rounding);
         Ok(Self {
@@ -616,8 +707,11 @@ impl Money {
         currency.decimal_places()
     }
 
-    fn scale_for_currency(currency: &Currency) -> Result<(u8, u32), MoneyError> {
-        let minor_units = Self::decimals_for_currency(currency)?;
+    fn scale_for_currency(
+        currency: &Currency,
+        registry: &CurrencyRegistry,
+    ) -> Result<(u8, u32), MoneyError> {
+        let minor_units = registry.decimal_places(currency)?;
         let scale = Self::ensure_scale_within_limits(minor_units)?;
         Ok((minor_units, scale))
     }
@@ -646,7 +740,11 @@ impl Money {
         Ok(canonical)
     }
 
-    fn from_rounded_parts(amount: &Decimal, currency: Currency, minor_units: u8) -> Self {
+    pub(crate) fn from_rounded_parts(
+        amount: &Decimal,
+        currency: Currency,
+        minor_units: u8,
+    ) -> Self {
         let scale = Self::ensure_scale_within_limits(minor_units)
             .expect("stored minor-unit scale was validated at construction");
         Self {
@@ -714,6 +812,7 @@ impl Money {
     #[cfg(feature = "money-formatting")]
     fn render_with_locale(
         &self,
+        registry: &CurrencyRegistry,
         locale: Locale,
         include_symbol: bool,
         include_code: bool,
@@ -729,7 +828,9 @@ impl Money {
         }
 
         let mut symbol = if include_symbol {
-            self.currency.symbol().filter(|s| !s.as_ref().is_empty())
+            registry
+                .symbol(&self.currency)
+                .filter(|s| !s.as_ref().is_empty())
         } else {
             None
         };
@@ -742,7 +843,8 @@ impl Money {
             symbol = None;
         }
 
-        let symbol_first = symbol_first_override.unwrap_or_else(|| self.currency.symbol_first());
+        let symbol_first =
+            symbol_first_override.unwrap_or_else(|| registry.symbol_first(&self.currency));
         let symbol_spacing = symbol.as_ref().is_some_and(|s| s.chars().count() > 1);
 
         let code = if include_code {
@@ -790,6 +892,36 @@ impl Money {
 
         Formatter::new(amount, locale, params).format()
     }
+
+    #[cfg(feature = "money-formatting")]
+    fn render_with_pattern(
+        &self,
+        registry: &CurrencyRegistry,
+        locale: Locale,
+        pattern: &MoneyPattern,
+        include_symbol: bool,
+        fraction_digits: Option<u32>,
+    ) -> Result<String, MoneyError> {
+        let max_fraction_digits = u32::from(MAX_DECIMAL_PRECISION);
+        if let Some(digits) = fraction_digits.filter(|digits| *digits > max_fraction_digits) {
+            return Err(MoneyError::FormatPrecisionExceeded {
+                actual_fraction_digits: digits,
+                max_fraction_digits,
+            });
+        }
+
+        let symbol = if include_symbol {
+            registry
+                .symbol(&self.currency)
+                .filter(|s| !s.as_ref().is_empty())
+        } else {
+            None
+        };
+        let code = Some(Cow::Borrowed(self.currency.code()));
+        let params = pattern.params(symbol, code, fraction_digits);
+
+        Formatter::new(copy_decimal(&self.amount), locale, params).format()
+    }
 }
 
 impl CurrencyAmount for Money {
@@ -841,6 +973,8 @@ pub struct LocalizedMoney<'a> {
     include_code: bool,
     symbol_first_override: Option<bool>,
     fraction_digits: Option<u32>,
+    pattern: Option<&'a MoneyPattern>,
+    registry: Option<&'a CurrencyRegistry>,
 }
 
 #[cfg(feature = "money-formatting")]
@@ -855,6 +989,8 @@ impl<'a> LocalizedMoney<'a> {
             include_code: false,
             symbol_first_override: None,
             fraction_digits: None,
+            pattern: None,
+            registry: None,
         }
     }
 
@@ -886,6 +1022,35 @@ impl<'a> LocalizedMoney<'a> {
         self
     }
 
+    /// Lay out the amount with a CLDR-style `pattern`.
+    ///
+    /// The pattern decides where the sign, symbol, and code go, so
+    /// [`LocalizedMoney::with_code`] and [`LocalizedMoney::symbol_first`] are
+    /// ignored. [`LocalizedMoney::without_symbol`] still blanks `¤`, and
+    /// [`LocalizedMoney::fraction_digits`] overrides the pattern's fraction
+    /// digits.
+    ///
+    /// ```rust
+    /// # use paft_money::{Currency, IsoCurrency, Locale, Money, MoneyPattern};
+    /// let pattern = MoneyPattern::parse("+#,##0.00## ¤¤").unwrap();
+    /// let gain = Money::from_canonical_str("1234.5", Currency::Iso(IsoCurrency::EUR)).unwrap();
+    /// let rendered = gain.localized(Locale::EnEu).with_pattern(&pattern).into_string();
+    /// assert_eq!(rendered.unwrap(), "+1.234,50 EUR");
+    /// ```
+    #[must_use]
+    pub const fn with_pattern(mut self, pattern: &'a MoneyPattern) -> Self {
+        self.pattern = Some(pattern);
+        self
+    }
+
+    /// Look up the currency's symbol and placement in `registry` instead of
+    /// the global registry.
+    #[must_use]
+    pub const fn with_registry(mut self, registry: &'a CurrencyRegistry) -> Self {
+        self.registry = Some(registry);
+        self
+    }
+
     /// Produce the localized string according to the configured options.
     ///
     /// # Errors
@@ -898,11 +1063,22 @@ impl<'a> LocalizedMoney<'a> {
     }
 
     fn format_internal(&self) -> Result<String, MoneyError> {
+        let registry = self.registry.unwrap_or_else(|| CurrencyRegistry::global());
+        if let Some(pattern) = self.pattern {
+            return self.money.render_with_pattern(
+                registry,
+                self.locale,
+                pattern,
+                self.include_symbol,
+                self.fraction_digits,
+            );
+        }
         let digits = self
             .fraction_digits
             .unwrap_or_else(|| u32::from(self.money.minor_units()));
 
         self.money.render_with_locale(
+            registry,
             self.locale,
             self.include_symbol,
             self.include_code,
@@ -0,0 +1,267 @@
+//! Accumulator for amounts in several currencies.
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::amount::MonetaryAmount;
+use crate::currency::Currency;
+use crate::decimal::{self, RoundingStrategy};
+use crate::error::MoneyError;
+use crate::exact::{checked_add_decimal, checked_mul_decimal};
+use crate::money::Money;
+use crate::rate_book::RateSource;
+
+/// A sum of amounts in any number of currencies.
+///
+/// Amounts are kept at full precision per currency, so adding [`Money`] and
+/// [`MonetaryAmount`] values never rounds. Currencies whose total returns to
+/// zero are dropped, and entries are ordered by currency code, so two bags
+/// holding the same totals compare and serialize identically.
+///
+/// Serializes as the list of per-currency totals:
+/// `[{"amount":"5","currency":"EUR"},{"amount":"12.34","currency":"USD"}]`.
+///
+/// ```rust
+/// # use paft_decimal::Decimal;
+/// # use paft_money::{Currency, ExchangeRate, IsoCurrency, Money, MoneyBag, RateBook};
+/// # fn run() -> Result<(), paft_money::MoneyError> {
+/// let usd = Currency::Iso(IsoCurrency::USD);
+/// let eur = Currency::Iso(IsoCurrency::EUR);
+///
+/// let mut bag = MoneyBag::new();
+/// bag.try_add(Money::from_canonical_str("10", usd.clone())?)?;
+/// bag.try_add(Money::from_canonical_str("5", eur.clone())?)?;
+/// assert_eq!(bag.len(), 2);
+///
+/// let rates = RateBook::from_rates([ExchangeRate::new(eur, usd.clone(), Decimal::from(2))?])?;
+/// assert_eq!(bag.collapse(&usd, &rates)?.format(), "20 USD");
+/// # Ok(()) } run().unwrap();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize)]
+#[serde(transparent)]
+pub struct MoneyBag {
+    amounts: Vec<MonetaryAmount>,
+}
+
+impl MoneyBag {
+    /// Build an empty bag.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            amounts: Vec::new(),
+        }
+    }
+
+    /// Build a bag holding the sum of `amounts`.
+    ///
+    /// # Errors
+    /// Returns [`MoneyError::ConversionError`] when a per-currency total
+    /// overflows the active decimal backend.
+    pub fn from_amounts<I, A>(amounts: I) -> Result<Self, MoneyError>
+    where
+        I: IntoIterator<Item = A>,
+        A: Into<MonetaryAmount>,
+    {
+        let mut bag = Self::new();
+        for amount in amounts {
+            bag.try_add(amount)?;
+        }
+        Ok(bag)
+    }
+
+    /// Number of currencies with a non-zero total.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.amounts.len()
+    }
+
+    /// Whether every currency total is zero.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.amounts.is_empty()
+    }
+
+    /// Per-currency totals, ordered by currency code.
+    pub fn iter(&self) -> std::slice::Iter<'_, MonetaryAmount> {
+        self.amounts.iter()
+    }
+
+    /// Currencies with a non-zero total, ordered by code.
+    pub fn currencies(&self) -> impl Iterator<Item = &Currency> {
+        self.amounts.iter().map(MonetaryAmount::currency)
+    }
+
+    /// The total held in `currency`, if non-zero.
+    #[must_use]
+    pub fn get(&self, currency: &Currency) -> Option<&MonetaryAmount> {
+        self.position(currency)
+            .ok()
+            .map(|index| &self.amounts[index])
+    }
+
+    /// Add `amount` to the total for its currency.
+    ///
+    /// # Errors
+    /// Returns [`MoneyError::ConversionError`] when the total overflows the
+    /// active decimal backend.
+    pub fn try_add(&mut self, amount: impl Into<MonetaryAmount>) -> Result<(), MoneyError> {
+        let amount = amount.into();
+        match self.position(amount.currency()) {
+            Ok(index) => {
+                let total = self.amounts[index].try_add(&amount)?;
+                if total.amount() == decimal::zero() {
+                    self.amounts.remove(index);
+                } else {
+                    self.amounts[index] = total;
+                }
+            }
+            Err(index) => {
+                if amount.amount() != decimal::zero() {
+                    self.amounts.insert(index, amount);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Subtract `amount` from the total for its currency.
+    ///
+    /// # Errors
+    /// Returns [`MoneyError::ConversionError`] when the total overflows the
+    /// active decimal backend.
+    pub fn try_sub(&mut self, amount: impl Into<MonetaryAmount>) -> Result<(), MoneyError> {
+        let amount = amount.into();
+        self.try_add(MonetaryAmount::new(
+            -amount.amount(),
+            amount.currency().clone(),
+        ))
+    }
+
+    /// Add every total of `other` to this bag.
+    ///
+    /// # Errors
+    /// Returns [`MoneyError::ConversionError`] when a total overflows the
+    /// active decimal backend.
+    pub fn try_add_bag(&mut self, other: &Self) -> Result<(), MoneyError> {
+        for amount in other {
+            self.try_add(amount.clone())?;
+        }
+        Ok(())
+    }
+
+    /// Subtract every total of `other` from this bag.
+    ///
+    /// # Errors
+    /// Returns [`MoneyError::ConversionError`] when a total overflows the
+    /// active decimal backend.
+    pub fn try_sub_bag(&mut self, other: &Self) -> Result<(), MoneyError> {
+        for amount in other {
+            self.try_sub(amount.clone())?;
+        }
+        Ok(())
+    }
+
+    /// Negate every total in place.
+    pub fn negate(&mut self) {
+        for amount in &mut self.amounts {
+            *amount = MonetaryAmount::new(-amount.amount(), amount.currency().clone());
+        }
+    }
+
+    /// A copy of this bag with every total negated.
+    #[must_use]
+    pub fn negated(&self) -> Self {
+        let mut bag = self.clone();
+        bag.negate();
+        bag
+    }
+
+    /// Convert every total into `target` through `rates` and sum them,
+    /// rounding once with [`RoundingStrategy::MidpointAwayFromZero`].
+    ///
+    /// # Errors
+    /// See [`MoneyBag::collapse_with`].
+    pub fn collapse<R>(&self, target: &Currency, rates: &R) -> Result<Money, MoneyError>
+    where
+        R: RateSource + ?Sized,
+    {
+        self.collapse_with(target, rates, RoundingStrategy::MidpointAwayFromZero)
+    }
+
+    /// Convert every total into `target` through `rates` and sum them at full
+    /// precision, rounding the result once with `rounding`.
+    ///
+    /// # Errors
+    /// - [`MoneyError::MissingExchangeRates`] listing every currency for
+    ///   which `rates` reported [`MoneyError::ExchangeRateNotFound`].
+    /// - Any other error from `rates`, or [`MoneyError::ConversionError`]
+    ///   when the sum overflows the active decimal backend.
+    /// - Errors from [`MonetaryAmount::to_money_with`] for the final rounding.
+    pub fn collapse_with<R>(
+        &self,
+        target: &Currency,
+        rates: &R,
+        rounding: RoundingStrategy,
+    ) -> Result<Money, MoneyError>
+    where
+        R: RateSource + ?Sized,
+    {
+        let mut total = decimal::zero();
+        let mut missing = Vec::new();
+        for amount in &self.amounts {
+            if amount.currency() == target {
+                total = checked_add_decimal(&total, &amount.amount())?;
+                continue;
+            }
+            match rates.exchange_rate(amount.currency(), target) {
+                Ok(rate) => {
+                    let converted = checked_mul_decimal(&amount.amount(), &rate.rate())?;
+                    total = checked_add_decimal(&total, &converted)?;
+                }
+                Err(MoneyError::ExchangeRateNotFound { .. }) => {
+                    missing.push(amount.currency().clone());
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        if !missing.is_empty() {
+            return Err(MoneyError::MissingExchangeRates {
+                target: target.clone(),
+                missing,
+            });
+        }
+        MonetaryAmount::new(total, target.clone()).to_money_with(rounding, None)
+    }
+
+    fn position(&self, currency: &Currency) -> Result<usize, usize> {
+        self.amounts
+            .binary_search_by(|held| held.currency().code().cmp(currency.code()))
+    }
+}
+
+impl<'a> IntoIterator for &'a MoneyBag {
+    type Item = &'a MonetaryAmount;
+    type IntoIter = std::slice::Iter<'a, MonetaryAmount>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for MoneyBag {
+    type Item = MonetaryAmount;
+    type IntoIter = std::vec::IntoIter<MonetaryAmount>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.amounts.into_iter()
+    }
+}
+
+impl<'de> Deserialize<'de> for MoneyBag {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let amounts = Vec::<MonetaryAmount>::deserialize(deserializer)?;
+        Self::from_amounts(amounts).map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,362 @@
+//! CLDR-style number patterns for [`crate::LocalizedMoney`].
+
+use std::borrow::Cow;
+use std::fmt;
+use std::num::NonZeroUsize;
+use std::str::FromStr;
+
+use crate::currency_utils::MAX_DECIMAL_PRECISION;
+use crate::error::MoneyError;
+use crate::format::{FormatItem, Params};
+
+/// A compiled CLDR-style currency pattern such as `¤#,##0.00;(¤#,##0.00)`.
+///
+/// A pattern holds a positive subpattern and an optional negative subpattern
+/// separated by `;`. Each subpattern is a prefix, a number part, and a suffix:
+///
+/// - Number part: `0` is a required digit, `#` an optional one, `,` marks
+///   grouping and `.` the decimal point. Grouping sizes follow CLDR: the
+///   digits after the last `,` form the primary group and the digits between
+///   the last two commas the secondary group, so `#,##,##0` groups Indian
+///   style. Fraction `0`s set the minimum and `0`s plus `#`s the maximum
+///   fraction digits; the amount is rounded half-to-even to the maximum and
+///   trailing zeros are trimmed down to the minimum.
+/// - Affixes: `¤` is the currency symbol, `¤¤` the currency code, `-` the
+///   minus sign and `+` an explicit plus sign. Text in single quotes is
+///   literal (`''` is an apostrophe); any other character is copied as is.
+///
+/// Only the affixes of the negative subpattern are used; its number part must
+/// be present but is otherwise ignored, as in CLDR. Without a negative
+/// subpattern, negative amounts replace each `+` of the positive subpattern
+/// with `-`, or prefix `-` when there is none.
+///
+/// The locale still supplies the grouping and decimal separator characters;
+/// the pattern supplies everything else.
+///
+/// ```rust
+/// # use paft_money::{Currency, IsoCurrency, Locale, Money, MoneyPattern};
+/// let accounting: MoneyPattern = "¤#,##0.00;(¤#,##0.00)".parse().unwrap();
+/// let loss = Money::from_canonical_str("-1234.5", Currency::Iso(IsoCurrency::USD)).unwrap();
+/// let rendered = loss.localized(Locale::EnUs).with_pattern(&accounting).into_string();
+/// assert_eq!(rendered.unwrap(), "($1,234.50)");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoneyPattern {
+    source: String,
+    positive: Vec<FormatItem>,
+    negative: Vec<FormatItem>,
+    grouping: Vec<NonZeroUsize>,
+    min_integer_digits: usize,
+    min_fraction_digits: u32,
+    max_fraction_digits: u32,
+}
+
+impl MoneyPattern {
+    /// Compile `pattern`.
+    ///
+    /// # Errors
+    /// Returns [`MoneyError::InvalidFormatPattern`] with the character
+    /// position of the problem when the pattern is malformed, uses an
+    /// unsupported CLDR feature (percent, per-mille, padding, significant
+    /// digits, or `¤¤¤`), or asks for more than
+    /// [`crate::MAX_DECIMAL_PRECISION`] fraction digits.
+    pub fn parse(pattern: &str) -> Result<Self, MoneyError> {
+        let chars: Vec<char> = pattern.chars().collect();
+        let (positive, rest) = parse_subpattern(&chars, 0)?;
+        let negative = match rest {
+            Some(start) => {
+                let (negative, rest) = parse_subpattern(&chars, start)?;
+                if let Some(extra) = rest {
+                    return Err(invalid(extra - 1, "pattern has more than two subpatterns"));
+                }
+                negative.affixes
+            }
+            None => implicit_negative(&positive.affixes),
+        };
+        let number = positive.number;
+        if number.max_fraction_digits > u32::from(MAX_DECIMAL_PRECISION) {
+            return Err(invalid(
+                number.end,
+                "pattern exceeds the maximum fraction digits",
+            ));
+        }
+        Ok(Self {
+            source: pattern.to_string(),
+            positive: positive.affixes,
+            negative,
+            grouping: number.grouping,
+            min_integer_digits: number.min_integer_digits,
+            min_fraction_digits: number.min_fraction_digits,
+            max_fraction_digits: number.max_fraction_digits,
+        })
+    }
+
+    /// The pattern text this was compiled from.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Fewest fraction digits rendered; trailing zeros are kept up to this.
+    #[must_use]
+    pub const fn min_fraction_digits(&self) -> u32 {
+        self.min_fraction_digits
+    }
+
+    /// Most fraction digits rendered; amounts are rounded to this.
+    #[must_use]
+    pub const fn max_fraction_digits(&self) -> u32 {
+        self.max_fraction_digits
+    }
+
+    /// Fewest integer digits rendered, padding with leading zeros.
+    #[must_use]
+    pub const fn min_integer_digits(&self) -> usize {
+        self.min_integer_digits
+    }
+
+    /// Formatter parameters for this pattern. `fraction_digits` overrides
+    /// both fraction digit bounds when set.
+    pub(crate) fn params<'a>(
+        &self,
+        symbol: Option<Cow<'a, str>>,
+        code: Option<Cow<'a, str>>,
+        fraction_digits: Option<u32>,
+    ) -> Params<'a> {
+        let mut params = Params::new(self.positive.clone());
+        params.negative_positions = Some(self.negative.clone());
+        params.rounding_digits = Some(fraction_digits.unwrap_or(self.max_fraction_digits));
+        params.min_fraction_digits = Some(fraction_digits.unwrap_or(self.min_fraction_digits));
+        params.min_integer_digits = self.min_integer_digits;
+        params.grouping = Some(self.grouping.clone());
+        params.symbol = symbol;
+        params.code = code;
+        params
+    }
+}
+
+impl FromStr for MoneyPattern {
+    type Err = MoneyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for MoneyPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+struct Subpattern {
+    affixes: Vec<FormatItem>,
+    number: NumberPart,
+}
+
+struct NumberPart {
+    grouping: Vec<NonZeroUsize>,
+    min_integer_digits: usize,
+    min_fraction_digits: u32,
+    max_fraction_digits: u32,
+    // Position just past the number part, for error reporting.
+    end: usize,
+}
+
+const fn invalid(position: usize, reason: &'static str) -> MoneyError {
+    MoneyError::InvalidFormatPattern { position, reason }
+}
+
+const fn is_number_char(c: char) -> bool {
+    matches!(c, '#' | '0' | ',' | '.')
+}
+
+/// Parses the subpattern starting at `start`, returning it and the position
+/// after its terminating `;`, if any.
+fn parse_subpattern(
+    chars: &[char],
+    start: usize,
+) -> Result<(Subpattern, Option<usize>), MoneyError> {
+    let mut affixes = Vec::new();
+    let mut number = None;
+    let mut index = start;
+    while index < chars.len() {
+        let c = chars[index];
+        match c {
+            ';' => return finish(affixes, number, index, Some(index + 1)),
+            _ if is_number_char(c) => {
+                if number.is_some() {
+                    return Err(invalid(index, "number part must be contiguous"));
+                }
+                let (part, end) = parse_number(chars, index)?;
+                affixes.push(FormatItem::Amount);
+                number = Some(part);
+                index = end;
+                continue;
+            }
+            '¤' => {
+                let run = chars[index..].iter().take_while(|c| **c == '¤').count();
+                affixes.push(match run {
+                    1 => FormatItem::Symbol,
+                    2 => FormatItem::Code,
+                    _ => return Err(invalid(index, "currency names (¤¤¤) are not supported")),
+                });
+                index += run;
+                continue;
+            }
+            '-' => affixes.push(FormatItem::Sign),
+            '+' => affixes.push(FormatItem::Plus),
+            '\'' => {
+                let (text, end) = parse_quoted(chars, index)?;
+                push_literal(&mut affixes, &text);
+                index = end;
+                continue;
+            }
+            '%' | '‰' | '*' | '@' => {
+                return Err(invalid(index, "unsupported pattern character"));
+            }
+            _ => push_literal(&mut affixes, c.encode_utf8(&mut [0; 4])),
+        }
+        index += 1;
+    }
+    finish(affixes, number, chars.len(), None)
+}
+
+fn finish(
+    affixes: Vec<FormatItem>,
+    number: Option<NumberPart>,
+    end: usize,
+    rest: Option<usize>,
+) -> Result<(Subpattern, Option<usize>), MoneyError> {
+    let number = number.ok_or_else(|| invalid(end, "subpattern has no number part"))?;
+    Ok((Subpattern { affixes, number }, rest))
+}
+
+/// Parses a quoted literal starting at the opening quote, returning its text
+/// and the position after the closing quote.
+fn parse_quoted(chars: &[char], start: usize) -> Result<(String, usize), MoneyError> {
+    if chars.get(start + 1) == Some(&'\'') {
+        return Ok(("'".to_string(), start + 2));
+    }
+    let mut text = String::new();
+    let mut index = start + 1;
+    loop {
+        match chars.get(index) {
+            None => return Err(invalid(start, "unterminated quote")),
+            Some('\'') if chars.get(index + 1) == Some(&'\'') => {
+                text.push('\'');
+                index += 2;
+            }
+            Some('\'') => return Ok((text, index + 1)),
+            Some(c) => {
+                text.push(*c);
+                index += 1;
+            }
+        }
+    }
+}
+
+fn push_literal(affixes: &mut Vec<FormatItem>, text: &str) {
+    if let Some(FormatItem::Literal(last)) = affixes.last_mut() {
+        last.push_str(text);
+    } else {
+        affixes.push(FormatItem::Literal(text.to_string()));
+    }
+}
+
+/// Parses the number part starting at `start`, returning it and the position
+/// after its last character.
+fn parse_number(chars: &[char], start: usize) -> Result<(NumberPart, usize), MoneyError> {
+    let end = start
+        + chars[start..]
+            .iter()
+            .take_while(|c| is_number_char(**c))
+            .count();
+    let part = &chars[start..end];
+    let dot = part.iter().position(|c| *c == '.').unwrap_or(part.len());
+    let integer = &part[..dot];
+    let fraction = part.get(dot + 1..).unwrap_or_default();
+    let fraction_start = start + dot + 1;
+
+    let mut min_integer_digits = 0;
+    let mut commas = Vec::new();
+    for (offset, c) in integer.iter().enumerate() {
+        match c {
+            '0' => min_integer_digits += 1,
+            '#' if min_integer_digits > 0 => {
+                return Err(invalid(
+                    start + offset,
+                    "'#' must precede '0' in the integer part",
+                ));
+            }
+            ',' => commas.push(offset),
+            _ => {}
+        }
+    }
+    if min_integer_digits == 0 && !integer.contains(&'#') && fraction.is_empty() {
+        return Err(invalid(start, "number part has no digits"));
+    }
+    let mut grouping = Vec::new();
+    if let Some(&last) = commas.last() {
+        grouping.push(
+            NonZeroUsize::new(integer.len() - last - 1)
+                .ok_or_else(|| invalid(start + last, "grouping separator must precede a digit"))?,
+        );
+        if let Some(&previous) = commas.iter().rev().nth(1) {
+            grouping.push(NonZeroUsize::new(last - previous - 1).ok_or_else(|| {
+                invalid(start + last, "grouping separators must not be adjacent")
+            })?);
+        }
+    }
+
+    let mut min_fraction_digits = 0_u32;
+    let mut max_fraction_digits = 0_u32;
+    for (offset, c) in fraction.iter().enumerate() {
+        match c {
+            '0' if max_fraction_digits > min_fraction_digits => {
+                return Err(invalid(
+                    fraction_start + offset,
+                    "'0' must precede '#' in the fraction part",
+                ));
+            }
+            '0' => {
+                min_fraction_digits += 1;
+                max_fraction_digits += 1;
+            }
+            '#' => max_fraction_digits += 1,
+            _ => {
+                return Err(invalid(
+                    fraction_start + offset,
+                    "fraction part may only contain '0' and '#'",
+                ));
+            }
+        }
+    }
+
+    Ok((
+        NumberPart {
+            grouping,
+            min_integer_digits,
+            min_fraction_digits,
+            max_fraction_digits,
+            end,
+        },
+        end,
+    ))
+}
+
+fn implicit_negative(positive: &[FormatItem]) -> Vec<FormatItem> {
+    if positive.contains(&FormatItem::Plus) {
+        return positive
+            .iter()
+            .map(|item| match item {
+                FormatItem::Plus => FormatItem::Sign,
+                other => other.clone(),
+            })
+            .collect();
+    }
+    let mut negative = Vec::with_capacity(positive.len() + 1);
+    negative.push(FormatItem::Sign);
+    negative.extend(positive.iter().cloned());
+    negative
+}
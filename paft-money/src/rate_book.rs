@@ -0,0 +1,434 @@
+//! A collection of exchange rates that resolves arbitrary currency pairs.
+
+use std::collections::{HashMap, VecDeque};
+
+use serde::{Deserialize, Serialize};
+
+use crate::currency::Currency;
+use crate::decimal::{self, Decimal, RoundingStrategy};
+use crate::error::MoneyError;
+use crate::exact::checked_mul_decimal;
+use crate::money::{ExchangeRate, Money};
+
+/// How a [`RateBook`] derived a resolved rate.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum RateResolution {
+    /// Source and target currencies are the same; the rate is `1`.
+    Identity,
+    /// A rate for the requested pair is held directly.
+    Direct,
+    /// The held rate for the reverse pair was inverted.
+    Inverse,
+    /// Triangulated through the book's configured pivot currency.
+    Pivot {
+        /// The pivot currency the two legs meet at.
+        pivot: Currency,
+    },
+    /// Chained along the shortest path of held rates.
+    ShortestPath,
+}
+
+/// One hop of a resolved rate.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RateLeg {
+    /// Currency converted from on this hop.
+    pub from: Currency,
+    /// Currency converted to on this hop.
+    pub to: Currency,
+    /// Whether the held rate for `to`/`from` was inverted for this hop.
+    pub inverted: bool,
+}
+
+/// A rate resolved by a [`RateBook`], together with the path used.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResolvedRate {
+    /// The effective rate from the requested source to the requested target.
+    pub rate: ExchangeRate,
+    /// How the rate was derived.
+    pub method: RateResolution,
+    /// Hops in conversion order; empty for [`RateResolution::Identity`].
+    pub legs: Vec<RateLeg>,
+}
+
+/// A source of exchange rates between arbitrary currency pairs.
+///
+/// Implemented by [`RateBook`] and by [`crate::HistoricalRates`], the view of
+/// a [`crate::RateHistory`] at one instant.
+pub trait RateSource {
+    /// The rate converting `from` into `to`.
+    ///
+    /// # Errors
+    /// Returns [`MoneyError::ExchangeRateNotFound`] when the source has no
+    /// rate for the pair, or another [`MoneyError`] when deriving it fails.
+    fn exchange_rate(&self, from: &Currency, to: &Currency) -> Result<ExchangeRate, MoneyError>;
+}
+
+impl RateSource for RateBook {
+    fn exchange_rate(&self, from: &Currency, to: &Currency) -> Result<ExchangeRate, MoneyError> {
+        Ok(self.resolve(from, to)?.rate)
+    }
+}
+
+/// A set of [`ExchangeRate`]s that resolves any connected currency pair.
+///
+/// [`RateBook::resolve`] tries, in order: the identity rate, a held rate for
+/// the pair, the inverse of a held rate for the reverse pair, triangulation
+/// through the configured pivot currency, and finally the shortest chain of
+/// held rates. The book holds at most one rate per unordered pair, so
+/// [`RateBook::insert`] rejects rates that contradict one already held. When
+/// several equally short chains yield different rates, resolution fails with
+/// [`MoneyError::AmbiguousExchangeRate`] rather than picking one silently;
+/// add a direct rate or configure a pivot to disambiguate.
+///
+/// ```rust
+/// # use paft_money::IsoCurrency;
+/// # use paft_decimal::Decimal;
+/// # use paft_money::{Currency, ExchangeRate, RateBook, RateResolution};
+/// # fn run() -> Result<(), paft_money::MoneyError> {
+/// let usd = Currency::Iso(IsoCurrency::USD);
+/// let eur = Currency::Iso(IsoCurrency::EUR);
+/// let gbp = Currency::Iso(IsoCurrency::GBP);
+///
+/// let mut book = RateBook::new().with_pivot(usd.clone());
+/// book.insert(ExchangeRate::new(usd.clone(), eur.clone(), Decimal::from(2))?)?;
+/// book.insert(ExchangeRate::new(usd.clone(), gbp.clone(), Decimal::from(4))?)?;
+///
+/// let resolved = book.resolve(&eur, &gbp)?;
+/// assert_eq!(resolved.rate.rate(), Decimal::from(2));
+/// assert_eq!(resolved.method, RateResolution::Pivot { pivot: usd });
+/// # Ok(()) } run().unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RateBook {
+    rates: HashMap<(Currency, Currency), ExchangeRate>,
+    // Insertion order of `rates` keys, for deterministic traversal.
+    order: Vec<(Currency, Currency)>,
+    pivot: Option<Currency>,
+}
+
+impl RateBook {
+    /// Build an empty book without a pivot currency.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a book from `rates`, inserting each with [`RateBook::insert`].
+    ///
+    /// # Errors
+    /// Returns [`MoneyError::ConflictingExchangeRate`] when two rates
+    /// contradict each other.
+    pub fn from_rates<I>(rates: I) -> Result<Self, MoneyError>
+    where
+        I: IntoIterator<Item = ExchangeRate>,
+    {
+        let mut book = Self::new();
+        for rate in rates {
+            book.insert(rate)?;
+        }
+        Ok(book)
+    }
+
+    /// Triangulate through `pivot` before falling back to the shortest path.
+    #[must_use]
+    pub fn with_pivot(mut self, pivot: Currency) -> Self {
+        self.pivot = Some(pivot);
+        self
+    }
+
+    /// Set or clear the pivot currency.
+    pub fn set_pivot(&mut self, pivot: Option<Currency>) {
+        self.pivot = pivot;
+    }
+
+    /// The configured pivot currency, if any.
+    #[must_use]
+    pub const fn pivot(&self) -> Option<&Currency> {
+        self.pivot.as_ref()
+    }
+
+    /// Number of held rates.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Whether the book holds no rates.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Held rates in insertion order.
+    pub fn rates(&self) -> impl Iterator<Item = &ExchangeRate> {
+        self.order.iter().map(|key| &self.rates[key])
+    }
+
+    /// The rate held for exactly `from`/`to`, without deriving anything.
+    #[must_use]
+    pub fn direct(&self, from: &Currency, to: &Currency) -> Option<&ExchangeRate> {
+        self.rates.get(&(from.clone(), to.clone()))
+    }
+
+    /// Add a rate to the book.
+    ///
+    /// Identity rates carry no information and are ignored. Re-inserting the
+    /// rate already held for a pair is a no-op. A rate for the reverse of a
+    /// held pair is accepted only when it equals the held rate's
+    /// [`ExchangeRate::try_inverse`].
+    ///
+    /// # Errors
+    /// Returns [`MoneyError::ConflictingExchangeRate`] when the book already
+    /// holds a different rate for the pair or its reverse; use
+    /// [`RateBook::replace`] to overwrite deliberately.
+    pub fn insert(&mut self, rate: ExchangeRate) -> Result<(), MoneyError> {
+        if rate.from() == rate.to() {
+            return Ok(());
+        }
+        if let Some(existing) = self.direct(rate.from(), rate.to()) {
+            if existing.rate() == rate.rate() {
+                return Ok(());
+            }
+            return Err(conflict(&rate, existing.rate()));
+        }
+        if let Some(reverse) = self.direct(rate.to(), rate.from()) {
+            let existing = reverse.try_inverse()?.rate();
+            if existing == rate.rate() {
+                return Ok(());
+            }
+            return Err(conflict(&rate, existing));
+        }
+        let key = (rate.from().clone(), rate.to().clone());
+        self.order.push(key.clone());
+        self.rates.insert(key, rate);
+        Ok(())
+    }
+
+    /// Hold `rate` for its pair, dropping any rate held for the pair or its
+    /// reverse. Returns the dropped rate.
+    pub fn replace(&mut self, rate: ExchangeRate) -> Option<ExchangeRate> {
+        if rate.from() == rate.to() {
+            return None;
+        }
+        let previous = self
+            .remove(rate.from(), rate.to())
+            .or_else(|| self.remove(rate.to(), rate.from()));
+        let key = (rate.from().clone(), rate.to().clone());
+        self.order.push(key.clone());
+        self.rates.insert(key, rate);
+        previous
+    }
+
+    /// Remove and return the rate held for exactly `from`/`to`.
+    pub fn remove(&mut self, from: &Currency, to: &Currency) -> Option<ExchangeRate> {
+        let key = (from.clone(), to.clone());
+        let removed = self.rates.remove(&key)?;
+        self.order.retain(|held| held != &key);
+        Some(removed)
+    }
+
+    /// Resolve the rate from `from` to `to`, reporting the path used.
+    ///
+    /// # Errors
+    /// - [`MoneyError::ExchangeRateNotFound`] when no chain of held rates
+    ///   connects the currencies.
+    /// - [`MoneyError::AmbiguousExchangeRate`] when equally short chains
+    ///   disagree.
+    /// - [`MoneyError::ConversionError`] when inverting or chaining rates
+    ///   overflows the active decimal backend.
+    pub fn resolve(&self, from: &Currency, to: &Currency) -> Result<ResolvedRate, MoneyError> {
+        if from == to {
+            return Ok(ResolvedRate {
+                rate: ExchangeRate::new(from.clone(), to.clone(), decimal::one())?,
+                method: RateResolution::Identity,
+                legs: Vec::new(),
+            });
+        }
+        if let Some(leg) = self.leg(from, to) {
+            let method = if leg.inverted {
+                RateResolution::Inverse
+            } else {
+                RateResolution::Direct
+            };
+            return self.chain(from, to, vec![leg], method);
+        }
+        if let Some(pivot) = self.pivot.as_ref().filter(|p| *p != from && *p != to)
+            && let (Some(first), Some(second)) = (self.leg(from, pivot), self.leg(pivot, to))
+        {
+            let method = RateResolution::Pivot {
+                pivot: pivot.clone(),
+            };
+            return self.chain(from, to, vec![first, second], method);
+        }
+        self.shortest_path(from, to)
+    }
+
+    /// Convert `money` into `to`, rounding with
+    /// [`RoundingStrategy::MidpointAwayFromZero`].
+    ///
+    /// # Errors
+    /// Propagates errors from [`RateBook::resolve`] and
+    /// [`Money::try_convert_with`].
+    pub fn convert(&self, money: &Money, to: &Currency) -> Result<Money, MoneyError> {
+        self.convert_with(money, to, RoundingStrategy::MidpointAwayFromZero)
+    }
+
+    /// Convert `money` into `to` with an explicit rounding strategy.
+    ///
+    /// # Errors
+    /// Propagates errors from [`RateBook::resolve`] and
+    /// [`Money::try_convert_with`].
+    pub fn convert_with(
+        &self,
+        money: &Money,
+        to: &Currency,
+        rounding: RoundingStrategy,
+    ) -> Result<Money, MoneyError> {
+        let resolved = self.resolve(money.currency(), to)?;
+        money.try_convert_with(&resolved.rate, rounding)
+    }
+
+    /// A single held hop from `from` to `to`, direct before inverted.
+    fn leg(&self, from: &Currency, to: &Currency) -> Option<RateLeg> {
+        let inverted = if self.direct(from, to).is_some() {
+            false
+        } else if self.direct(to, from).is_some() {
+            true
+        } else {
+            return None;
+        };
+        Some(RateLeg {
+            from: from.clone(),
+            to: to.clone(),
+            inverted,
+        })
+    }
+
+    fn leg_rate(&self, leg: &RateLeg) -> Result<Decimal, MoneyError> {
+        if leg.inverted {
+            Ok(self.rates[&(leg.to.clone(), leg.from.clone())]
+                .try_inverse()?
+                .rate())
+        } else {
+            Ok(self.rates[&(leg.from.clone(), leg.to.clone())].rate())
+        }
+    }
+
+    fn chain_rate(&self, legs: &[RateLeg]) -> Result<Decimal, MoneyError> {
+        legs.iter().try_fold(decimal::one(), |acc, leg| {
+            checked_mul_decimal(&acc, &self.leg_rate(leg)?)
+        })
+    }
+
+    fn chain(
+        &self,
+        from: &Currency,
+        to: &Currency,
+        legs: Vec<RateLeg>,
+        method: RateResolution,
+    ) -> Result<ResolvedRate, MoneyError> {
+        let rate = self.chain_rate(&legs)?;
+        Ok(ResolvedRate {
+            rate: ExchangeRate::new(from.clone(), to.clone(), rate)?,
+            method,
+            legs,
+        })
+    }
+
+    fn neighbours(&self) -> HashMap<&Currency, Vec<&Currency>> {
+        let mut neighbours: HashMap<&Currency, Vec<&Currency>> = HashMap::new();
+        for (a, b) in &self.order {
+            neighbours.entry(a).or_default().push(b);
+            neighbours.entry(b).or_default().push(a);
+        }
+        neighbours
+    }
+
+    fn shortest_path(&self, from: &Currency, to: &Currency) -> Result<ResolvedRate, MoneyError> {
+        let not_found = || MoneyError::ExchangeRateNotFound {
+            from: from.clone(),
+            to: to.clone(),
+        };
+        let neighbours = self.neighbours();
+
+        // Breadth-first search recording every predecessor at minimal depth,
+        // so all shortest paths can be compared afterwards.
+        let mut depth: HashMap<&Currency, usize> = HashMap::from([(from, 0)]);
+        let mut parents: HashMap<&Currency, Vec<&Currency>> = HashMap::new();
+        let mut queue = VecDeque::from([from]);
+        while let Some(node) = queue.pop_front() {
+            let next_depth = depth[node] + 1;
+            if depth.get(to).is_some_and(|found| next_depth > *found) {
+                break;
+            }
+            for &next in neighbours.get(node).into_iter().flatten() {
+                match depth.get(next) {
+                    None => {
+                        depth.insert(next, next_depth);
+                        parents.insert(next, vec![node]);
+                        queue.push_back(next);
+                    }
+                    Some(d) if *d == next_depth => {
+                        let preds = parents.entry(next).or_default();
+                        if !preds.contains(&node) {
+                            preds.push(node);
+                        }
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        if !depth.contains_key(to) {
+            return Err(not_found());
+        }
+
+        let mut resolved: Option<ResolvedRate> = None;
+        for path in all_paths(&parents, from, to) {
+            let legs: Vec<RateLeg> = path
+                .windows(2)
+                .filter_map(|pair| self.leg(pair[0], pair[1]))
+                .collect();
+            let candidate = self.chain(from, to, legs, RateResolution::ShortestPath)?;
+            match &resolved {
+                None => resolved = Some(candidate),
+                Some(first) if first.rate.rate() != candidate.rate.rate() => {
+                    return Err(MoneyError::AmbiguousExchangeRate {
+                        from: from.clone(),
+                        to: to.clone(),
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+        resolved.ok_or_else(not_found)
+    }
+}
+
+fn conflict(rate: &ExchangeRate, existing: Decimal) -> MoneyError {
+    MoneyError::ConflictingExchangeRate {
+        from: rate.from().clone(),
+        to: rate.to().clone(),
+        existing,
+    }
+}
+
+/// Every path from `from` to `to` through the BFS predecessor lists.
+fn all_paths<'a>(
+    parents: &HashMap<&'a Currency, Vec<&'a Currency>>,
+    from: &'a Currency,
+    to: &'a Currency,
+) -> Vec<Vec<&'a Currency>> {
+    if from == to {
+        return vec![vec![from]];
+    }
+    let mut paths = Vec::new();
+    for &parent in parents.get(to).into_iter().flatten() {
+        for mut path in all_paths(parents, from, parent) {
+            path.push(to);
+            paths.push(path);
+        }
+    }
+    paths
+}
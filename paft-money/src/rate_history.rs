@@ -0,0 +1,311 @@
+//! Time-indexed exchange rate observations with as-of lookup.
+
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Bound;
+
+use chrono::{DateTime, Utc};
+#[cfg(feature = "dataframe")]
+use df_derive_macros::ToDataFrame;
+use serde::{Deserialize, Serialize};
+
+use crate::currency::Currency;
+use crate::decimal::{self, RoundingStrategy};
+use crate::error::MoneyError;
+use crate::money::{ExchangeRate, Money};
+use crate::rate_book::RateSource;
+
+/// Which observation an as-of lookup accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AsOfMode {
+    /// The latest observation at or before the requested time.
+    #[default]
+    LastKnown,
+    /// The observation closest to the requested time on either side; ties
+    /// prefer the earlier observation.
+    Nearest,
+    /// Only an observation at exactly the requested time.
+    Exact,
+}
+
+/// An exchange rate observed at a point in time.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "dataframe", derive(ToDataFrame))]
+pub struct RateObservation {
+    /// Observation time as Unix milliseconds.
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub ts: DateTime<Utc>,
+    /// The rate valid at `ts`.
+    pub rate: ExchangeRate,
+}
+
+/// Historical exchange rates keyed by currency pair and observation time.
+///
+/// Lookups for a pair without its own series fall back to inverting the
+/// series for the reverse pair. Daily fixings are usually stored at the start
+/// of their day in UTC (`date.and_time(NaiveTime::MIN).and_utc()`), so a
+/// [`AsOfMode::LastKnown`] lookup at any instant of that day finds them.
+///
+/// ```rust
+/// # use chrono::{NaiveDate, NaiveTime};
+/// # use paft_decimal::Decimal;
+/// # use paft_money::{Currency, ExchangeRate, IsoCurrency, Money, RateHistory};
+/// # fn run() -> Result<(), paft_money::MoneyError> {
+/// let usd = Currency::Iso(IsoCurrency::USD);
+/// let eur = Currency::Iso(IsoCurrency::EUR);
+/// let day = |d| NaiveDate::from_ymd_opt(2024, 1, d).unwrap().and_time(NaiveTime::MIN).and_utc();
+///
+/// let mut history = RateHistory::new();
+/// history.insert(day(2), ExchangeRate::new(eur.clone(), usd.clone(), Decimal::from(2))?);
+/// history.insert(day(3), ExchangeRate::new(eur.clone(), usd.clone(), Decimal::from(3))?);
+///
+/// // The weekend uses Friday's fixing.
+/// let eur_10 = Money::from_canonical_str("10", eur)?;
+/// assert_eq!(history.convert_at(&eur_10, &usd, day(6))?.format(), "30 USD");
+/// # Ok(()) } run().unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RateHistory {
+    series: HashMap<(Currency, Currency), BTreeMap<DateTime<Utc>, ExchangeRate>>,
+    // Insertion order of `series` keys, for deterministic export.
+    pairs: Vec<(Currency, Currency)>,
+    mode: AsOfMode,
+}
+
+impl RateHistory {
+    /// Build an empty history using [`AsOfMode::LastKnown`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use `mode` for [`RateHistory::rate_at`] and the conversion helpers.
+    #[must_use]
+    pub const fn with_mode(mut self, mode: AsOfMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// The lookup mode used by [`RateHistory::rate_at`].
+    #[must_use]
+    pub const fn mode(&self) -> AsOfMode {
+        self.mode
+    }
+
+    /// Number of stored observations across all pairs.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.series.values().map(BTreeMap::len).sum()
+    }
+
+    /// Whether no observations are stored.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.series.is_empty()
+    }
+
+    /// Currency pairs with a series, in order of first insertion.
+    pub fn pairs(&self) -> impl Iterator<Item = (&Currency, &Currency)> {
+        self.pairs.iter().map(|(from, to)| (from, to))
+    }
+
+    /// Record `rate` as observed at `ts`, returning the observation it
+    /// replaces for the same pair and time.
+    pub fn insert(&mut self, ts: DateTime<Utc>, rate: ExchangeRate) -> Option<ExchangeRate> {
+        let key = (rate.from().clone(), rate.to().clone());
+        if !self.series.contains_key(&key) {
+            self.pairs.push(key.clone());
+        }
+        self.series.entry(key).or_default().insert(ts, rate)
+    }
+
+    /// Observations stored for exactly `from`/`to`, oldest first.
+    pub fn series(
+        &self,
+        from: &Currency,
+        to: &Currency,
+    ) -> impl Iterator<Item = (&DateTime<Utc>, &ExchangeRate)> {
+        self.series
+            .get(&(from.clone(), to.clone()))
+            .into_iter()
+            .flatten()
+    }
+
+    /// All observations, grouped by pair in insertion order and oldest first
+    /// within each pair.
+    #[must_use]
+    pub fn observations(&self) -> Vec<RateObservation> {
+        self.pairs
+            .iter()
+            .flat_map(|pair| &self.series[pair])
+            .map(|(ts, rate)| RateObservation {
+                ts: *ts,
+                rate: rate.clone(),
+            })
+            .collect()
+    }
+
+    /// The rate from `from` to `to` as of `when`, using the history's mode.
+    ///
+    /// # Errors
+    /// See [`RateHistory::rate_at_with`].
+    pub fn rate_at(
+        &self,
+        from: &Currency,
+        to: &Currency,
+        when: DateTime<Utc>,
+    ) -> Result<RateObservation, MoneyError> {
+        self.rate_at_with(from, to, when, self.mode)
+    }
+
+    /// The rate from `from` to `to` as of `when` under an explicit `mode`.
+    ///
+    /// The returned observation carries the time it was observed, which
+    /// differs from `when` unless `mode` is [`AsOfMode::Exact`]. Identity
+    /// pairs resolve to a rate of `1` observed at `when`.
+    ///
+    /// # Errors
+    /// - [`MoneyError::ExchangeRateNotFound`] when neither the pair nor its
+    ///   reverse has an observation acceptable under `mode`.
+    /// - [`MoneyError::ConversionError`] when inverting a reverse observation
+    ///   overflows the active decimal backend.
+    pub fn rate_at_with(
+        &self,
+        from: &Currency,
+        to: &Currency,
+        when: DateTime<Utc>,
+        mode: AsOfMode,
+    ) -> Result<RateObservation, MoneyError> {
+        if from == to {
+            return Ok(RateObservation {
+                ts: when,
+                rate: ExchangeRate::new(from.clone(), to.clone(), decimal::one())?,
+            });
+        }
+        if let Some((ts, rate)) = self.lookup(from, to, when, mode) {
+            return Ok(RateObservation {
+                ts: *ts,
+                rate: rate.clone(),
+            });
+        }
+        if let Some((ts, rate)) = self.lookup(to, from, when, mode) {
+            return Ok(RateObservation {
+                ts: *ts,
+                rate: rate.try_inverse()?,
+            });
+        }
+        Err(MoneyError::ExchangeRateNotFound {
+            from: from.clone(),
+            to: to.clone(),
+        })
+    }
+
+    /// Convert `money` into `target` at the rate valid at `when`, rounding
+    /// with [`RoundingStrategy::MidpointAwayFromZero`].
+    ///
+    /// # Errors
+    /// Propagates errors from [`RateHistory::rate_at`] and
+    /// [`Money::try_convert_with`].
+    pub fn convert_at(
+        &self,
+        money: &Money,
+        target: &Currency,
+        when: DateTime<Utc>,
+    ) -> Result<Money, MoneyError> {
+        self.convert_at_with(money, target, when, RoundingStrategy::MidpointAwayFromZero)
+    }
+
+    /// Convert `money` into `target` at the rate valid at `when` with an
+    /// explicit rounding strategy.
+    ///
+    /// # Errors
+    /// Propagates errors from [`RateHistory::rate_at`] and
+    /// [`Money::try_convert_with`].
+    pub fn convert_at_with(
+        &self,
+        money: &Money,
+        target: &Currency,
+        when: DateTime<Utc>,
+        rounding: RoundingStrategy,
+    ) -> Result<Money, MoneyError> {
+        let observation = self.rate_at(money.currency(), target, when)?;
+        money.try_convert_with(&observation.rate, rounding)
+    }
+
+    /// View of the history at `when`, usable wherever a [`RateSource`] is
+    /// expected.
+    #[must_use]
+    pub const fn at(&self, when: DateTime<Utc>) -> HistoricalRates<'_> {
+        HistoricalRates {
+            history: self,
+            when,
+        }
+    }
+
+    /// Export every observation as a `DataFrame`, in the order of
+    /// [`RateHistory::observations`].
+    ///
+    /// Columns are `ts` followed by the rate's columns prefixed with `rate.`.
+    ///
+    /// # Errors
+    /// Returns a Polars error if the `DataFrame` cannot be built.
+    #[cfg(feature = "dataframe")]
+    pub fn to_dataframe(&self) -> polars::prelude::PolarsResult<polars::prelude::DataFrame> {
+        use paft_utils::dataframe::ToDataFrameVec;
+
+        self.observations().to_dataframe()
+    }
+
+    fn lookup(
+        &self,
+        from: &Currency,
+        to: &Currency,
+        when: DateTime<Utc>,
+        mode: AsOfMode,
+    ) -> Option<(&DateTime<Utc>, &ExchangeRate)> {
+        let series = self.series.get(&(from.clone(), to.clone()))?;
+        let before = || series.range(..=when).next_back();
+        match mode {
+            AsOfMode::Exact => series.get_key_value(&when),
+            AsOfMode::LastKnown => before(),
+            AsOfMode::Nearest => {
+                let after = series
+                    .range((Bound::Excluded(when), Bound::Unbounded))
+                    .next();
+                match (before(), after) {
+                    (Some(before), Some(after)) => {
+                        if after.0.signed_duration_since(when)
+                            < when.signed_duration_since(*before.0)
+                        {
+                            Some(after)
+                        } else {
+                            Some(before)
+                        }
+                    }
+                    (before, after) => before.or(after),
+                }
+            }
+        }
+    }
+}
+
+/// A [`RateHistory`] pinned to one instant, as returned by [`RateHistory::at`].
+#[derive(Debug, Clone, Copy)]
+pub struct HistoricalRates<'a> {
+    history: &'a RateHistory,
+    when: DateTime<Utc>,
+}
+
+impl HistoricalRates<'_> {
+    /// The instant rates are looked up at.
+    #[must_use]
+    pub const fn when(&self) -> DateTime<Utc> {
+        self.when
+    }
+}
+
+impl RateSource for HistoricalRates<'_> {
+    fn exchange_rate(&self, from: &Currency, to: &Currency) -> Result<ExchangeRate, MoneyError> {
+        Ok(self.history.rate_at(from, to, self.when)?.rate)
+    }
+}
@@ -0,0 +1,87 @@
+//! Rounding [`Money`] to increments coarser than its minor unit.
+
+use crate::currency_utils::CurrencyRegistry;
+use crate::decimal::{self, Decimal, RoundingStrategy};
+use crate::error::MoneyError;
+use crate::exact::{checked_div_decimal, checked_mul_decimal, decimal_from_scaled_units};
+use crate::money::Money;
+
+impl Money {
+    /// Round to the nearest multiple of `increment` using `strategy`.
+    ///
+    /// `increment` must be a positive multiple of this value's minor unit, so
+    /// the result stays exactly representable. Use it for settlement
+    /// conventions such as CHF 0.05 or whole-unit amounts.
+    ///
+    /// ```rust
+    /// # use paft_decimal::{Decimal, RoundingStrategy};
+    /// # use paft_money::{Currency, IsoCurrency, Money};
+    /// let total = Money::from_canonical_str("12.37", Currency::Iso(IsoCurrency::CHF)).unwrap();
+    /// let increment: Decimal = "0.05".parse().unwrap();
+    /// let rounded = total
+    ///     .round_to_increment(&increment, RoundingStrategy::MidpointAwayFromZero)
+    ///     .unwrap();
+    /// assert_eq!(rounded.format(), "12.35 CHF");
+    /// ```
+    ///
+    /// # Errors
+    /// - [`MoneyError::InvalidRoundingIncrement`] when `increment` is not a
+    ///   positive multiple of the minor unit.
+    /// - [`MoneyError::ConversionError`] when the rounded amount overflows
+    ///   the decimal backend.
+    pub fn round_to_increment(
+        &self,
+        increment: &Decimal,
+        strategy: RoundingStrategy,
+    ) -> Result<Self, MoneyError> {
+        let minor_units = self.minor_units();
+        let on_scale = decimal::round_dp_with_strategy(
+            increment,
+            u32::from(minor_units),
+            RoundingStrategy::ToZero,
+        );
+        if *increment <= decimal::zero() || on_scale != *increment {
+            return Err(MoneyError::InvalidRoundingIncrement {
+                increment: decimal::clone_decimal(increment),
+                minor_units,
+            });
+        }
+        let steps = checked_div_decimal(&self.amount(), increment)?;
+        let steps = decimal::round_dp_with_strategy(&steps, 0, strategy);
+        let rounded = checked_mul_decimal(&steps, increment)?;
+        Ok(Self::from_rounded_parts(
+            &rounded,
+            self.currency().clone(),
+            minor_units,
+        ))
+    }
+
+    /// Round to the currency's cash increment from the global registry, such
+    /// as CHF 0.05 or whole SEK, using `strategy`.
+    ///
+    /// Currencies without a cash increment are returned unchanged.
+    ///
+    /// # Errors
+    /// See [`Money::round_to_increment`].
+    pub fn round_to_cash(&self, strategy: RoundingStrategy) -> Result<Self, MoneyError> {
+        self.round_to_cash_in(CurrencyRegistry::global(), strategy)
+    }
+
+    /// Like [`Money::round_to_cash`], reading the cash increment from
+    /// `registry`.
+    ///
+    /// # Errors
+    /// See [`Money::round_to_increment`].
+    pub fn round_to_cash_in(
+        &self,
+        registry: &CurrencyRegistry,
+        strategy: RoundingStrategy,
+    ) -> Result<Self, MoneyError> {
+        let Some(increment) = registry.cash_increment(self.currency()) else {
+            return Ok(self.clone());
+        };
+        let increment =
+            decimal_from_scaled_units(i128::from(increment.get()), u32::from(self.minor_units()))?;
+        self.round_to_increment(&increment, strategy)
+    }
+}
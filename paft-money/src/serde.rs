@@ -0,0 +1,260 @@
+//! Opt-in serde adapters that encode amounts as integers.
+//!
+//! [`Money`], [`Price`], and [`MonetaryAmount`] serialize their amount as a
+//! canonical decimal string by default. Schemas that carry integers instead
+//! can pick an adapter per field with `#[serde(with = "...")]`:
+//!
+//! - [`minor_units`]: `{"minor": 12345, "currency": "USD"}`, counted in the
+//!   currency's minor units (its ISO exponent or registered metadata).
+//! - [`scaled_units`]: `{"units": 123450, "scale": 4, "currency": "USD"}`,
+//!   with the scale carried in the payload.
+//!
+//! Both encode the integer as an `i64`; amounts that do not fit fail with
+//! [`MoneyError::UnitsOverflow`]. Deserialized [`Money`] is validated like
+//! [`Money::new_exact`], so payloads with more precision than the currency
+//! allows are rejected rather than rounded.
+//!
+//! ```rust
+//! use paft_money::{Currency, IsoCurrency, Money, Price};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Fill {
+//!     #[serde(with = "paft_money::serde::minor_units")]
+//!     notional: Money,
+//!     #[serde(with = "paft_money::serde::scaled_units")]
+//!     price: Price,
+//! }
+//!
+//! let usd = Currency::Iso(IsoCurrency::USD);
+//! let fill = Fill {
+//!     notional: Money::from_canonical_str("123.45", usd.clone()).unwrap(),
+//!     price: Price::from_canonical_str("12.3450", usd).unwrap(),
+//! };
+//! let json = serde_json::to_string(&fill).unwrap();
+//! assert_eq!(
+//!     json,
+//!     r#"{"notional":{"minor":12345,"currency":"USD"},"price":{"units":123450,"scale":4,"currency":"USD"}}"#
+//! );
+//! let back: Fill = serde_json::from_str(&json).unwrap();
+//! assert_eq!(back.notional, fill.notional);
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::amount::MonetaryAmount;
+use crate::currency::Currency;
+use crate::decimal::{self, Decimal};
+use crate::error::MoneyError;
+use crate::exact::{CurrencyAmount, copy_decimal, decimal_from_scaled_units};
+use crate::money::Money;
+use crate::price::Price;
+
+mod private {
+    use super::{Currency, CurrencyAmount, Decimal, MoneyError};
+
+    pub trait Units: CurrencyAmount + Sized {
+        /// Scale used by [`super::scaled_units`] when serializing.
+        fn natural_scale(&self) -> u32;
+
+        fn from_parts(amount: Decimal, currency: Currency) -> Result<Self, MoneyError>;
+    }
+}
+
+/// Amount types the adapters in this module accept: [`Money`], [`Price`],
+/// and [`MonetaryAmount`].
+pub trait UnitsAmount: private::Units {}
+
+impl private::Units for Money {
+    fn natural_scale(&self) -> u32 {
+        u32::from(self.minor_units())
+    }
+
+    fn from_parts(amount: Decimal, currency: Currency) -> Result<Self, MoneyError> {
+        Self::new_exact(amount, currency)
+    }
+}
+
+impl UnitsAmount for Money {}
+
+impl private::Units for Price {
+    fn natural_scale(&self) -> u32 {
+        fractional_scale(self.raw_amount())
+    }
+
+    fn from_parts(amount: Decimal, currency: Currency) -> Result<Self, MoneyError> {
+        Ok(Self::new(amount, currency))
+    }
+}
+
+impl UnitsAmount for Price {}
+
+impl private::Units for MonetaryAmount {
+    fn natural_scale(&self) -> u32 {
+        fractional_scale(self.raw_amount())
+    }
+
+    fn from_parts(amount: Decimal, currency: Currency) -> Result<Self, MoneyError> {
+        Ok(Self::new(amount, currency))
+    }
+}
+
+impl UnitsAmount for MonetaryAmount {}
+
+fn fractional_scale(amount: &Decimal) -> u32 {
+    u32::try_from(decimal::fractional_digit_count(amount).max(0)).unwrap_or(u32::MAX)
+}
+
+/// Encodes `amount` as `i64` units at `scale`.
+fn to_units(amount: &Decimal, currency: &Currency, scale: u32) -> Result<i64, MoneyError> {
+    let Some(units) = decimal::try_to_scaled_units(amount, scale) else {
+        let actual_scale = fractional_scale(amount);
+        if actual_scale > scale {
+            return Err(MoneyError::PrecisionExceeded {
+                currency_code: currency.code().to_string(),
+                max_scale: scale,
+                actual_scale,
+            });
+        }
+        return Err(MoneyError::UnitsOverflow {
+            amount: copy_decimal(amount),
+            scale,
+        });
+    };
+    i64::try_from(units).map_err(|_| MoneyError::UnitsOverflow {
+        amount: copy_decimal(amount),
+        scale,
+    })
+}
+
+fn currency_scale(currency: &Currency) -> Result<u32, MoneyError> {
+    currency.decimal_places().map(u32::from)
+}
+
+/// Serde adapter encoding amounts as integer minor units of their currency:
+/// `{"minor": 12345, "currency": "USD"}` for USD 123.45.
+///
+/// Serializing fails with [`MoneyError::PrecisionExceeded`] when a [`Price`]
+/// or [`MonetaryAmount`] has more fractional digits than the currency's
+/// minor units, and with [`MoneyError::UnitsOverflow`] when the count does
+/// not fit in `i64`.
+pub mod minor_units {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{MinorShadow, MinorWire, UnitsAmount, currency_scale, to_units};
+    use crate::exact::decimal_from_scaled_units;
+
+    /// Serializes an amount as integer minor units and its currency.
+    ///
+    /// # Errors
+    /// Returns the serializer error, or a custom error when the currency has
+    /// no known scale or the amount cannot be encoded (see the module docs).
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: UnitsAmount,
+        S: Serializer,
+    {
+        let currency = value.raw_currency();
+        let minor = currency_scale(currency)
+            .and_then(|scale| to_units(value.raw_amount(), currency, scale))
+            .map_err(serde::ser::Error::custom)?;
+        MinorWire { minor, currency }.serialize(serializer)
+    }
+
+    /// Deserializes an amount from integer minor units and its currency.
+    ///
+    /// # Errors
+    /// Returns the deserializer error, or a custom error when the currency
+    /// has no known scale or the value is rejected by its constructor.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: UnitsAmount,
+        D: Deserializer<'de>,
+    {
+        let shadow = MinorShadow::deserialize(deserializer)?;
+        currency_scale(&shadow.currency)
+            .and_then(|scale| decimal_from_scaled_units(i128::from(shadow.minor), scale))
+            .and_then(|amount| T::from_parts(amount, shadow.currency))
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Serde adapter encoding amounts as integer units at an explicit scale:
+/// `{"units": 123450, "scale": 4, "currency": "USD"}` for USD 12.3450.
+///
+/// [`Money`] is written at its minor-unit scale; [`Price`] and
+/// [`MonetaryAmount`] keep the fractional digits they carry, so trailing
+/// zeros survive a round trip. Serializing fails with
+/// [`MoneyError::UnitsOverflow`] when the units do not fit in `i64`.
+pub mod scaled_units {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{ScaledShadow, ScaledWire, UnitsAmount, decimal_from_scaled_units, to_units};
+
+    /// Serializes an amount as integer units, their scale, and the currency.
+    ///
+    /// # Errors
+    /// Returns the serializer error, or a custom error when the units do not
+    /// fit in `i64`.
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: UnitsAmount,
+        S: Serializer,
+    {
+        let currency = value.raw_currency();
+        let scale = value.natural_scale();
+        let units =
+            to_units(value.raw_amount(), currency, scale).map_err(serde::ser::Error::custom)?;
+        ScaledWire {
+            units,
+            scale,
+            currency,
+        }
+        .serialize(serializer)
+    }
+
+    /// Deserializes an amount from integer units, their scale, and the
+    /// currency.
+    ///
+    /// # Errors
+    /// Returns the deserializer error, or a custom error when the decimal
+    /// backend cannot represent the value or its constructor rejects it.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: UnitsAmount,
+        D: Deserializer<'de>,
+    {
+        let shadow = ScaledShadow::deserialize(deserializer)?;
+        decimal_from_scaled_units(i128::from(shadow.units), shadow.scale)
+            .and_then(|amount| T::from_parts(amount, shadow.currency))
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Serialize)]
+struct MinorWire<'a> {
+    minor: i64,
+    currency: &'a Currency,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MinorShadow {
+    minor: i64,
+    currency: Currency,
+}
+
+#[derive(Serialize)]
+struct ScaledWire<'a> {
+    units: i64,
+    scale: u32,
+    currency: &'a Currency,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ScaledShadow {
+    units: i64,
+    scale: u32,
+    currency: Currency,
+}
@@ -0,0 +1,147 @@
+use iso_currency::Currency as IsoCurrency;
+use paft_decimal::Decimal;
+use paft_money::{AllocationStrategy, Currency, Money, MoneyError};
+
+fn money(amount: &str, code: IsoCurrency) -> Money {
+    Money::from_canonical_str(amount, Currency::Iso(code)).unwrap()
+}
+
+fn ratios(values: &[i64]) -> Vec<Decimal> {
+    values.iter().copied().map(Decimal::from).collect()
+}
+
+fn formatted(parts: &[Money]) -> Vec<String> {
+    parts.iter().map(Money::format).collect()
+}
+
+fn sum(parts: &[Money]) -> Money {
+    parts
+        .iter()
+        .skip(1)
+        .fold(parts[0].clone(), |acc, part| acc.try_add(part).unwrap())
+}
+
+#[test]
+fn largest_remainder_gives_leftover_to_biggest_fractions() {
+    let total = money("10.00", IsoCurrency::USD);
+    // Exact shares in cents: 142.85.., 285.71.., 571.42..; the two leftover
+    // cents go to the first two parts, which have the largest remainders.
+    let parts = total.allocate(&ratios(&[1, 2, 4])).unwrap();
+    assert_eq!(formatted(&parts), ["1.43 USD", "2.86 USD", "5.71 USD"]);
+    assert_eq!(sum(&parts), total);
+}
+
+#[test]
+fn round_robin_gives_leftover_in_order() {
+    let total = money("10.00", IsoCurrency::USD);
+    let parts = total
+        .allocate_with(&ratios(&[1, 2, 4]), AllocationStrategy::RoundRobin)
+        .unwrap();
+    assert_eq!(formatted(&parts), ["1.43 USD", "2.86 USD", "5.71 USD"]);
+
+    let parts = money("0.05", IsoCurrency::USD)
+        .allocate_with(&ratios(&[1, 1, 1, 1]), AllocationStrategy::RoundRobin)
+        .unwrap();
+    assert_eq!(
+        formatted(&parts),
+        ["0.02 USD", "0.01 USD", "0.01 USD", "0.01 USD"]
+    );
+}
+
+#[test]
+fn strategies_differ_when_remainders_are_uneven() {
+    let total = money("0.10", IsoCurrency::USD);
+    // Exact shares in cents: 1.4285.., 2.8571.., 5.7142.. -> floors 1, 2, 5.
+    let weights = ratios(&[1, 2, 4]);
+    let largest = total.allocate(&weights).unwrap();
+    let robin = total
+        .allocate_with(&weights, AllocationStrategy::RoundRobin)
+        .unwrap();
+    assert_eq!(formatted(&largest), ["0.01 USD", "0.03 USD", "0.06 USD"]);
+    assert_eq!(formatted(&robin), ["0.02 USD", "0.03 USD", "0.05 USD"]);
+    assert_eq!(sum(&largest), total);
+    assert_eq!(sum(&robin), total);
+}
+
+#[test]
+fn fractional_ratios_and_zero_weights() {
+    let total = money("100", IsoCurrency::JPY);
+    let weights = vec![
+        "0.5".parse().unwrap(),
+        Decimal::from(0),
+        "0.25".parse().unwrap(),
+        "0.25".parse().unwrap(),
+    ];
+    let parts = total.allocate(&weights).unwrap();
+    assert_eq!(formatted(&parts), ["50 JPY", "0 JPY", "25 JPY", "25 JPY"]);
+
+    let parts = money("1", IsoCurrency::JPY)
+        .allocate_with(&ratios(&[0, 1, 1]), AllocationStrategy::RoundRobin)
+        .unwrap();
+    assert_eq!(formatted(&parts), ["0 JPY", "1 JPY", "0 JPY"]);
+}
+
+#[test]
+fn negative_amounts_allocate_symmetrically() {
+    let total = money("-10.00", IsoCurrency::USD);
+    let parts = total.allocate(&ratios(&[1, 1, 1])).unwrap();
+    assert_eq!(formatted(&parts), ["-3.34 USD", "-3.33 USD", "-3.33 USD"]);
+    assert_eq!(sum(&parts), total);
+}
+
+#[test]
+fn split_is_exact_and_front_loaded() {
+    let total = money("100.00", IsoCurrency::EUR);
+    let parts = total.split(3).unwrap();
+    assert_eq!(formatted(&parts), ["33.34 EUR", "33.33 EUR", "33.33 EUR"]);
+    assert_eq!(sum(&parts), total);
+
+    let parts = money("0.01", IsoCurrency::EUR).split(4).unwrap();
+    assert_eq!(sum(&parts), money("0.01", IsoCurrency::EUR));
+    assert_eq!(parts[3], money("0", IsoCurrency::EUR));
+
+    let three_decimals = money("1.000", IsoCurrency::KWD).split(3).unwrap();
+    assert_eq!(
+        formatted(&three_decimals),
+        ["0.334 KWD", "0.333 KWD", "0.333 KWD"]
+    );
+}
+
+#[test]
+fn parts_keep_currency_and_minor_units() {
+    let total = money("7.00", IsoCurrency::GBP);
+    for part in total.split(2).unwrap() {
+        assert_eq!(part.currency(), total.currency());
+        assert_eq!(part.minor_units(), total.minor_units());
+    }
+}
+
+#[test]
+fn invalid_ratios_are_rejected() {
+    let total = money("1.00", IsoCurrency::USD);
+    assert_eq!(total.allocate(&[]), Err(MoneyError::EmptyAllocation));
+    assert_eq!(total.split(0), Err(MoneyError::EmptyAllocation));
+    assert_eq!(
+        total.allocate(&ratios(&[0, 0])),
+        Err(MoneyError::ZeroAllocationTotal)
+    );
+    assert_eq!(
+        total.allocate(&ratios(&[1, -1, 2])),
+        Err(MoneyError::NegativeAllocationRatio {
+            index: 1,
+            ratio: Decimal::from(-1),
+        })
+    );
+}
+
+#[test]
+fn allocation_strategy_serde() {
+    assert_eq!(
+        serde_json::to_string(&AllocationStrategy::LargestRemainder).unwrap(),
+        "\"largest_remainder\""
+    );
+    assert_eq!(
+        serde_json::from_str::<AllocationStrategy>("\"round_robin\"").unwrap(),
+        AllocationStrategy::RoundRobin
+    );
+}
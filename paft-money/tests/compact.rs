@@ -0,0 +1,163 @@
+#![cfg(feature = "money-formatting")]
+
+use iso_currency::Currency as IsoCurrency;
+use paft_decimal::{Decimal, RoundingStrategy};
+use paft_money::{
+    CompactFormat, CompactStyle, CompactSuffix, Currency, Locale, MAX_DECIMAL_PRECISION, Money,
+    MoneyError, Price,
+};
+
+fn decimal(value: &str) -> Decimal {
+    value.parse().unwrap()
+}
+
+fn compact(value: &str, format: &CompactFormat) -> String {
+    format.format_decimal(&decimal(value)).unwrap()
+}
+
+#[test]
+fn short_english_suffixes_scale_by_thousands() {
+    let format = CompactFormat::new(Locale::EnUs);
+    assert_eq!(compact("0", &format), "0");
+    assert_eq!(compact("12.345", &format), "12");
+    assert_eq!(compact("0.012345", &format), "0.012");
+    assert_eq!(compact("1000", &format), "1K");
+    assert_eq!(compact("1500", &format), "1.5K");
+    assert_eq!(compact("123456", &format), "123K");
+    assert_eq!(compact("2912345678901", &format), "2.9T");
+    assert_eq!(compact("-4560000", &format), "-4.6M");
+    assert_eq!(compact("1234000000000000", &format), "1,234T");
+}
+
+#[test]
+fn rounding_carries_into_the_next_suffix() {
+    let format = CompactFormat::new(Locale::EnUs);
+    assert_eq!(compact("999960", &format), "1M");
+    assert_eq!(compact("999.6", &format), "1K");
+    assert_eq!(compact("999", &format), "999");
+    assert_eq!(compact("-999999999", &format), "-1B");
+}
+
+#[test]
+fn significant_digits_and_rounding_strategy_are_configurable() {
+    let three = CompactFormat::new(Locale::EnUs).with_significant_digits(3);
+    assert_eq!(compact("1234567", &three), "1.23M");
+    assert_eq!(compact("1200000", &three), "1.2M");
+
+    let one = CompactFormat::new(Locale::EnUs).with_significant_digits(1);
+    assert_eq!(compact("1500", &one), "2K");
+    assert_eq!(compact("45678", &one), "46K");
+
+    let down = CompactFormat::new(Locale::EnUs).with_rounding(RoundingStrategy::ToZero);
+    assert_eq!(compact("1990", &down), "1.9K");
+    assert_eq!(compact("-1990", &down), "-1.9K");
+
+    let floor =
+        CompactFormat::new(Locale::EnUs).with_rounding(RoundingStrategy::ToNegativeInfinity);
+    assert_eq!(compact("-1910", &floor), "-2K");
+}
+
+#[test]
+fn long_style_uses_words() {
+    let long = CompactFormat::new(Locale::EnUs).with_style(CompactStyle::Long);
+    assert_eq!(compact("2500", &long), "2.5 thousand");
+    assert_eq!(compact("1000000", &long), "1 million");
+    assert_eq!(compact("7100000000", &long), "7.1 billion");
+}
+
+#[test]
+fn locale_suffixes_separators_and_plurals() {
+    let de = CompactFormat::new(Locale::DeCh);
+    assert_eq!(compact("123456", &de), "123'456");
+    assert_eq!(compact("1234567890", &de), "1.2\u{a0}Mrd.");
+    let de_long = de.with_style(CompactStyle::Long);
+    assert_eq!(compact("1000000", &de_long), "1 Million");
+    assert_eq!(compact("1500000", &de_long), "1.5 Millionen");
+
+    let fr = CompactFormat::new(Locale::FrFr);
+    assert_eq!(compact("1500", &fr), "1,5\u{a0}k");
+    let fr_long = fr.with_style(CompactStyle::Long);
+    assert_eq!(compact("1500000", &fr_long), "1,5 million");
+    assert_eq!(compact("2500000", &fr_long), "2,5 millions");
+
+    let pt_long = CompactFormat::new(Locale::PtBr).with_style(CompactStyle::Long);
+    assert_eq!(compact("1000000", &pt_long), "1 milhão");
+    assert_eq!(compact("3000000000", &pt_long), "3 bilhões");
+
+    let ja = CompactFormat::new(Locale::JaJp);
+    assert_eq!(compact("12345", &ja), "1.2万");
+    assert_eq!(compact("123456789", &ja), "1.2億");
+
+    let en_in = CompactFormat::new(Locale::EnIn);
+    assert_eq!(compact("150000", &en_in), "1.5L");
+    assert_eq!(compact("25000000", &en_in), "2.5Cr");
+}
+
+#[test]
+fn money_and_price_include_symbol_or_code() {
+    let format = CompactFormat::new(Locale::EnUs);
+    let cap =
+        Money::from_canonical_str("2912345678901.23", Currency::Iso(IsoCurrency::USD)).unwrap();
+    assert_eq!(cap.format_compact(&format).unwrap(), "$2.9T");
+    assert_eq!(
+        cap.format_compact(&format.clone().with_code()).unwrap(),
+        "$2.9T USD"
+    );
+    assert_eq!(
+        cap.format_compact(&format.clone().without_symbol().with_code())
+            .unwrap(),
+        "2.9T USD"
+    );
+
+    let loss = Money::from_canonical_str("-1500000", Currency::Iso(IsoCurrency::USD)).unwrap();
+    assert_eq!(loss.format_compact(&format).unwrap(), "-$1.5M");
+
+    let strike = Price::from_canonical_str("4250.5", Currency::Iso(IsoCurrency::EUR)).unwrap();
+    assert_eq!(
+        strike
+            .format_compact(&CompactFormat::new(Locale::EnEu))
+            .unwrap(),
+        "€4,3K"
+    );
+}
+
+#[test]
+fn custom_suffixes_replace_the_vocabulary() {
+    let format = CompactFormat::new(Locale::EnUs)
+        .with_suffixes([
+            CompactSuffix::new(6, " mn"),
+            CompactSuffix::new(3, " lot").with_singular(" lot!"),
+        ])
+        .unwrap();
+    assert_eq!(format.suffixes()[0].exponent(), 3);
+    assert_eq!(compact("1000", &format), "1 lot!");
+    assert_eq!(compact("2000", &format), "2 lot");
+    assert_eq!(compact("2000000000", &format), "2,000 mn");
+
+    assert!(matches!(
+        CompactFormat::new(Locale::EnUs).with_suffixes([CompactSuffix::new(0, "x")]),
+        Err(MoneyError::InvalidCompactFormat { .. })
+    ));
+    assert!(matches!(
+        CompactFormat::new(Locale::EnUs)
+            .with_suffixes([CompactSuffix::new(3, "k"), CompactSuffix::new(3, "K")]),
+        Err(MoneyError::InvalidCompactFormat { .. })
+    ));
+}
+
+#[test]
+fn invalid_significant_digits_are_rejected() {
+    let value = decimal("1");
+    assert!(matches!(
+        CompactFormat::new(Locale::EnUs)
+            .with_significant_digits(0)
+            .format_decimal(&value),
+        Err(MoneyError::InvalidCompactFormat { .. })
+    ));
+    assert!(matches!(
+        CompactFormat::new(Locale::EnUs)
+            .with_significant_digits(u32::from(MAX_DECIMAL_PRECISION) + 1)
+            .format_decimal(&value),
+        Err(MoneyError::FormatPrecisionExceeded { .. })
+    ));
+}
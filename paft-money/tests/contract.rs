@@ -0,0 +1,142 @@
+use paft_decimal::{Decimal, PositiveDecimal};
+use paft_money::{
+    ContractSpec, Currency, IsoCurrency, MoneyError, MoneyParseError, Price, Quantity,
+    QuantityAmount, QuantityUnit,
+};
+use serde_json::json;
+
+fn decimal(value: &str) -> Decimal {
+    value.parse().unwrap()
+}
+
+fn positive(value: &str) -> PositiveDecimal {
+    PositiveDecimal::new(decimal(value)).unwrap()
+}
+
+fn qty(value: &str) -> QuantityAmount {
+    QuantityAmount::from_decimal(decimal(value)).unwrap()
+}
+
+fn usd(value: &str) -> Price {
+    Price::from_canonical_str(value, Currency::Iso(IsoCurrency::USD)).unwrap()
+}
+
+#[test]
+fn notional_applies_the_contract_multiplier() {
+    let es = ContractSpec::new(positive("50"));
+    let notional = usd("5000.25")
+        .try_notional(&Quantity::contracts(qty("2")), &es)
+        .unwrap();
+    assert_eq!(notional.format(), "500025 USD");
+
+    // Shares count the underlying directly, whatever the multiplier.
+    let option = ContractSpec::new(positive("100"));
+    let contracts = option
+        .notional(&usd("1.25"), &Quantity::contracts(qty("3")))
+        .unwrap();
+    let shares = option
+        .notional(&usd("1.25"), &Quantity::shares(qty("300")))
+        .unwrap();
+    assert_eq!(contracts, shares);
+    assert_eq!(contracts.format(), "375 USD");
+
+    // Spot totals match the bare price-times-quantity product.
+    assert_eq!(
+        usd("12.5")
+            .try_notional(&Quantity::shares(qty("4")), &ContractSpec::default())
+            .unwrap(),
+        usd("12.5").try_total(&qty("4")).unwrap()
+    );
+}
+
+#[test]
+fn lots_convert_through_the_lot_size() {
+    let btc = ContractSpec::spot()
+        .with_lot_size(positive("0.001"))
+        .with_min_increment(positive("0.001"));
+    let five_lots = Quantity::lots(qty("5"));
+    assert_eq!(btc.underlying_quantity(&five_lots).unwrap(), qty("0.005"));
+    assert_eq!(
+        usd("60000")
+            .try_notional(&five_lots, &btc)
+            .unwrap()
+            .format(),
+        "300 USD"
+    );
+
+    let board_lots = ContractSpec::spot().with_lot_size(positive("100"));
+    assert_eq!(
+        board_lots
+            .convert(&Quantity::lots(qty("3")), QuantityUnit::Shares)
+            .unwrap(),
+        Quantity::shares(qty("300"))
+    );
+    assert_eq!(
+        board_lots
+            .convert(&Quantity::shares(qty("250")), QuantityUnit::Lots)
+            .unwrap(),
+        Quantity::lots(qty("2.5"))
+    );
+
+    let lots_of_futures = ContractSpec::new(positive("10")).with_lot_size(positive("5"));
+    assert_eq!(
+        lots_of_futures.underlying_per(QuantityUnit::Lots).unwrap(),
+        decimal("50")
+    );
+}
+
+#[test]
+fn validate_enforces_the_minimum_increment_in_contracts() {
+    let option = ContractSpec::new(positive("100")).with_min_increment(positive("1"));
+    assert_eq!(option.validate(&Quantity::contracts(qty("2"))), Ok(()));
+    assert_eq!(option.validate(&Quantity::shares(qty("300"))), Ok(()));
+    assert_eq!(
+        option.validate(&Quantity::shares(qty("150"))),
+        Err(MoneyError::InvalidQuantityIncrement {
+            quantity: decimal("1.5"),
+            increment: decimal("1"),
+        })
+    );
+
+    let btc = ContractSpec::spot().with_min_increment(positive("0.0001"));
+    assert_eq!(btc.validate(&Quantity::base_units(qty("0.0123"))), Ok(()));
+    let err = btc
+        .validate(&Quantity::base_units(qty("0.01234")))
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "quantity 0.01234 is not a multiple of the minimum increment 0.0001"
+    );
+
+    assert_eq!(
+        ContractSpec::spot().validate(&Quantity::shares(qty("0.333"))),
+        Ok(())
+    );
+}
+
+#[test]
+fn quantities_and_specs_serialize_with_named_units() {
+    let quantity = Quantity::contracts(qty("2.5"));
+    let value = serde_json::to_value(&quantity).unwrap();
+    assert_eq!(value, json!({"amount": "2.5", "unit": "contracts"}));
+    assert_eq!(serde_json::from_value::<Quantity>(value).unwrap(), quantity);
+    assert_eq!(quantity.to_string(), "2.5 contracts");
+
+    let spec = ContractSpec::new(positive("50"));
+    let value = serde_json::to_value(&spec).unwrap();
+    assert_eq!(value, json!({"multiplier": "50", "lot_size": "1"}));
+    assert_eq!(serde_json::from_value::<ContractSpec>(value).unwrap(), spec);
+    assert!(
+        serde_json::from_value::<ContractSpec>(json!({"multiplier": "0", "lot_size": "1"}))
+            .is_err()
+    );
+
+    assert_eq!("Base_Units".parse(), Ok(QuantityUnit::BaseUnits));
+    assert_eq!(
+        "bushels".parse::<QuantityUnit>(),
+        Err(MoneyParseError::InvalidEnumValue {
+            enum_name: "QuantityUnit",
+            value: "bushels".to_string(),
+        })
+    );
+}
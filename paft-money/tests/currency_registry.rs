@@ -0,0 +1,151 @@
+use paft_decimal::Decimal;
+use paft_money::{
+    Currency, CurrencyMetadataEntry, CurrencyRegistry, IsoCurrency, Locale, Money, MoneyError,
+    currency_metadata, set_currency_metadata,
+};
+
+fn code(code: &str) -> Currency {
+    Currency::try_from_str(code).unwrap()
+}
+
+#[test]
+fn tenants_register_conflicting_tokens_independently() {
+    let a = CurrencyRegistry::new();
+    let b = CurrencyRegistry::new();
+    a.set_metadata("RGA", "Tenant A points", 0, "pt", false, Locale::EnUs)
+        .unwrap();
+    b.set_metadata("RGA", "Tenant B credits", 3, "cr", true, Locale::EnEu)
+        .unwrap();
+
+    let rga = code("RGA");
+    assert_eq!(a.decimal_places(&rga), Ok(0));
+    assert_eq!(b.decimal_places(&rga), Ok(3));
+    assert_eq!(a.full_name(&rga), "Tenant A points");
+    assert_eq!(b.full_name(&rga), "Tenant B credits");
+    assert_eq!(currency_metadata("RGA"), None);
+    assert!(matches!(
+        Money::zero(rga.clone()),
+        Err(MoneyError::MetadataNotFound { .. })
+    ));
+
+    let amount = || "12.3456".parse::<Decimal>().unwrap();
+    let in_a = Money::new_in(amount(), rga.clone(), &a).unwrap();
+    let in_b = Money::new_in(amount(), rga.clone(), &b).unwrap();
+    assert_eq!(in_a.format(), "12 RGA");
+    assert_eq!(in_b.format(), "12.346 RGA");
+    assert_eq!(
+        in_a.try_add(&in_b),
+        Err(MoneyError::MinorUnitMismatch {
+            currency: rga,
+            expected_scale: 0,
+            found_scale: 3,
+        })
+    );
+}
+
+#[test]
+fn constructors_resolve_scale_in_the_given_registry() {
+    let tenant = CurrencyRegistry::new();
+    tenant
+        .set_metadata("RGB", "Ledger unit", 4, "LU", true, Locale::EnUs)
+        .unwrap();
+    let rgb = code("RGB");
+
+    assert_eq!(
+        Money::from_canonical_str_in("1.2345", rgb.clone(), &tenant)
+            .unwrap()
+            .minor_units(),
+        4
+    );
+    assert!(matches!(
+        Money::from_canonical_str_in("1.23456", rgb.clone(), &tenant),
+        Err(MoneyError::PrecisionExceeded { max_scale: 4, .. })
+    ));
+    assert_eq!(
+        Money::from_minor_units_in(12_345, rgb.clone(), &tenant)
+            .unwrap()
+            .format(),
+        "1.2345 RGB"
+    );
+    assert_eq!(
+        Money::zero_in(rgb.clone(), &tenant).unwrap().minor_units(),
+        4
+    );
+    assert!(matches!(
+        Money::zero_in(rgb, &CurrencyRegistry::new()),
+        Err(MoneyError::MetadataNotFound { .. })
+    ));
+
+    // ISO exponents and built-in metadata are shared by every registry.
+    let empty = CurrencyRegistry::new();
+    assert_eq!(
+        empty.decimal_places(&Currency::Iso(IsoCurrency::JPY)),
+        Ok(0)
+    );
+    assert_eq!(empty.decimal_places(&Currency::BTC), Ok(8));
+    assert!(
+        empty
+            .set_metadata("JPY", "Yen", 2, "¥", true, Locale::JaJp)
+            .is_err()
+    );
+}
+
+#[test]
+fn global_registry_backs_the_free_functions() {
+    set_currency_metadata("RGC", "Global token", 2, "G", true, Locale::EnUs).unwrap();
+    assert_eq!(
+        CurrencyRegistry::global().metadata("RGC"),
+        currency_metadata("RGC")
+    );
+    assert!(CurrencyRegistry::new().metadata("RGC").is_none());
+
+    // Cloning snapshots the custom entries into an independent registry.
+    let snapshot = CurrencyRegistry::global().clone();
+    assert_eq!(snapshot.metadata("RGC").unwrap().minor_units, 2);
+    snapshot.clear_metadata("RGC");
+    assert!(snapshot.metadata("RGC").is_none());
+    assert!(currency_metadata("RGC").is_some());
+}
+
+#[test]
+fn batches_load_into_one_registry() {
+    let tenant = CurrencyRegistry::new();
+    let entry = CurrencyMetadataEntry {
+        code: "RGD".to_string(),
+        full_name: "Batch token".to_string(),
+        minor_units: 6,
+        symbol: "B".to_string(),
+        symbol_first: true,
+        default_locale: Locale::EnUs,
+        cash_increment: None,
+    };
+    assert_eq!(tenant.load(std::slice::from_ref(&entry), false), Ok(1));
+    assert!(tenant.export().contains(&entry));
+    assert!(currency_metadata("RGD").is_none());
+}
+
+#[cfg(feature = "money-formatting")]
+#[test]
+fn formatting_reads_symbols_from_the_given_registry() {
+    let tenant = CurrencyRegistry::new();
+    tenant
+        .set_metadata("RGE", "Tenant euro", 2, "€T", false, Locale::EnEu)
+        .unwrap();
+    let rge = code("RGE");
+    let money = Money::from_canonical_str_in("1234.5", rge.clone(), &tenant).unwrap();
+
+    assert_eq!(
+        money
+            .localized(Locale::EnEu)
+            .with_registry(&tenant)
+            .into_string()
+            .unwrap(),
+        "1.234,50 €T"
+    );
+    assert_eq!(
+        money.localized(Locale::EnEu).into_string().unwrap(),
+        "RGE 1.234,50"
+    );
+    assert_eq!(tenant.default_locale(&rge), Locale::EnEu);
+    assert!(!tenant.symbol_first(&rge));
+}
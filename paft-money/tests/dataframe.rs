@@ -62,3 +62,29 @@ fn monetary_amount_to_dataframe() {
     let df = amount.to_dataframe().unwrap();
     assert_eq!(df.height(), 1);
 }
+
+#[test]
+fn rate_history_to_dataframe() {
+    use chrono::DateTime;
+    use paft_money::RateHistory;
+
+    let mut history = RateHistory::new();
+    for (secs, rate) in [(1_704_153_600, 11), (1_704_240_000, 12)] {
+        history.insert(
+            DateTime::from_timestamp(secs, 0).unwrap(),
+            ExchangeRate::new(
+                Currency::Iso(IsoCurrency::EUR),
+                Currency::Iso(IsoCurrency::USD),
+                Decimal::from(rate) / Decimal::from(10),
+            )
+            .unwrap(),
+        );
+    }
+
+    let df = history.to_dataframe().unwrap();
+    assert_eq!(df.height(), 2);
+    let columns = df.get_column_names();
+    for name in ["ts", "rate.from", "rate.to", "rate.rate"] {
+        assert!(columns.iter().any(|c| c.as_str() == name), "missing {name}");
+    }
+}
@@ -0,0 +1,215 @@
+use chrono::NaiveDate;
+use paft_decimal::{Decimal, RoundingStrategy};
+use paft_money::{
+    Compounding, Currency, DayCount, IsoCurrency, Money, MoneyError, MoneyParseError,
+};
+
+const fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).expect("valid test date")
+}
+
+fn money(amount: &str, code: IsoCurrency) -> Money {
+    Money::from_canonical_str(amount, Currency::Iso(code)).unwrap()
+}
+
+fn decimal(value: &str) -> Decimal {
+    value.parse().unwrap()
+}
+
+#[test]
+fn actual_conventions_count_calendar_days() {
+    let (start, end) = (date(2003, 11, 1), date(2004, 5, 1));
+    assert_eq!(DayCount::Act360.days(start, end), 182);
+    assert_eq!(
+        DayCount::Act360.year_fraction(start, end),
+        Decimal::from(182) / Decimal::from(360)
+    );
+    assert_eq!(
+        DayCount::Act365Fixed.year_fraction(start, end),
+        Decimal::from(182) / Decimal::from(365)
+    );
+    // ISDA example: 61 days in 2003 over 365 plus 121 days in 2004 over 366.
+    assert_eq!(
+        DayCount::ActActIsda.year_fraction(start, end),
+        Decimal::from(61 * 366 + 121 * 365) / Decimal::from(365 * 366)
+    );
+    assert_eq!(
+        DayCount::ActActIsda.year_fraction(date(2024, 1, 1), date(2025, 1, 1)),
+        Decimal::from(1)
+    );
+    assert_eq!(
+        DayCount::ActActIsda.year_fraction(end, start),
+        -DayCount::ActActIsda.year_fraction(start, end)
+    );
+}
+
+#[test]
+fn thirty_360_variants_adjust_month_ends() {
+    let cases = [
+        // (start, end, 30/360, 30/360 US, 30E/360, 30E/360 ISDA)
+        (date(2024, 1, 31), date(2024, 3, 31), 60, 60, 60, 60),
+        (date(2024, 2, 29), date(2024, 3, 31), 32, 30, 31, 30),
+        (date(2023, 2, 28), date(2024, 2, 29), 361, 360, 361, 360),
+        (date(2024, 1, 15), date(2024, 3, 31), 76, 76, 75, 75),
+        (date(2024, 1, 1), date(2025, 1, 1), 360, 360, 360, 360),
+    ];
+    for (start, end, bond, us, euro, euro_isda) in cases {
+        assert_eq!(DayCount::Thirty360.days(start, end), bond, "{start}..{end}");
+        assert_eq!(DayCount::Thirty360Us.days(start, end), us, "{start}..{end}");
+        assert_eq!(
+            DayCount::ThirtyE360.days(start, end),
+            euro,
+            "{start}..{end}"
+        );
+        assert_eq!(
+            DayCount::ThirtyE360Isda.days(start, end),
+            euro_isda,
+            "{start}..{end}"
+        );
+    }
+    assert_eq!(
+        DayCount::Thirty360.year_fraction(date(2024, 1, 15), date(2024, 7, 15)),
+        decimal("0.5")
+    );
+}
+
+#[test]
+fn simple_interest_rounds_exact_midpoints_per_strategy() {
+    let principal = money("100", IsoCurrency::USD);
+    let rate = decimal("0.045");
+    let accrue = |rounding| {
+        principal
+            .simple_interest(
+                &rate,
+                date(2024, 1, 1),
+                date(2024, 1, 11),
+                DayCount::Act360,
+                rounding,
+            )
+            .unwrap()
+    };
+    // 100 * 0.045 * 10 / 360 = 0.125 exactly.
+    assert_eq!(
+        accrue(RoundingStrategy::MidpointAwayFromZero),
+        money("0.13", IsoCurrency::USD)
+    );
+    assert_eq!(
+        accrue(RoundingStrategy::MidpointNearestEven),
+        money("0.12", IsoCurrency::USD)
+    );
+
+    let yen = money("1000000", IsoCurrency::JPY)
+        .simple_interest(
+            &decimal("0.001"),
+            date(2024, 1, 1),
+            date(2024, 12, 31),
+            DayCount::Act365Fixed,
+            RoundingStrategy::ToZero,
+        )
+        .unwrap();
+    assert_eq!(yen, money("1000", IsoCurrency::JPY));
+}
+
+#[test]
+fn compound_interest_compounds_whole_periods_and_accrues_the_stub() {
+    let principal = money("1000", IsoCurrency::USD);
+    let half_up = RoundingStrategy::MidpointAwayFromZero;
+
+    let monthly = principal
+        .compound_interest(
+            &decimal("0.12"),
+            date(2023, 1, 1),
+            date(2024, 1, 1),
+            DayCount::Thirty360,
+            Compounding::Monthly,
+            half_up,
+        )
+        .unwrap();
+    // 1000 * 1.01^12 - 1000 = 126.825...
+    assert_eq!(monthly, money("126.83", IsoCurrency::USD));
+
+    let stub = principal
+        .compound_interest(
+            &decimal("0.06"),
+            date(2024, 1, 1),
+            date(2024, 5, 15),
+            DayCount::Act365Fixed,
+            Compounding::Quarterly,
+            half_up,
+        )
+        .unwrap();
+    // One quarter of 365/4 days, then 175/365 of a quarter at 1.5%:
+    // 1000 * 1.015 * (1 + 0.015 * 175 / 365) - 1000 = 22.2997...
+    assert_eq!(stub, money("22.30", IsoCurrency::USD));
+
+    let same_day = principal
+        .compound_interest(
+            &decimal("0.06"),
+            date(2024, 1, 1),
+            date(2024, 1, 1),
+            DayCount::ActActIsda,
+            Compounding::Annual,
+            half_up,
+        )
+        .unwrap();
+    assert_eq!(same_day, money("0", IsoCurrency::USD));
+}
+
+#[test]
+fn accrual_periods_must_not_run_backwards() {
+    let principal = money("1000", IsoCurrency::EUR);
+    let err = principal
+        .simple_interest(
+            &decimal("0.01"),
+            date(2024, 2, 1),
+            date(2024, 1, 1),
+            DayCount::Act360,
+            RoundingStrategy::ToZero,
+        )
+        .unwrap_err();
+    assert_eq!(
+        err,
+        MoneyError::InvalidAccrualPeriod {
+            start: date(2024, 2, 1),
+            end: date(2024, 1, 1),
+        }
+    );
+    assert!(matches!(
+        principal.compound_interest(
+            &decimal("0.01"),
+            date(2024, 2, 1),
+            date(2024, 1, 1),
+            DayCount::Act360,
+            Compounding::Annual,
+            RoundingStrategy::ToZero,
+        ),
+        Err(MoneyError::InvalidAccrualPeriod { .. })
+    ));
+}
+
+#[test]
+fn day_counts_use_market_names() {
+    assert_eq!("act/365f".parse::<DayCount>(), Ok(DayCount::Act365Fixed));
+    assert_eq!("30E/360 ISDA".parse(), Ok(DayCount::ThirtyE360Isda));
+    assert_eq!(
+        "ACT/364".parse::<DayCount>(),
+        Err(MoneyParseError::InvalidEnumValue {
+            enum_name: "DayCount",
+            value: "ACT/364".to_string(),
+        })
+    );
+    assert_eq!(DayCount::ActActIsda.to_string(), "ACT/ACT ISDA");
+    assert_eq!(
+        serde_json::to_string(&DayCount::Thirty360Us).unwrap(),
+        r#""30/360 US""#
+    );
+    assert_eq!(
+        serde_json::from_str::<DayCount>(r#""ACT/360""#).unwrap(),
+        DayCount::Act360
+    );
+    assert_eq!(
+        serde_json::to_string(&Compounding::SemiAnnual).unwrap(),
+        r#""semi_annual""#
+    );
+    assert_eq!(Compounding::Monthly.periods_per_year(), 12);
+}
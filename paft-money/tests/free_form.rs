@@ -0,0 +1,216 @@
+#![cfg(feature = "money-formatting")]
+
+use iso_currency::Currency as IsoCurrency;
+use paft_money::{Currency, Locale, Money, MoneyError, MoneyParser, set_currency_metadata};
+
+const fn iso(code: IsoCurrency) -> Currency {
+    Currency::Iso(code)
+}
+
+fn money(amount: &str, code: IsoCurrency) -> Money {
+    Money::from_canonical_str(amount, iso(code)).unwrap()
+}
+
+#[test]
+fn detects_symbols_and_codes_on_either_side() {
+    assert_eq!(
+        Money::parse_free_form("$1,234.56").unwrap(),
+        money("1234.56", IsoCurrency::USD)
+    );
+    assert_eq!(
+        Money::parse_free_form("1.234,56 €").unwrap(),
+        money("1234.56", IsoCurrency::EUR)
+    );
+    assert_eq!(
+        Money::parse_free_form("USD 12").unwrap(),
+        money("12", IsoCurrency::USD)
+    );
+    assert_eq!(
+        Money::parse_free_form("CHF 1'000").unwrap(),
+        money("1000", IsoCurrency::CHF)
+    );
+    assert_eq!(
+        Money::parse_free_form("12.5 gbp").unwrap(),
+        money("12.5", IsoCurrency::GBP)
+    );
+    assert_eq!(
+        Money::parse_free_form("£3").unwrap(),
+        money("3", IsoCurrency::GBP)
+    );
+    assert_eq!(
+        Money::parse_free_form("0.5 BTC").unwrap().currency().code(),
+        "BTC"
+    );
+}
+
+#[test]
+fn parentheses_and_signs_mark_negatives() {
+    let usd = MoneyParser::new().with_default_currency(iso(IsoCurrency::USD));
+    assert_eq!(
+        usd.parse("(45.00)").unwrap(),
+        money("-45", IsoCurrency::USD)
+    );
+    assert_eq!(
+        usd.parse("($45.00)").unwrap(),
+        money("-45", IsoCurrency::USD)
+    );
+    assert_eq!(
+        usd.parse("$(45.00)").unwrap(),
+        money("-45", IsoCurrency::USD)
+    );
+    assert_eq!(usd.parse("-$5").unwrap(), money("-5", IsoCurrency::USD));
+    assert_eq!(usd.parse("$-5").unwrap(), money("-5", IsoCurrency::USD));
+    assert_eq!(
+        usd.parse("5 \u{2212}").unwrap(),
+        money("-5", IsoCurrency::USD)
+    );
+    assert_eq!(usd.parse("+5").unwrap(), money("5", IsoCurrency::USD));
+    assert_eq!(usd.parse("-0.00").unwrap(), money("0", IsoCurrency::USD));
+
+    for malformed in ["(-45)", "--5", "(45", "45)", "-5+"] {
+        assert_eq!(
+            usd.parse(malformed),
+            Err(MoneyError::InvalidAmountFormat),
+            "{malformed}"
+        );
+    }
+}
+
+#[test]
+fn infers_separators_from_the_number() {
+    let usd = MoneyParser::new().with_default_currency(iso(IsoCurrency::USD));
+    assert_eq!(
+        usd.parse("1 234 567,89").unwrap(),
+        money("1234567.89", IsoCurrency::USD)
+    );
+    assert_eq!(
+        usd.parse("1\u{202f}234,5").unwrap(),
+        money("1234.5", IsoCurrency::USD)
+    );
+    assert_eq!(usd.parse("12,5").unwrap(), money("12.5", IsoCurrency::USD));
+    assert_eq!(
+        usd.parse("1.234.567").unwrap(),
+        money("1234567", IsoCurrency::USD)
+    );
+    assert_eq!(
+        usd.parse("12,34,567.5").unwrap(),
+        money("1234567.5", IsoCurrency::USD)
+    );
+    assert_eq!(
+        usd.parse("0,125").unwrap_err(),
+        MoneyError::ScaleTooLarge {
+            digits: 3,
+            exponent: 2
+        }
+    );
+
+    assert_eq!(usd.parse("1,23,4"), Err(MoneyError::InvalidGrouping));
+    assert_eq!(usd.parse("1,2345.6"), Err(MoneyError::InvalidGrouping));
+    assert_eq!(usd.parse("1.234.5"), Err(MoneyError::InvalidGrouping));
+    assert_eq!(usd.parse("1,234.5,6"), Err(MoneyError::InvalidAmountFormat));
+    assert_eq!(usd.parse("1 234"), Ok(money("1234", IsoCurrency::USD)));
+    assert_eq!(usd.parse("1_234"), Err(MoneyError::InvalidAmountFormat));
+}
+
+#[test]
+fn three_trailing_digits_use_currency_scale_then_locale() {
+    // Two-decimal currencies cannot carry three fraction digits.
+    assert_eq!(
+        Money::parse_free_form("1.234 €").unwrap(),
+        money("1234", IsoCurrency::EUR)
+    );
+    assert_eq!(
+        Money::parse_free_form("$1,234").unwrap(),
+        money("1234", IsoCurrency::USD)
+    );
+
+    // Bahraini dinar has three decimals, so `1,234` is genuinely ambiguous.
+    let err = Money::parse_free_form("BHD 1,234").unwrap_err();
+    assert_eq!(
+        err,
+        MoneyError::AmbiguousSeparators {
+            amount: "1,234".to_string(),
+            interpretations: vec!["1234".to_string(), "1.234".to_string()],
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "separators in '1,234' are ambiguous: could be 1234 or 1.234"
+    );
+
+    let en = MoneyParser::new().with_locale(Locale::EnUs);
+    assert_eq!(
+        en.parse("BHD 1,234").unwrap(),
+        money("1234", IsoCurrency::BHD)
+    );
+    let eu = MoneyParser::new().with_locale(Locale::EnEu);
+    assert_eq!(
+        eu.parse("BHD 1,234").unwrap(),
+        money("1.234", IsoCurrency::BHD)
+    );
+}
+
+#[test]
+fn shared_symbols_need_a_preference() {
+    set_currency_metadata("ZZA", "Test A", 2, "Ƶ", true, Locale::EnUs).unwrap();
+    set_currency_metadata("ZZB", "Test B", 2, "Ƶ", true, Locale::EnUs).unwrap();
+    let zza = Currency::try_from_str("ZZA").unwrap();
+    let zzb = Currency::try_from_str("ZZB").unwrap();
+
+    let err = Money::parse_free_form("Ƶ10").unwrap_err();
+    assert_eq!(
+        err,
+        MoneyError::AmbiguousCurrency {
+            token: "Ƶ".to_string(),
+            candidates: vec![zza, zzb.clone()],
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "currency symbol 'Ƶ' is ambiguous between ZZA, ZZB"
+    );
+
+    let parsed = MoneyParser::new()
+        .prefer(iso(IsoCurrency::USD))
+        .prefer(zzb.clone())
+        .parse("Ƶ10")
+        .unwrap();
+    assert_eq!(parsed.currency(), &zzb);
+}
+
+#[test]
+fn currency_markers_must_be_known_and_consistent() {
+    assert_eq!(
+        Money::parse_free_form("45.00"),
+        Err(MoneyError::CurrencyNotDetected)
+    );
+    assert_eq!(
+        Money::parse_free_form("US$ 45"),
+        Err(MoneyError::UnknownCurrencyMarker {
+            token: "US$".to_string()
+        })
+    );
+    assert_eq!(
+        Money::parse_free_form("$12 EUR"),
+        Err(MoneyError::MismatchedCurrencyAffix)
+    );
+    assert_eq!(
+        Money::parse_free_form("$12 USD").unwrap(),
+        money("12", IsoCurrency::USD)
+    );
+    assert_eq!(
+        Money::parse_free_form("$1.234"),
+        Ok(money("1234", IsoCurrency::USD))
+    );
+    assert_eq!(
+        Money::parse_free_form("¥1,234.5"),
+        Err(MoneyError::ScaleTooLarge {
+            digits: 1,
+            exponent: 0
+        })
+    );
+    assert_eq!(
+        Money::parse_free_form("USD"),
+        Err(MoneyError::InvalidAmountFormat)
+    );
+}
@@ -0,0 +1,162 @@
+use chrono::NaiveDate;
+use paft_decimal::Decimal;
+use paft_money::{
+    Currency, CurrencyRegistry, HistoricCurrency, IsoCurrency, Locale, Money, MoneyError,
+};
+
+const fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).expect("valid test date")
+}
+
+fn code(code: &str) -> Currency {
+    Currency::try_from_str(code).unwrap()
+}
+
+fn money(amount: &str, currency: &str) -> Money {
+    Money::from_canonical_str(amount, code(currency)).unwrap()
+}
+
+#[test]
+fn legacy_currencies_carry_metadata_and_validity() {
+    let dem = code("DEM");
+    assert_eq!(dem.decimal_places(), Ok(2));
+    assert_eq!(dem.full_name(), "Deutsche Mark");
+    assert_eq!(code("ITL").decimal_places(), Ok(0));
+
+    let historic = dem.historic().unwrap();
+    assert_eq!(historic.successor, Currency::Iso(IsoCurrency::EUR));
+    assert_eq!(
+        historic.conversion_factor,
+        "1.95583".parse::<Decimal>().unwrap()
+    );
+    assert_eq!(historic.conversion_date, date(1999, 1, 1));
+    assert!(dem.is_valid_on(date(1990, 10, 3)));
+    assert!(dem.is_valid_on(date(2001, 12, 31)));
+    assert!(!dem.is_valid_on(date(2002, 1, 1)));
+    assert!(!dem.is_valid_on(date(1948, 6, 20)));
+
+    assert!(Currency::Iso(IsoCurrency::EUR).historic().is_none());
+    assert!(Currency::Iso(IsoCurrency::EUR).is_valid_on(date(1900, 1, 1)));
+}
+
+#[test]
+fn redenominates_at_the_fixed_factor_from_the_conversion_date() {
+    assert_eq!(
+        money("1000", "DEM").redenominate(date(1999, 1, 1)).unwrap(),
+        money("511.29", "EUR")
+    );
+    assert_eq!(
+        money("100", "FRF").redenominate(date(2020, 5, 1)).unwrap(),
+        money("15.24", "EUR")
+    );
+    assert_eq!(
+        money("-193627", "ITL")
+            .redenominate(date(2002, 1, 1))
+            .unwrap(),
+        money("-100", "EUR")
+    );
+    assert_eq!(
+        money("1500000", "RUR")
+            .redenominate(date(1998, 1, 1))
+            .unwrap(),
+        money("1500", "RUB")
+    );
+
+    // GRD joined the euro two years later than DEM.
+    assert_eq!(
+        money("34075", "GRD").redenominate(date(2000, 12, 31)),
+        Err(MoneyError::NoSuccessorCurrency {
+            currency: code("GRD"),
+            date: date(2000, 12, 31),
+        })
+    );
+    assert_eq!(
+        money("10", "USD").redenominate(date(2020, 1, 1)),
+        Err(MoneyError::NoSuccessorCurrency {
+            currency: code("USD"),
+            date: date(2020, 1, 1),
+        })
+    );
+}
+
+#[test]
+fn successive_redenominations_apply_together() {
+    let veb = money("1000000000000", "VEB");
+    assert_eq!(
+        veb.redenominate(date(2010, 1, 1)).unwrap(),
+        money("1000000000", "VEF")
+    );
+    assert_eq!(
+        veb.redenominate(date(2019, 1, 1)).unwrap(),
+        money("10000", "VES")
+    );
+    assert_eq!(
+        veb.redenominate(date(2022, 1, 1)).unwrap(),
+        money("0.01", "VED")
+    );
+    assert_eq!(
+        money("123456789", "VEF")
+            .redenominate(date(2018, 8, 20))
+            .unwrap(),
+        money("1234.57", "VES")
+    );
+}
+
+#[test]
+fn registries_hold_custom_redenominations() {
+    let tenant = CurrencyRegistry::new();
+    tenant
+        .set_metadata("HCA", "Old points", 2, "op", true, Locale::EnUs)
+        .unwrap();
+    tenant
+        .set_metadata("HCB", "New points", 2, "np", true, Locale::EnUs)
+        .unwrap();
+    let hca = code("HCA");
+    let hcb = code("HCB");
+    let record = |successor: &Currency| HistoricCurrency {
+        valid_from: None,
+        valid_until: date(2023, 12, 31),
+        successor: successor.clone(),
+        conversion_factor: Decimal::from(100),
+        conversion_date: date(2024, 1, 1),
+    };
+
+    assert_eq!(tenant.set_historic(&hca, record(&hcb)), Ok(None));
+    let old = Money::from_canonical_str_in("250", hca.clone(), &tenant).unwrap();
+    assert_eq!(
+        old.redenominate_in(date(2024, 1, 1), &tenant).unwrap(),
+        Money::from_canonical_str_in("2.5", hcb.clone(), &tenant).unwrap()
+    );
+    assert!(tenant.is_valid_on(&hca, date(2023, 12, 31)));
+    assert!(!tenant.is_valid_on(&hca, date(2024, 1, 1)));
+    assert!(CurrencyRegistry::global().historic(&hca).is_none());
+
+    assert_eq!(
+        tenant.set_historic(&hcb, record(&hca)),
+        Err(MoneyError::SuccessorCycle {
+            currency: hcb.clone()
+        })
+    );
+    assert_eq!(
+        tenant.set_historic(
+            &hcb,
+            HistoricCurrency {
+                conversion_factor: Decimal::from(0),
+                ..record(&Currency::Iso(IsoCurrency::USD))
+            }
+        ),
+        Err(MoneyError::InvalidExchangeRate {
+            rate: Decimal::from(0)
+        })
+    );
+    assert!(matches!(
+        tenant.set_historic(&hcb, record(&code("HCZ"))),
+        Err(MoneyError::MetadataNotFound { .. })
+    ));
+
+    assert_eq!(tenant.clear_historic(&hca), Some(record(&hcb)));
+    assert!(matches!(
+        old.redenominate_in(date(2024, 1, 1), &tenant),
+        Err(MoneyError::NoSuccessorCurrency { .. })
+    ));
+}
@@ -0,0 +1,163 @@
+#![cfg(feature = "money-formatting")]
+
+use iso_currency::Currency as IsoCurrency;
+use paft_money::{Currency, LocalFormat, Locale, Money, MoneyError, register_locale};
+
+fn money(amount: &str, code: IsoCurrency) -> Money {
+    Money::from_canonical_str(amount, Currency::Iso(code)).unwrap()
+}
+
+#[test]
+fn swiss_locale_uses_apostrophe_grouping() {
+    let chf = money("1234567.5", IsoCurrency::CHF);
+    assert_eq!(
+        chf.format_with_locale(Locale::DeCh).unwrap(),
+        "CHF 1'234'567.50"
+    );
+    assert_eq!(
+        Money::from_str_locale(
+            "CHF 1'234'567.50",
+            Currency::Iso(IsoCurrency::CHF),
+            Locale::DeCh
+        )
+        .unwrap(),
+        chf
+    );
+}
+
+#[test]
+fn french_locale_uses_narrow_no_break_space() {
+    let eur = money("-1234.56", IsoCurrency::EUR);
+    let formatted = eur
+        .localized(Locale::FrFr)
+        .symbol_first(false)
+        .into_string()
+        .unwrap();
+    assert_eq!(formatted, "-1\u{202F}234,56€");
+    assert_eq!(
+        Money::from_str_locale(&formatted, Currency::Iso(IsoCurrency::EUR), Locale::FrFr).unwrap(),
+        eur
+    );
+    // A plain space is not the French group separator.
+    assert_eq!(
+        Money::from_str_locale("1 234,56", Currency::Iso(IsoCurrency::EUR), Locale::FrFr),
+        Err(MoneyError::InvalidAmountFormat)
+    );
+}
+
+#[test]
+fn japanese_and_brazilian_locales() {
+    let jpy = money("1234567", IsoCurrency::JPY);
+    assert_eq!(jpy.format_with_locale(Locale::JaJp).unwrap(), "¥1,234,567");
+
+    let brl = money("1234.5", IsoCurrency::BRL);
+    assert_eq!(
+        brl.amount_string_with_locale(Locale::PtBr, 2).unwrap(),
+        "1.234,50"
+    );
+    assert_eq!(
+        Money::from_str_locale(
+            "BRL 1.234,50",
+            Currency::Iso(IsoCurrency::BRL),
+            Locale::PtBr
+        )
+        .unwrap(),
+        brl
+    );
+}
+
+#[test]
+fn tags_round_trip_for_builtin_locales() {
+    for locale in [
+        Locale::EnUs,
+        Locale::EnIn,
+        Locale::EnEu,
+        Locale::EnBy,
+        Locale::DeCh,
+        Locale::FrFr,
+        Locale::JaJp,
+        Locale::PtBr,
+    ] {
+        assert_eq!(Locale::from_tag(&locale.tag()), Some(locale));
+    }
+    assert_eq!(Locale::from_tag("DE-ch"), Some(Locale::DeCh));
+    assert_eq!(Locale::from_tag("xx-unknown"), None);
+    assert_eq!(Locale::FrFr.spec().group_separator, '\u{202F}');
+}
+
+#[test]
+fn registered_locale_formats_and_parses() {
+    // Two-digit groups after the first three exercise non-uniform patterns.
+    let format = LocalFormat::new('_', ',', &[3, 2]).unwrap();
+    let custom = register_locale("x-test-underscore", format.clone()).unwrap();
+    assert!(matches!(custom, Locale::Custom(_)));
+    assert_eq!(custom.tag(), "x-test-underscore");
+    assert_eq!(custom.spec(), format);
+    assert_eq!(Locale::from_tag("X-TEST-UNDERSCORE"), Some(custom));
+
+    let usd = money("12345678.9", IsoCurrency::USD);
+    assert_eq!(usd.format_with_locale(custom).unwrap(), "$1_23_45_678,90");
+    assert_eq!(
+        Money::from_str_locale("$1_23_45_678,90", Currency::Iso(IsoCurrency::USD), custom).unwrap(),
+        usd
+    );
+    assert_eq!(
+        Money::from_str_locale("$12_345_678,90", Currency::Iso(IsoCurrency::USD), custom),
+        Err(MoneyError::InvalidGrouping)
+    );
+}
+
+#[test]
+fn re_registering_a_tag_updates_the_same_handle() {
+    let first =
+        register_locale("x-test-replace", LocalFormat::new(',', '.', &[3]).unwrap()).unwrap();
+    let second =
+        register_locale("X-Test-Replace", LocalFormat::new('.', ',', &[3]).unwrap()).unwrap();
+    assert_eq!(first, second);
+    assert_eq!(
+        money("1000", IsoCurrency::USD)
+            .format_with_locale(first)
+            .unwrap(),
+        "$1.000,00"
+    );
+}
+
+#[test]
+fn invalid_registrations_are_rejected() {
+    assert!(matches!(
+        LocalFormat::new(',', ',', &[3]),
+        Err(MoneyError::InvalidLocaleFormat { .. })
+    ));
+    assert!(matches!(
+        LocalFormat::new('1', '.', &[3]),
+        Err(MoneyError::InvalidLocaleFormat { .. })
+    ));
+    assert!(matches!(
+        LocalFormat::new(',', '.', &[3, 0]),
+        Err(MoneyError::InvalidLocaleFormat { .. })
+    ));
+    assert!(matches!(
+        LocalFormat::new(',', '.', &[]),
+        Err(MoneyError::InvalidLocaleFormat { .. })
+    ));
+
+    let valid = LocalFormat::new(',', '.', &[3]).unwrap();
+    assert!(matches!(
+        register_locale("en-us", valid.clone()),
+        Err(MoneyError::InvalidLocaleFormat { .. })
+    ));
+    assert!(matches!(
+        register_locale("  ", valid),
+        Err(MoneyError::InvalidLocaleFormat { .. })
+    ));
+
+    let hand_built = LocalFormat {
+        group_separator: '-',
+        decimal_separator: '.',
+        grouping: Locale::EnUs.spec().grouping,
+    };
+    assert!(matches!(
+        register_locale("x-test-sign", hand_built),
+        Err(MoneyError::InvalidLocaleFormat { .. })
+    ));
+}
@@ -0,0 +1,214 @@
+use paft_money::{
+    CurrencyMetadataEntry, CurrencyMetadataFile, Locale, MetadataLoadError, MinorUnitError,
+    currency_metadata, export_currency_metadata, load_currency_metadata, set_currency_metadata,
+};
+
+fn entry(code: &str, minor_units: u8) -> CurrencyMetadataEntry {
+    CurrencyMetadataEntry {
+        code: code.to_string(),
+        full_name: format!("{code} token"),
+        minor_units,
+        symbol: code.to_string(),
+        symbol_first: true,
+        default_locale: Locale::EnUs,
+        cash_increment: None,
+    }
+}
+
+#[test]
+fn batch_registers_every_entry() {
+    let loaded = load_currency_metadata(&[entry("MFA", 4), entry("mfb", 0)], false).unwrap();
+    assert_eq!(loaded, 2);
+    assert_eq!(currency_metadata("MFA").unwrap().minor_units, 4);
+    assert_eq!(currency_metadata("MFB").unwrap().full_name, "mfb token");
+}
+
+#[test]
+fn one_invalid_entry_rejects_the_whole_batch() {
+    let err = load_currency_metadata(&[entry("MFC", 2), entry("MFD", 19)], false).unwrap_err();
+    assert_eq!(
+        err,
+        MetadataLoadError::InvalidEntry {
+            index: 1,
+            code: "MFD".to_string(),
+            source: MinorUnitError::ExceedsMinorUnitScale { decimals: 19 },
+        }
+    );
+    assert!(
+        err.to_string()
+            .starts_with("currency metadata entry 1 ('MFD'): ")
+    );
+    assert_eq!(currency_metadata("MFC"), None);
+
+    let err = load_currency_metadata(&[entry("MFE", 2), entry("USD", 3)], true).unwrap_err();
+    assert!(matches!(
+        err,
+        MetadataLoadError::InvalidEntry {
+            index: 1,
+            source: MinorUnitError::MinorUnitsAlreadyRegistered { existing: 2, .. },
+            ..
+        }
+    ));
+    assert_eq!(currency_metadata("MFE"), None);
+
+    let err = load_currency_metadata(&[entry("MFF", 2), entry("", 2)], false).unwrap_err();
+    assert!(matches!(
+        err,
+        MetadataLoadError::InvalidEntry {
+            source: MinorUnitError::InvalidCurrencyCode { .. },
+            ..
+        }
+    ));
+    assert_eq!(currency_metadata("MFF"), None);
+}
+
+#[test]
+fn duplicate_codes_are_rejected() {
+    let err = load_currency_metadata(&[entry("MFG", 2), entry("mfg", 2)], false).unwrap_err();
+    assert_eq!(
+        err,
+        MetadataLoadError::DuplicateCode {
+            code: "MFG".to_string(),
+            first: 0,
+            second: 1,
+        }
+    );
+    assert_eq!(currency_metadata("MFG"), None);
+}
+
+#[test]
+fn scale_changes_need_the_override_flag() {
+    set_currency_metadata("MFH", "Old", 2, "H", true, Locale::EnUs).unwrap();
+    assert!(matches!(
+        load_currency_metadata(&[entry("MFH", 3)], false),
+        Err(MetadataLoadError::InvalidEntry {
+            source: MinorUnitError::MinorUnitsAlreadyRegistered {
+                existing: 2,
+                requested: 3,
+                ..
+            },
+            ..
+        })
+    ));
+    assert_eq!(currency_metadata("MFH").unwrap().full_name, "Old");
+
+    load_currency_metadata(&[entry("MFH", 3)], true).unwrap();
+    assert_eq!(currency_metadata("MFH").unwrap().minor_units, 3);
+}
+
+#[test]
+fn export_merges_builtin_and_custom_metadata() {
+    load_currency_metadata(&[entry("MFI", 5)], false).unwrap();
+    let exported = export_currency_metadata();
+    assert!(exported.windows(2).all(|pair| pair[0].code < pair[1].code));
+    assert!(exported.contains(&entry("MFI", 5)));
+    let btc = exported.iter().find(|entry| entry.code == "BTC").unwrap();
+    assert_eq!(btc.minor_units, 8);
+
+    assert!(CurrencyMetadataFile::effective().currencies.contains(btc));
+
+    // Exported entries load back unchanged.
+    let reload = [btc.clone(), entry("MFI", 5)];
+    assert_eq!(load_currency_metadata(&reload, false).unwrap(), 2);
+    assert_eq!(currency_metadata("BTC").unwrap().full_name, btc.full_name);
+}
+
+#[cfg(feature = "metadata-json")]
+#[test]
+fn json_documents_round_trip() {
+    let file = CurrencyMetadataFile::from_json_str(
+        r#"{"currencies": [
+            {"code": "MFJ", "full_name": "Desk token", "minor_units": 3, "symbol": "Ɉ",
+             "symbol_first": false, "default_locale": "de-CH"},
+            {"code": "MFK", "full_name": "Reward point", "minor_units": 0, "symbol": "pt"}
+        ]}"#,
+    )
+    .unwrap();
+    assert_eq!(file.currencies[0].default_locale, Locale::DeCh);
+    assert!(file.currencies[1].symbol_first);
+    assert_eq!(file.currencies[1].default_locale, Locale::EnUs);
+    assert_eq!(
+        CurrencyMetadataFile::from_json_str(&file.to_json_string()).unwrap(),
+        file
+    );
+
+    assert_eq!(file.load().unwrap(), 2);
+    let mfj = currency_metadata("MFJ").unwrap();
+    assert_eq!(mfj.symbol, "Ɉ");
+    assert!(!mfj.symbol_first);
+
+    for malformed in [
+        r#"{"currencies": [{"code": "MFL", "full_name": "x", "symbol": "x"}]}"#,
+        r#"{"currencies": [{"code": "MFL", "full_name": "x", "minor_units": 2, "symbol": "x", "default_locale": "xx-XX"}]}"#,
+        r#"{"currencies": [{"code": "MFL", "full_name": "x", "minor_units": 2, "symbol": "x", "decimals": 2}]}"#,
+    ] {
+        assert!(matches!(
+            CurrencyMetadataFile::from_json_str(malformed),
+            Err(MetadataLoadError::Parse { format: "JSON", .. })
+        ));
+    }
+}
+
+#[cfg(feature = "metadata-toml")]
+#[test]
+fn toml_documents_round_trip() {
+    let file = CurrencyMetadataFile::from_toml_str(
+        r#"
+[[currencies]]
+code = "MFM"
+full_name = "Ops token"
+minor_units = 6
+symbol = "Ⓜ"
+
+[[currencies]]
+code = "MFN"
+full_name = "Settlement unit"
+minor_units = 2
+symbol = "SU"
+symbol_first = false
+default_locale = "en-EU"
+"#,
+    )
+    .unwrap();
+    assert_eq!(file.currencies.len(), 2);
+    assert_eq!(file.currencies[1].default_locale, Locale::EnEu);
+    assert_eq!(
+        CurrencyMetadataFile::from_toml_str(&file.to_toml_string()).unwrap(),
+        file
+    );
+    assert!(matches!(
+        CurrencyMetadataFile::from_toml_str("currencies = 3"),
+        Err(MetadataLoadError::Parse { format: "TOML", .. })
+    ));
+}
+
+#[cfg(all(feature = "metadata-json", feature = "metadata-toml"))]
+#[test]
+fn files_are_read_by_extension() {
+    use paft_money::load_currency_metadata_file;
+
+    let dir = std::env::temp_dir().join(format!("paft-metadata-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let toml_path = dir.join("tokens.toml");
+    let document = CurrencyMetadataFile {
+        currencies: vec![entry("MFO", 4)],
+    };
+    std::fs::write(&toml_path, document.to_toml_string()).unwrap();
+    assert_eq!(load_currency_metadata_file(&toml_path).unwrap(), 1);
+    assert_eq!(currency_metadata("MFO").unwrap().minor_units, 4);
+
+    let json_path = dir.join("tokens.JSON");
+    std::fs::write(&json_path, document.to_json_string()).unwrap();
+    assert_eq!(CurrencyMetadataFile::read(&json_path).unwrap(), document);
+
+    assert!(matches!(
+        CurrencyMetadataFile::read(dir.join("tokens.yaml")),
+        Err(MetadataLoadError::UnsupportedFormat { .. })
+    ));
+    assert!(matches!(
+        CurrencyMetadataFile::read(dir.join("missing.json")),
+        Err(MetadataLoadError::Io { .. })
+    ));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,220 @@
+use chrono::{DateTime, Utc};
+use iso_currency::Currency as IsoCurrency;
+use paft_decimal::{Decimal, RoundingStrategy};
+use paft_money::{
+    Currency, ExchangeRate, MonetaryAmount, Money, MoneyBag, MoneyError, RateBook, RateHistory,
+    RateSource,
+};
+
+const fn ccy(code: IsoCurrency) -> Currency {
+    Currency::Iso(code)
+}
+
+fn money(amount: &str, code: IsoCurrency) -> Money {
+    Money::from_canonical_str(amount, ccy(code)).unwrap()
+}
+
+fn exact(amount: &str, code: IsoCurrency) -> MonetaryAmount {
+    MonetaryAmount::from_canonical_str(amount, ccy(code)).unwrap()
+}
+
+fn rate(from: IsoCurrency, to: IsoCurrency, value: &str) -> ExchangeRate {
+    ExchangeRate::new(ccy(from), ccy(to), value.parse().unwrap()).unwrap()
+}
+
+#[test]
+fn accumulates_per_currency_in_code_order() {
+    let mut bag = MoneyBag::new();
+    let payments = vec![
+        money("10.00", IsoCurrency::USD),
+        money("5.00", IsoCurrency::EUR),
+    ];
+    for payment in &payments {
+        bag.try_add(payment).unwrap();
+    }
+    bag.try_add(exact("0.005", IsoCurrency::USD)).unwrap();
+    bag.try_add(money("2.50", IsoCurrency::EUR)).unwrap();
+
+    assert_eq!(bag.len(), 2);
+    let totals: Vec<String> = bag.iter().map(MonetaryAmount::format).collect();
+    assert_eq!(totals, ["7.5 EUR", "10.005 USD"]);
+    assert_eq!(
+        bag.currencies().collect::<Vec<_>>(),
+        [&ccy(IsoCurrency::EUR), &ccy(IsoCurrency::USD)]
+    );
+    assert_eq!(
+        bag.get(&ccy(IsoCurrency::USD)),
+        Some(&exact("10.005", IsoCurrency::USD))
+    );
+    assert_eq!(bag.get(&ccy(IsoCurrency::GBP)), None);
+}
+
+#[test]
+fn sub_negate_and_zero_totals() {
+    let mut bag = MoneyBag::from_amounts([
+        money("10.00", IsoCurrency::USD),
+        money("3.00", IsoCurrency::GBP),
+    ])
+    .unwrap();
+    bag.try_sub(money("10.00", IsoCurrency::USD)).unwrap();
+    assert_eq!(bag.len(), 1);
+    assert!(bag.get(&ccy(IsoCurrency::USD)).is_none());
+
+    bag.try_sub(money("4.00", IsoCurrency::JPY)).unwrap();
+    assert_eq!(
+        bag.get(&ccy(IsoCurrency::JPY)),
+        Some(&exact("-4", IsoCurrency::JPY))
+    );
+
+    let negated = bag.negated();
+    assert_eq!(
+        negated.get(&ccy(IsoCurrency::GBP)),
+        Some(&exact("-3", IsoCurrency::GBP))
+    );
+    assert_eq!(
+        negated.get(&ccy(IsoCurrency::JPY)),
+        Some(&exact("4", IsoCurrency::JPY))
+    );
+
+    let mut cancelled = bag.clone();
+    cancelled.try_add_bag(&negated).unwrap();
+    assert!(cancelled.is_empty());
+
+    bag.try_sub_bag(&bag.clone()).unwrap();
+    assert_eq!(bag, MoneyBag::new());
+}
+
+#[test]
+fn equality_ignores_insertion_order() {
+    let a = MoneyBag::from_amounts([money("1", IsoCurrency::USD), money("2", IsoCurrency::EUR)])
+        .unwrap();
+    let b = MoneyBag::from_amounts([money("2", IsoCurrency::EUR), money("1", IsoCurrency::USD)])
+        .unwrap();
+    assert_eq!(a, b);
+}
+
+#[test]
+fn collapse_converts_at_full_precision_and_rounds_once() {
+    let bag = MoneyBag::from_amounts([
+        exact("0.004", IsoCurrency::USD),
+        exact("0.002", IsoCurrency::EUR),
+        exact("10", IsoCurrency::EUR),
+    ])
+    .unwrap();
+    let rates = RateBook::from_rates([rate(IsoCurrency::EUR, IsoCurrency::USD, "1.5")]).unwrap();
+
+    // 0.004 + 10.002 * 1.5 = 15.007 -> 15.01 (rounding each leg first would give 15.00).
+    let total = bag.collapse(&ccy(IsoCurrency::USD), &rates).unwrap();
+    assert_eq!(total, money("15.01", IsoCurrency::USD));
+
+    let truncated = bag
+        .collapse_with(&ccy(IsoCurrency::USD), &rates, RoundingStrategy::ToZero)
+        .unwrap();
+    assert_eq!(truncated, money("15.00", IsoCurrency::USD));
+
+    // The inverse is resolved by the rate book.
+    let in_eur = MoneyBag::from_amounts([money("3", IsoCurrency::USD)])
+        .unwrap()
+        .collapse(&ccy(IsoCurrency::EUR), &rates)
+        .unwrap();
+    assert_eq!(in_eur, money("2", IsoCurrency::EUR));
+
+    assert_eq!(
+        MoneyBag::new()
+            .collapse(&ccy(IsoCurrency::JPY), &rates)
+            .unwrap(),
+        money("0", IsoCurrency::JPY)
+    );
+}
+
+#[test]
+fn collapse_reports_every_missing_rate() {
+    let bag = MoneyBag::from_amounts([
+        money("1", IsoCurrency::USD),
+        money("1", IsoCurrency::EUR),
+        money("1", IsoCurrency::GBP),
+        money("1", IsoCurrency::CHF),
+    ])
+    .unwrap();
+    let rates = RateBook::from_rates([rate(IsoCurrency::EUR, IsoCurrency::USD, "1.1")]).unwrap();
+
+    let err = bag.collapse(&ccy(IsoCurrency::USD), &rates).unwrap_err();
+    assert_eq!(
+        err,
+        MoneyError::MissingExchangeRates {
+            target: ccy(IsoCurrency::USD),
+            missing: vec![ccy(IsoCurrency::CHF), ccy(IsoCurrency::GBP)],
+        }
+    );
+    assert_eq!(err.to_string(), "no exchange rate into USD for: CHF, GBP");
+}
+
+#[test]
+fn collapse_with_historical_rates() {
+    let mut history = RateHistory::new();
+    let day = |secs| DateTime::<Utc>::from_timestamp(secs, 0).unwrap();
+    history.insert(day(0), rate(IsoCurrency::EUR, IsoCurrency::USD, "1.1"));
+    history.insert(day(86_400), rate(IsoCurrency::EUR, IsoCurrency::USD, "1.2"));
+
+    let bag = MoneyBag::from_amounts([money("10", IsoCurrency::EUR)]).unwrap();
+    let usd = ccy(IsoCurrency::USD);
+    assert_eq!(
+        bag.collapse(&usd, &history.at(day(3_600))).unwrap(),
+        money("11", IsoCurrency::USD)
+    );
+    assert_eq!(
+        bag.collapse(&usd, &history.at(day(90_000))).unwrap(),
+        money("12", IsoCurrency::USD)
+    );
+    assert!(matches!(
+        bag.collapse(&usd, &history.at(day(-1))),
+        Err(MoneyError::MissingExchangeRates { .. })
+    ));
+}
+
+struct Fixed(Decimal);
+
+impl RateSource for Fixed {
+    fn exchange_rate(&self, from: &Currency, to: &Currency) -> Result<ExchangeRate, MoneyError> {
+        ExchangeRate::new(
+            from.clone(),
+            to.clone(),
+            paft_decimal::clone_decimal(&self.0),
+        )
+    }
+}
+
+#[test]
+fn custom_rate_sources_are_supported() {
+    let bag = MoneyBag::from_amounts([money("2", IsoCurrency::EUR)]).unwrap();
+    let source: &dyn RateSource = &Fixed(Decimal::from(3));
+    assert_eq!(
+        bag.collapse(&ccy(IsoCurrency::USD), source).unwrap(),
+        money("6", IsoCurrency::USD)
+    );
+}
+
+#[test]
+fn serde_round_trip_normalizes_entries() {
+    let bag = MoneyBag::from_amounts([
+        money("12.34", IsoCurrency::USD),
+        money("5", IsoCurrency::EUR),
+    ])
+    .unwrap();
+    let json = serde_json::to_string(&bag).unwrap();
+    assert_eq!(
+        json,
+        r#"[{"amount":"5","currency":"EUR"},{"amount":"12.34","currency":"USD"}]"#
+    );
+    assert_eq!(serde_json::from_str::<MoneyBag>(&json).unwrap(), bag);
+
+    let merged: MoneyBag = serde_json::from_str(
+        r#"[{"amount":"1","currency":"USD"},{"amount":"0","currency":"GBP"},{"amount":"2","currency":"USD"}]"#,
+    )
+    .unwrap();
+    assert_eq!(merged.len(), 1);
+    assert_eq!(
+        merged.get(&ccy(IsoCurrency::USD)),
+        Some(&exact("3", IsoCurrency::USD))
+    );
+}
@@ -0,0 +1,180 @@
+#![cfg(feature = "money-formatting")]
+
+use iso_currency::Currency as IsoCurrency;
+use paft_money::{Currency, Locale, MAX_DECIMAL_PRECISION, Money, MoneyError, MoneyPattern};
+
+fn money(amount: &str, code: IsoCurrency) -> Money {
+    Money::from_canonical_str(amount, Currency::Iso(code)).unwrap()
+}
+
+fn render(money: &Money, locale: Locale, pattern: &str) -> String {
+    let pattern = MoneyPattern::parse(pattern).unwrap();
+    money
+        .localized(locale)
+        .with_pattern(&pattern)
+        .into_string()
+        .unwrap()
+}
+
+fn pattern_error(pattern: &str) -> (usize, &'static str) {
+    match MoneyPattern::parse(pattern) {
+        Err(MoneyError::InvalidFormatPattern { position, reason }) => (position, reason),
+        other => panic!("expected InvalidFormatPattern for {pattern:?}, got {other:?}"),
+    }
+}
+
+#[test]
+fn accounting_pattern_wraps_negatives_in_parentheses() {
+    let accounting = "¤#,##0.00;(¤#,##0.00)";
+    assert_eq!(
+        render(
+            &money("-1234.5", IsoCurrency::USD),
+            Locale::EnUs,
+            accounting
+        ),
+        "($1,234.50)"
+    );
+    assert_eq!(
+        render(&money("1234.5", IsoCurrency::USD), Locale::EnUs, accounting),
+        "$1,234.50"
+    );
+    assert_eq!(
+        render(&money("-0", IsoCurrency::USD), Locale::EnUs, accounting),
+        "$0.00"
+    );
+}
+
+#[test]
+fn implicit_negative_prefixes_minus_or_replaces_plus() {
+    let loss = money("-5", IsoCurrency::USD);
+    assert_eq!(render(&loss, Locale::EnUs, "¤#,##0.00"), "-$5.00");
+    assert_eq!(render(&loss, Locale::EnUs, "+¤#,##0.00"), "-$5.00");
+    assert_eq!(
+        render(&money("5", IsoCurrency::USD), Locale::EnUs, "+¤#,##0.00"),
+        "+$5.00"
+    );
+    assert_eq!(
+        render(&money("0", IsoCurrency::USD), Locale::EnUs, "+¤#,##0.00"),
+        "+$0.00"
+    );
+}
+
+#[test]
+fn fraction_digit_bounds_round_and_trim() {
+    let pattern = MoneyPattern::parse("#,##0.00##").unwrap();
+    assert_eq!(pattern.min_fraction_digits(), 2);
+    assert_eq!(pattern.max_fraction_digits(), 4);
+
+    let eur = money("1234.5", IsoCurrency::EUR);
+    assert_eq!(render(&eur, Locale::EnUs, "#,##0.00##"), "1,234.50");
+    assert_eq!(render(&eur, Locale::EnUs, "#,##0.##"), "1,234.5");
+    assert_eq!(render(&eur, Locale::EnUs, "#,##0"), "1,234");
+    assert_eq!(
+        render(&money("2.5", IsoCurrency::EUR), Locale::EnUs, "0"),
+        "2",
+        "rounding for display is half-to-even"
+    );
+}
+
+#[test]
+fn explicit_fraction_digits_override_the_pattern() {
+    let pattern = MoneyPattern::parse("¤#,##0.##").unwrap();
+    let usd = money("12", IsoCurrency::USD);
+    assert_eq!(
+        usd.localized(Locale::EnUs)
+            .with_pattern(&pattern)
+            .fraction_digits(3)
+            .into_string()
+            .unwrap(),
+        "$12.000"
+    );
+    assert!(matches!(
+        usd.localized(Locale::EnUs)
+            .with_pattern(&pattern)
+            .fraction_digits(u32::MAX)
+            .into_string(),
+        Err(MoneyError::FormatPrecisionExceeded { .. })
+    ));
+}
+
+#[test]
+fn pattern_grouping_overrides_locale_grouping() {
+    let inr = money("12345678", IsoCurrency::INR);
+    assert_eq!(render(&inr, Locale::EnUs, "#,##,##0"), "1,23,45,678");
+    assert_eq!(render(&inr, Locale::EnIn, "#,##0"), "12,345,678");
+    assert_eq!(render(&inr, Locale::EnIn, "0"), "12345678");
+    assert_eq!(render(&inr, Locale::EnEu, "#,####"), "1234.5678");
+}
+
+#[test]
+fn locale_supplies_separator_characters() {
+    let eur = money("-1234.5", IsoCurrency::EUR);
+    assert_eq!(
+        render(&eur, Locale::EnEu, "#,##0.00 ¤;-#,##0.00 ¤"),
+        "-1.234,50 €"
+    );
+    assert_eq!(render(&eur, Locale::DeCh, "¤¤ #,##0.00"), "-EUR 1'234.50");
+}
+
+#[test]
+fn integer_digits_pad_and_may_be_omitted() {
+    let cents = money("0.5", IsoCurrency::USD);
+    assert_eq!(render(&cents, Locale::EnUs, "#.00"), ".50");
+    assert_eq!(render(&cents, Locale::EnUs, "000.00"), "000.50");
+    assert_eq!(
+        render(&money("0", IsoCurrency::USD), Locale::EnUs, "#"),
+        "0"
+    );
+}
+
+#[test]
+fn quoted_literals_and_symbol_options() {
+    let usd = money("7", IsoCurrency::USD);
+    assert_eq!(
+        render(&usd, Locale::EnUs, "'Total: '¤0.00' (it''s ¤)'"),
+        "Total: $7.00 (it's ¤)"
+    );
+
+    let pattern = MoneyPattern::parse("¤0.00 ¤¤").unwrap();
+    assert_eq!(
+        usd.localized(Locale::EnUs)
+            .with_pattern(&pattern)
+            .without_symbol()
+            .into_string()
+            .unwrap(),
+        "7.00 USD"
+    );
+    assert_eq!(
+        format!("{}", usd.localized(Locale::EnUs).with_pattern(&pattern)),
+        "$7.00 USD"
+    );
+}
+
+#[test]
+fn pattern_round_trips_its_source() {
+    let pattern: MoneyPattern = "¤#,##0.00;(¤#,##0.00)".parse().unwrap();
+    assert_eq!(pattern.as_str(), "¤#,##0.00;(¤#,##0.00)");
+    assert_eq!(pattern.to_string(), pattern.as_str());
+    assert_eq!(pattern.min_integer_digits(), 1);
+}
+
+#[test]
+fn malformed_patterns_report_position_and_reason() {
+    assert_eq!(pattern_error("¤").0, 1);
+    assert_eq!(pattern_error("#,##0.0#0").0, 8);
+    assert_eq!(pattern_error("0#").0, 1);
+    assert_eq!(pattern_error("#,##0,").0, 5);
+    assert_eq!(pattern_error("#,,##0").0, 2);
+    assert_eq!(pattern_error("0.00;(0.00);0").0, 11);
+    assert_eq!(pattern_error("0 ¤ 0").0, 4);
+    assert_eq!(pattern_error("'open 0").0, 0);
+    assert_eq!(pattern_error("#,##0%").0, 5);
+    assert_eq!(pattern_error("¤¤¤0").0, 0);
+    assert_eq!(pattern_error("0.00;").0, 5);
+    assert_eq!(pattern_error("0.0.0").0, 3);
+    let too_precise = format!("0.{}", "0".repeat(usize::from(MAX_DECIMAL_PRECISION) + 1));
+    assert_eq!(
+        pattern_error(&too_precise).1,
+        "pattern exceeds the maximum fraction digits"
+    );
+}
@@ -0,0 +1,285 @@
+use iso_currency::Currency as IsoCurrency;
+use paft_decimal::{Decimal, RoundingStrategy};
+use paft_money::{Currency, ExchangeRate, Money, MoneyError, RateBook, RateLeg, RateResolution};
+
+const fn ccy(code: IsoCurrency) -> Currency {
+    Currency::Iso(code)
+}
+
+fn rate(from: IsoCurrency, to: IsoCurrency, value: &str) -> ExchangeRate {
+    ExchangeRate::new(ccy(from), ccy(to), value.parse().unwrap()).unwrap()
+}
+
+fn dec(value: &str) -> Decimal {
+    value.parse().unwrap()
+}
+
+const fn leg(from: IsoCurrency, to: IsoCurrency, inverted: bool) -> RateLeg {
+    RateLeg {
+        from: ccy(from),
+        to: ccy(to),
+        inverted,
+    }
+}
+
+#[test]
+fn identity_resolves_without_rates() {
+    let book = RateBook::new();
+    let resolved = book
+        .resolve(&ccy(IsoCurrency::USD), &ccy(IsoCurrency::USD))
+        .unwrap();
+    assert_eq!(resolved.rate.rate(), Decimal::from(1));
+    assert_eq!(resolved.method, RateResolution::Identity);
+    assert!(resolved.legs.is_empty());
+}
+
+#[test]
+fn direct_and_inverse_resolution() {
+    let book = RateBook::from_rates([rate(IsoCurrency::USD, IsoCurrency::EUR, "0.8")]).unwrap();
+
+    let direct = book
+        .resolve(&ccy(IsoCurrency::USD), &ccy(IsoCurrency::EUR))
+        .unwrap();
+    assert_eq!(direct.method, RateResolution::Direct);
+    assert_eq!(direct.rate.rate(), dec("0.8"));
+    assert_eq!(
+        direct.legs,
+        vec![leg(IsoCurrency::USD, IsoCurrency::EUR, false)]
+    );
+
+    let inverse = book
+        .resolve(&ccy(IsoCurrency::EUR), &ccy(IsoCurrency::USD))
+        .unwrap();
+    assert_eq!(inverse.method, RateResolution::Inverse);
+    assert_eq!(inverse.rate.rate(), dec("1.25"));
+    assert_eq!(inverse.rate.from(), &ccy(IsoCurrency::EUR));
+    assert_eq!(
+        inverse.legs,
+        vec![leg(IsoCurrency::EUR, IsoCurrency::USD, true)]
+    );
+}
+
+#[test]
+fn pivot_triangulation_reports_pivot_and_legs() {
+    let book = RateBook::from_rates([
+        rate(IsoCurrency::USD, IsoCurrency::EUR, "0.8"),
+        rate(IsoCurrency::JPY, IsoCurrency::USD, "0.01"),
+    ])
+    .unwrap()
+    .with_pivot(ccy(IsoCurrency::USD));
+
+    let resolved = book
+        .resolve(&ccy(IsoCurrency::JPY), &ccy(IsoCurrency::EUR))
+        .unwrap();
+    assert_eq!(
+        resolved.method,
+        RateResolution::Pivot {
+            pivot: ccy(IsoCurrency::USD)
+        }
+    );
+    assert_eq!(resolved.rate.rate(), dec("0.008"));
+    assert_eq!(
+        resolved.legs,
+        vec![
+            leg(IsoCurrency::JPY, IsoCurrency::USD, false),
+            leg(IsoCurrency::USD, IsoCurrency::EUR, false),
+        ]
+    );
+
+    let back = book
+        .resolve(&ccy(IsoCurrency::EUR), &ccy(IsoCurrency::JPY))
+        .unwrap();
+    assert_eq!(back.rate.rate(), dec("125"));
+    assert!(back.legs.iter().all(|leg| leg.inverted));
+}
+
+#[test]
+fn pivot_is_preferred_over_other_shortest_paths() {
+    // EUR->GBP via USD gives 2, via CHF gives 3; the pivot disambiguates.
+    let rates = [
+        rate(IsoCurrency::EUR, IsoCurrency::USD, "1"),
+        rate(IsoCurrency::USD, IsoCurrency::GBP, "2"),
+        rate(IsoCurrency::EUR, IsoCurrency::CHF, "1"),
+        rate(IsoCurrency::CHF, IsoCurrency::GBP, "3"),
+    ];
+    let eur = ccy(IsoCurrency::EUR);
+    let gbp = ccy(IsoCurrency::GBP);
+
+    let book = RateBook::from_rates(rates).unwrap();
+    assert_eq!(
+        book.resolve(&eur, &gbp),
+        Err(MoneyError::AmbiguousExchangeRate {
+            from: eur.clone(),
+            to: gbp.clone(),
+        })
+    );
+
+    let book = book.with_pivot(ccy(IsoCurrency::USD));
+    assert_eq!(book.resolve(&eur, &gbp).unwrap().rate.rate(), dec("2"));
+}
+
+#[test]
+fn shortest_path_chains_across_several_hops() {
+    let book = RateBook::from_rates([
+        rate(IsoCurrency::USD, IsoCurrency::EUR, "0.5"),
+        rate(IsoCurrency::GBP, IsoCurrency::EUR, "4"),
+        rate(IsoCurrency::GBP, IsoCurrency::CHF, "3"),
+    ])
+    .unwrap();
+
+    let resolved = book
+        .resolve(&ccy(IsoCurrency::USD), &ccy(IsoCurrency::CHF))
+        .unwrap();
+    assert_eq!(resolved.method, RateResolution::ShortestPath);
+    assert_eq!(resolved.rate.rate(), dec("0.375"));
+    assert_eq!(
+        resolved.legs,
+        vec![
+            leg(IsoCurrency::USD, IsoCurrency::EUR, false),
+            leg(IsoCurrency::EUR, IsoCurrency::GBP, true),
+            leg(IsoCurrency::GBP, IsoCurrency::CHF, false),
+        ]
+    );
+}
+
+#[test]
+fn consistent_equal_length_paths_are_not_ambiguous() {
+    let book = RateBook::from_rates([
+        rate(IsoCurrency::EUR, IsoCurrency::USD, "2"),
+        rate(IsoCurrency::USD, IsoCurrency::GBP, "3"),
+        rate(IsoCurrency::EUR, IsoCurrency::CHF, "3"),
+        rate(IsoCurrency::CHF, IsoCurrency::GBP, "2"),
+    ])
+    .unwrap();
+    let resolved = book
+        .resolve(&ccy(IsoCurrency::EUR), &ccy(IsoCurrency::GBP))
+        .unwrap();
+    assert_eq!(resolved.rate.rate(), dec("6"));
+    assert_eq!(resolved.legs.len(), 2);
+}
+
+#[test]
+fn shorter_path_wins_over_longer_disagreeing_path() {
+    let book = RateBook::from_rates([
+        rate(IsoCurrency::EUR, IsoCurrency::USD, "2"),
+        rate(IsoCurrency::USD, IsoCurrency::GBP, "3"),
+        rate(IsoCurrency::EUR, IsoCurrency::CHF, "1"),
+        rate(IsoCurrency::CHF, IsoCurrency::JPY, "1"),
+        rate(IsoCurrency::JPY, IsoCurrency::GBP, "1"),
+    ])
+    .unwrap();
+    let resolved = book
+        .resolve(&ccy(IsoCurrency::EUR), &ccy(IsoCurrency::GBP))
+        .unwrap();
+    assert_eq!(resolved.rate.rate(), dec("6"));
+}
+
+#[test]
+fn unreachable_pair_is_not_found() {
+    let book = RateBook::from_rates([
+        rate(IsoCurrency::USD, IsoCurrency::EUR, "0.9"),
+        rate(IsoCurrency::GBP, IsoCurrency::CHF, "1.1"),
+    ])
+    .unwrap()
+    .with_pivot(ccy(IsoCurrency::USD));
+    assert_eq!(
+        book.resolve(&ccy(IsoCurrency::EUR), &ccy(IsoCurrency::CHF)),
+        Err(MoneyError::ExchangeRateNotFound {
+            from: ccy(IsoCurrency::EUR),
+            to: ccy(IsoCurrency::CHF),
+        })
+    );
+}
+
+#[test]
+fn insert_rejects_conflicting_rates() {
+    let mut book = RateBook::new();
+    book.insert(rate(IsoCurrency::USD, IsoCurrency::EUR, "0.8"))
+        .unwrap();
+    // Re-inserting the same rate, an identity rate, or the exact inverse is fine.
+    book.insert(rate(IsoCurrency::USD, IsoCurrency::EUR, "0.8"))
+        .unwrap();
+    book.insert(rate(IsoCurrency::USD, IsoCurrency::USD, "1"))
+        .unwrap();
+    book.insert(rate(IsoCurrency::EUR, IsoCurrency::USD, "1.25"))
+        .unwrap();
+    assert_eq!(book.len(), 1);
+
+    assert_eq!(
+        book.insert(rate(IsoCurrency::USD, IsoCurrency::EUR, "0.9")),
+        Err(MoneyError::ConflictingExchangeRate {
+            from: ccy(IsoCurrency::USD),
+            to: ccy(IsoCurrency::EUR),
+            existing: dec("0.8"),
+        })
+    );
+    assert_eq!(
+        book.insert(rate(IsoCurrency::EUR, IsoCurrency::USD, "1.2")),
+        Err(MoneyError::ConflictingExchangeRate {
+            from: ccy(IsoCurrency::EUR),
+            to: ccy(IsoCurrency::USD),
+            existing: dec("1.25"),
+        })
+    );
+}
+
+#[test]
+fn replace_and_remove_overwrite_held_rates() {
+    let mut book = RateBook::from_rates([rate(IsoCurrency::USD, IsoCurrency::EUR, "0.8")]).unwrap();
+    let previous = book.replace(rate(IsoCurrency::EUR, IsoCurrency::USD, "1.1"));
+    assert_eq!(
+        previous,
+        Some(rate(IsoCurrency::USD, IsoCurrency::EUR, "0.8"))
+    );
+    assert_eq!(book.len(), 1);
+    assert!(
+        book.direct(&ccy(IsoCurrency::USD), &ccy(IsoCurrency::EUR))
+            .is_none()
+    );
+    assert_eq!(
+        book.rates().cloned().collect::<Vec<_>>(),
+        vec![rate(IsoCurrency::EUR, IsoCurrency::USD, "1.1")]
+    );
+
+    assert!(
+        book.remove(&ccy(IsoCurrency::EUR), &ccy(IsoCurrency::USD))
+            .is_some()
+    );
+    assert!(book.is_empty());
+}
+
+#[test]
+fn convert_uses_resolved_rate_and_rounding() {
+    let book = RateBook::from_rates([
+        rate(IsoCurrency::USD, IsoCurrency::EUR, "0.5"),
+        rate(IsoCurrency::GBP, IsoCurrency::EUR, "3"),
+    ])
+    .unwrap();
+    let usd = Money::from_canonical_str("10.00", ccy(IsoCurrency::USD)).unwrap();
+
+    let gbp = book.convert(&usd, &ccy(IsoCurrency::GBP)).unwrap();
+    assert_eq!(gbp.format(), "1.67 GBP");
+
+    let floored = book
+        .convert_with(&usd, &ccy(IsoCurrency::GBP), RoundingStrategy::ToZero)
+        .unwrap();
+    assert_eq!(floored.format(), "1.66 GBP");
+
+    assert!(matches!(
+        book.convert(&usd, &ccy(IsoCurrency::JPY)),
+        Err(MoneyError::ExchangeRateNotFound { .. })
+    ));
+}
+
+#[test]
+fn resolved_rate_serializes_path() {
+    let book = RateBook::from_rates([rate(IsoCurrency::USD, IsoCurrency::EUR, "0.8")]).unwrap();
+    let resolved = book
+        .resolve(&ccy(IsoCurrency::EUR), &ccy(IsoCurrency::USD))
+        .unwrap();
+    let json = serde_json::to_value(&resolved).unwrap();
+    assert_eq!(json["method"]["kind"], "inverse");
+    assert_eq!(json["legs"][0]["inverted"], true);
+    let back: paft_money::ResolvedRate = serde_json::from_value(json).unwrap();
+    assert_eq!(back, resolved);
+}
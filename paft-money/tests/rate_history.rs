@@ -0,0 +1,192 @@
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use iso_currency::Currency as IsoCurrency;
+use paft_decimal::{Decimal, RoundingStrategy};
+use paft_money::{AsOfMode, Currency, ExchangeRate, Money, MoneyError, RateHistory};
+
+const fn ccy(code: IsoCurrency) -> Currency {
+    Currency::Iso(code)
+}
+
+const fn day(d: u32) -> DateTime<Utc> {
+    NaiveDate::from_ymd_opt(2024, 1, d)
+        .unwrap()
+        .and_time(NaiveTime::MIN)
+        .and_utc()
+}
+
+fn eur_usd(value: &str) -> ExchangeRate {
+    ExchangeRate::new(
+        ccy(IsoCurrency::EUR),
+        ccy(IsoCurrency::USD),
+        value.parse().unwrap(),
+    )
+    .unwrap()
+}
+
+fn dec(value: &str) -> Decimal {
+    value.parse().unwrap()
+}
+
+fn history() -> RateHistory {
+    let mut history = RateHistory::new();
+    history.insert(day(2), eur_usd("1.10"));
+    history.insert(day(5), eur_usd("1.20"));
+    history.insert(day(10), eur_usd("1.25"));
+    history
+}
+
+#[test]
+fn last_known_uses_latest_observation_at_or_before() {
+    let history = history();
+    let eur = ccy(IsoCurrency::EUR);
+    let usd = ccy(IsoCurrency::USD);
+
+    let on_day = history.rate_at(&eur, &usd, day(5)).unwrap();
+    assert_eq!(on_day.ts, day(5));
+    assert_eq!(on_day.rate.rate(), dec("1.20"));
+
+    let later = history.rate_at(&eur, &usd, day(9)).unwrap();
+    assert_eq!(later.ts, day(5));
+
+    assert_eq!(
+        history.rate_at(&eur, &usd, day(1)),
+        Err(MoneyError::ExchangeRateNotFound { from: eur, to: usd })
+    );
+}
+
+#[test]
+fn nearest_picks_closest_and_prefers_earlier_on_ties() {
+    let history = history().with_mode(AsOfMode::Nearest);
+    let eur = ccy(IsoCurrency::EUR);
+    let usd = ccy(IsoCurrency::USD);
+
+    assert_eq!(history.rate_at(&eur, &usd, day(1)).unwrap().ts, day(2));
+    assert_eq!(history.rate_at(&eur, &usd, day(9)).unwrap().ts, day(10));
+    // Day 3 is one day after day 2 and two days before day 5.
+    assert_eq!(history.rate_at(&eur, &usd, day(3)).unwrap().ts, day(2));
+    // Day 7 12:00 is equidistant from days 5 and 10; the earlier wins.
+    let midpoint = day(7) + chrono::Duration::hours(12);
+    assert_eq!(history.rate_at(&eur, &usd, midpoint).unwrap().ts, day(5));
+    assert_eq!(history.rate_at(&eur, &usd, day(31)).unwrap().ts, day(10));
+}
+
+#[test]
+fn exact_requires_matching_timestamp() {
+    let history = history();
+    let eur = ccy(IsoCurrency::EUR);
+    let usd = ccy(IsoCurrency::USD);
+
+    let exact = history
+        .rate_at_with(&eur, &usd, day(10), AsOfMode::Exact)
+        .unwrap();
+    assert_eq!(exact.rate.rate(), dec("1.25"));
+    assert!(matches!(
+        history.rate_at_with(&eur, &usd, day(9), AsOfMode::Exact),
+        Err(MoneyError::ExchangeRateNotFound { .. })
+    ));
+}
+
+#[test]
+fn reverse_series_is_inverted() {
+    let history = history();
+    let observation = history
+        .rate_at(&ccy(IsoCurrency::USD), &ccy(IsoCurrency::EUR), day(12))
+        .unwrap();
+    assert_eq!(observation.ts, day(10));
+    assert_eq!(observation.rate.from(), &ccy(IsoCurrency::USD));
+    assert_eq!(observation.rate.rate(), dec("0.8"));
+}
+
+#[test]
+fn identity_pair_resolves_at_requested_time() {
+    let history = RateHistory::new();
+    let observation = history
+        .rate_at(&ccy(IsoCurrency::USD), &ccy(IsoCurrency::USD), day(3))
+        .unwrap();
+    assert_eq!(observation.ts, day(3));
+    assert_eq!(observation.rate.rate(), Decimal::from(1));
+}
+
+#[test]
+fn convert_at_uses_rate_valid_on_each_date() {
+    let history = history();
+    let usd = ccy(IsoCurrency::USD);
+    let eur_10 = Money::from_canonical_str("10.00", ccy(IsoCurrency::EUR)).unwrap();
+
+    assert_eq!(
+        history.convert_at(&eur_10, &usd, day(3)).unwrap().format(),
+        "11 USD"
+    );
+    assert_eq!(
+        history.convert_at(&eur_10, &usd, day(11)).unwrap().format(),
+        "12.5 USD"
+    );
+
+    let usd_1 = Money::from_canonical_str("1.00", usd).unwrap();
+    let eur = ccy(IsoCurrency::EUR);
+    assert_eq!(
+        history.convert_at(&usd_1, &eur, day(3)).unwrap().format(),
+        "0.91 EUR"
+    );
+    assert_eq!(
+        history
+            .convert_at_with(&usd_1, &eur, day(3), RoundingStrategy::AwayFromZero)
+            .unwrap()
+            .format(),
+        "0.91 EUR"
+    );
+    assert_eq!(
+        history
+            .convert_at_with(&usd_1, &eur, day(3), RoundingStrategy::ToZero)
+            .unwrap()
+            .format(),
+        "0.9 EUR"
+    );
+}
+
+#[test]
+fn insert_replaces_same_instant_and_tracks_pairs() {
+    let mut history = history();
+    assert_eq!(history.len(), 3);
+    assert_eq!(
+        history.insert(day(5), eur_usd("1.21")),
+        Some(eur_usd("1.20"))
+    );
+    assert_eq!(history.len(), 3);
+
+    let gbp_usd =
+        ExchangeRate::new(ccy(IsoCurrency::GBP), ccy(IsoCurrency::USD), dec("1.27")).unwrap();
+    history.insert(day(1), gbp_usd.clone());
+
+    let eur = ccy(IsoCurrency::EUR);
+    let usd = ccy(IsoCurrency::USD);
+    let gbp = ccy(IsoCurrency::GBP);
+    assert_eq!(
+        history.pairs().collect::<Vec<_>>(),
+        vec![(&eur, &usd), (&gbp, &usd)]
+    );
+    assert_eq!(history.series(&eur, &usd).count(), 3);
+    assert!(history.series(&usd, &eur).next().is_none());
+
+    let observations = history.observations();
+    assert_eq!(observations.len(), 4);
+    assert_eq!(observations[1].rate.rate(), dec("1.21"));
+    assert_eq!(observations[3].rate, gbp_usd);
+}
+
+#[test]
+fn observation_serde_uses_millisecond_timestamps() {
+    let observation = history()
+        .rate_at(&ccy(IsoCurrency::EUR), &ccy(IsoCurrency::USD), day(2))
+        .unwrap();
+    let json = serde_json::to_value(&observation).unwrap();
+    assert_eq!(json["ts"], 1_704_153_600_000_i64);
+    assert_eq!(json["rate"]["rate"], "1.1");
+    let back: paft_money::RateObservation = serde_json::from_value(json).unwrap();
+    assert_eq!(back, observation);
+
+    assert_eq!(
+        serde_json::to_string(&AsOfMode::LastKnown).unwrap(),
+        "\"last_known\""
+    );
+}
@@ -0,0 +1,160 @@
+use std::num::NonZeroU32;
+
+use paft_decimal::{Decimal, RoundingStrategy};
+use paft_money::{
+    Currency, CurrencyRegistry, IsoCurrency, Locale, MinorUnitError, Money, MoneyError,
+    currency_metadata,
+};
+
+fn money(amount: &str, code: IsoCurrency) -> Money {
+    Money::from_canonical_str(amount, Currency::Iso(code)).unwrap()
+}
+
+fn decimal(value: &str) -> Decimal {
+    value.parse().unwrap()
+}
+
+#[test]
+fn rounds_to_arbitrary_increments_with_each_strategy() {
+    let total = money("12.375", IsoCurrency::BHD);
+    let quarter = decimal("0.25");
+    let cases = [
+        (RoundingStrategy::MidpointAwayFromZero, "12.5"),
+        (RoundingStrategy::MidpointNearestEven, "12.5"),
+        (RoundingStrategy::ToZero, "12.25"),
+        (RoundingStrategy::ToNegativeInfinity, "12.25"),
+        (RoundingStrategy::AwayFromZero, "12.5"),
+    ];
+    for (strategy, expected) in cases {
+        let rounded = total.round_to_increment(&quarter, strategy).unwrap();
+        assert_eq!(rounded, money(expected, IsoCurrency::BHD), "{strategy:?}");
+        assert_eq!(rounded.minor_units(), 3);
+    }
+
+    let midpoint = money("-0.125", IsoCurrency::BHD);
+    assert_eq!(
+        midpoint
+            .round_to_increment(&quarter, RoundingStrategy::MidpointAwayFromZero)
+            .unwrap(),
+        money("-0.25", IsoCurrency::BHD)
+    );
+    assert_eq!(
+        midpoint
+            .round_to_increment(&quarter, RoundingStrategy::MidpointNearestEven)
+            .unwrap(),
+        money("0", IsoCurrency::BHD)
+    );
+}
+
+#[test]
+fn increments_must_be_positive_multiples_of_the_minor_unit() {
+    let usd = money("1.23", IsoCurrency::USD);
+    for increment in ["0", "-0.05", "0.005"] {
+        assert_eq!(
+            usd.round_to_increment(&decimal(increment), RoundingStrategy::ToZero),
+            Err(MoneyError::InvalidRoundingIncrement {
+                increment: decimal(increment),
+                minor_units: 2,
+            })
+        );
+    }
+    assert_eq!(
+        usd.round_to_increment(&decimal("0.010"), RoundingStrategy::ToZero)
+            .unwrap(),
+        usd
+    );
+    assert_eq!(
+        usd.round_to_increment(&decimal("10"), RoundingStrategy::AwayFromZero)
+            .unwrap(),
+        money("10", IsoCurrency::USD)
+    );
+}
+
+#[test]
+fn cash_rounding_uses_built_in_increments() {
+    let half_up = RoundingStrategy::MidpointAwayFromZero;
+    assert_eq!(
+        money("12.37", IsoCurrency::CHF)
+            .round_to_cash(half_up)
+            .unwrap(),
+        money("12.35", IsoCurrency::CHF)
+    );
+    assert_eq!(
+        money("12.38", IsoCurrency::CHF)
+            .round_to_cash(half_up)
+            .unwrap(),
+        money("12.4", IsoCurrency::CHF)
+    );
+    assert_eq!(
+        money("99.50", IsoCurrency::SEK)
+            .round_to_cash(half_up)
+            .unwrap(),
+        money("100", IsoCurrency::SEK)
+    );
+    assert_eq!(
+        money("-7.26", IsoCurrency::DKK)
+            .round_to_cash(half_up)
+            .unwrap(),
+        money("-7.5", IsoCurrency::DKK)
+    );
+    // No cash increment: unchanged.
+    assert_eq!(
+        money("1.01", IsoCurrency::USD)
+            .round_to_cash(half_up)
+            .unwrap(),
+        money("1.01", IsoCurrency::USD)
+    );
+    assert_eq!(
+        currency_metadata("CHF").unwrap().cash_increment,
+        NonZeroU32::new(5)
+    );
+}
+
+#[test]
+fn cash_increments_are_configurable_per_registry() {
+    let tenant = CurrencyRegistry::new();
+    let usd = Currency::Iso(IsoCurrency::USD);
+    assert_eq!(tenant.cash_increment(&usd), None);
+    assert_eq!(
+        tenant.set_cash_increment("USD", NonZeroU32::new(25)),
+        Ok(None)
+    );
+    assert_eq!(tenant.cash_increment(&usd), NonZeroU32::new(25));
+    assert_eq!(
+        tenant.set_cash_increment("ZAR", NonZeroU32::new(10)),
+        Ok(None)
+    );
+    let zar = tenant
+        .metadata("ZAR")
+        .expect("ISO currencies without metadata get an entry");
+    assert_eq!((zar.minor_units, zar.symbol.as_ref()), (2, "ZAR"));
+    assert_eq!(
+        money("1.13", IsoCurrency::USD)
+            .round_to_cash_in(&tenant, RoundingStrategy::MidpointAwayFromZero)
+            .unwrap(),
+        money("1.25", IsoCurrency::USD)
+    );
+    assert_eq!(CurrencyRegistry::global().cash_increment(&usd), None);
+
+    // Removing the built-in CHF increment disables cash rounding.
+    let chf = Currency::Iso(IsoCurrency::CHF);
+    assert_eq!(
+        tenant.set_cash_increment("CHF", None),
+        Ok(NonZeroU32::new(5))
+    );
+    assert_eq!(tenant.cash_increment(&chf), None);
+
+    // Registering display metadata keeps the increment.
+    let sek = Currency::Iso(IsoCurrency::SEK);
+    tenant
+        .set_metadata("SEK", "Krona", 2, "kr", false, Locale::EnEu)
+        .unwrap();
+    assert_eq!(tenant.cash_increment(&sek), NonZeroU32::new(100));
+
+    assert_eq!(
+        tenant.set_cash_increment("XAU", NonZeroU32::new(5)),
+        Err(MinorUnitError::UnknownMinorUnits {
+            code: "XAU".to_string()
+        })
+    );
+}
@@ -0,0 +1,130 @@
+use paft_money::{Currency, IsoCurrency, MonetaryAmount, Money, Price};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct MinorRecord {
+    #[serde(with = "paft_money::serde::minor_units")]
+    money: Money,
+    #[serde(with = "paft_money::serde::minor_units")]
+    price: Price,
+    #[serde(with = "paft_money::serde::minor_units")]
+    total: MonetaryAmount,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct ScaledRecord {
+    #[serde(with = "paft_money::serde::scaled_units")]
+    money: Money,
+    #[serde(with = "paft_money::serde::scaled_units")]
+    price: Price,
+    #[serde(with = "paft_money::serde::scaled_units")]
+    total: MonetaryAmount,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct MinorMoney(#[serde(with = "paft_money::serde::minor_units")] Money);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct MinorPrice(#[serde(with = "paft_money::serde::minor_units")] Price);
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct ScaledMoney(#[serde(with = "paft_money::serde::scaled_units")] Money);
+
+const fn iso(code: IsoCurrency) -> Currency {
+    Currency::Iso(code)
+}
+
+#[test]
+fn minor_units_round_trip() {
+    let record = MinorRecord {
+        money: Money::from_canonical_str("-123.45", iso(IsoCurrency::USD)).unwrap(),
+        price: Price::from_canonical_str("1500", iso(IsoCurrency::JPY)).unwrap(),
+        total: MonetaryAmount::from_canonical_str("0.00000001", Currency::BTC).unwrap(),
+    };
+    let value = serde_json::to_value(&record).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "money": {"minor": -12345, "currency": "USD"},
+            "price": {"minor": 1500, "currency": "JPY"},
+            "total": {"minor": 1, "currency": "BTC"},
+        })
+    );
+    let back: MinorRecord = serde_json::from_value(value).unwrap();
+    assert_eq!(back, record);
+    assert_eq!(back.money.minor_units(), 2);
+}
+
+#[test]
+fn scaled_units_round_trip_and_keep_trailing_zeros() {
+    let record = ScaledRecord {
+        money: Money::from_canonical_str("12.3", iso(IsoCurrency::EUR)).unwrap(),
+        price: Price::from_canonical_str("101.2500", iso(IsoCurrency::USD)).unwrap(),
+        total: MonetaryAmount::from_canonical_str("7", iso(IsoCurrency::GBP)).unwrap(),
+    };
+    let value = serde_json::to_value(&record).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "money": {"units": 1230, "scale": 2, "currency": "EUR"},
+            "price": {"units": 1_012_500, "scale": 4, "currency": "USD"},
+            "total": {"units": 7, "scale": 0, "currency": "GBP"},
+        })
+    );
+    let back: ScaledRecord = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(back, record);
+    assert_eq!(serde_json::to_value(&back).unwrap(), value);
+
+    // Money accepts any scale that is exact at its minor units.
+    let money: ScaledMoney =
+        serde_json::from_value(json!({"units": 12300, "scale": 4, "currency": "USD"})).unwrap();
+    assert_eq!(money.0.format(), "1.23 USD");
+}
+
+#[test]
+fn amounts_beyond_i64_units_fail_to_serialize() {
+    let large = Money::from_canonical_str("100000000000000000", iso(IsoCurrency::USD)).unwrap();
+    let err = serde_json::to_string(&MinorMoney(large.clone())).unwrap_err();
+    assert!(
+        err.to_string()
+            .ends_with("does not fit in 64-bit integer units at scale 2"),
+        "{err}"
+    );
+    assert!(serde_json::to_string(&ScaledMoney(large)).is_err());
+
+    let fits = Money::from_minor_units(i128::from(i64::MAX), iso(IsoCurrency::USD)).unwrap();
+    let json = serde_json::to_string(&MinorMoney(fits.clone())).unwrap();
+    assert_eq!(serde_json::from_str::<MinorMoney>(&json).unwrap().0, fits);
+
+    assert!(
+        serde_json::from_value::<MinorMoney>(
+            json!({"minor": 9_223_372_036_854_775_808_u64, "currency": "USD"})
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn precision_and_scale_violations_are_rejected() {
+    let sub_cent = Price::from_canonical_str("12.345", iso(IsoCurrency::USD)).unwrap();
+    let err = serde_json::to_string(&MinorPrice(sub_cent)).unwrap_err();
+    assert!(err.to_string().starts_with("precision exceeded for USD"));
+
+    let err = serde_json::from_value::<ScaledMoney>(
+        json!({"units": 12345, "scale": 3, "currency": "USD"}),
+    )
+    .unwrap_err();
+    assert!(err.to_string().starts_with("precision exceeded for USD"));
+
+    let err =
+        serde_json::from_value::<MinorMoney>(json!({"minor": 1, "currency": "ZZQ"})).unwrap_err();
+    assert!(err.to_string().contains("metadata not registered"));
+
+    assert!(
+        serde_json::from_value::<MinorMoney>(
+            json!({"minor": 1, "currency": "USD", "minor_units": 2})
+        )
+        .is_err()
+    );
+}
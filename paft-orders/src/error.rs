@@ -0,0 +1,85 @@
+//! Error types specific to `paft-orders`.
+
+use paft_decimal::Decimal;
+use paft_market::MarketError;
+use paft_money::MoneyError;
+use thiserror::Error;
+
+use crate::order::OrderType;
+use crate::status::OrderStatus;
+
+/// Errors returned when building orders or recording their executions.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum OrderError {
+    /// String value did not match any modeled order enum code.
+    #[error("{enum_name}: invalid enum value '{value}'")]
+    InvalidEnumValue {
+        /// Enum type that rejected the value.
+        enum_name: &'static str,
+        /// Rejected input value.
+        value: String,
+    },
+
+    /// Order quantity must be greater than zero.
+    #[error("Order quantity must be greater than zero")]
+    ZeroQuantity,
+
+    /// Order type requires a price that was not set.
+    #[error("{order_type} orders require a {field}")]
+    MissingPrice {
+        /// Order type being built.
+        order_type: OrderType,
+        /// Missing price field, `"limit_price"` or `"stop_price"`.
+        field: &'static str,
+    },
+
+    /// Order type does not take a price that was set.
+    #[error("{order_type} orders do not take a {field}")]
+    UnexpectedPrice {
+        /// Order type being built.
+        order_type: OrderType,
+        /// Unexpected price field, `"limit_price"` or `"stop_price"`.
+        field: &'static str,
+    },
+
+    /// Order fields are inconsistent.
+    #[error("Invalid order: {reason}")]
+    InvalidOrder {
+        /// Validation failure reason.
+        reason: &'static str,
+    },
+
+    /// Order status cannot move from `from` to `to`.
+    #[error("Order status cannot change from {from} to {to}")]
+    InvalidTransition {
+        /// Current status.
+        from: OrderStatus,
+        /// Rejected next status.
+        to: OrderStatus,
+    },
+
+    /// Fill does not belong to the execution it was applied to.
+    #[error("Fill does not match the order: {reason}")]
+    FillMismatch {
+        /// Mismatch reason.
+        reason: &'static str,
+    },
+
+    /// Fills exceed the ordered quantity.
+    #[error("Fills total {filled}, exceeding the ordered quantity {ordered}")]
+    Overfill {
+        /// Ordered quantity.
+        ordered: Decimal,
+        /// Filled quantity including the rejected fill.
+        filled: Decimal,
+    },
+
+    /// Price failed tick-size validation.
+    #[error(transparent)]
+    Market(#[from] MarketError),
+
+    /// Monetary arithmetic failed.
+    #[error(transparent)]
+    Money(#[from] MoneyError),
+}
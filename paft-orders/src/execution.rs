@@ -0,0 +1,293 @@
+//! Fill and execution records under the `paft_orders::execution` namespace.
+//!
+//! A [`GenericFill`] is one partial or complete execution reported by a
+//! venue. A [`GenericExecution`] tracks an order's status and fills, and
+//! [`GenericExecution::apply_fill`] keeps the two consistent.
+
+use chrono::{DateTime, Utc};
+use paft_decimal::{self as decimal, Decimal};
+use paft_domain::Instrument;
+use paft_money::{MonetaryAmount, Money, MoneyError, Price, QuantityAmount};
+use serde::{Deserialize, Serialize};
+
+use crate::error::OrderError;
+use crate::order::{OrderRequest, OrderSide};
+use crate::status::OrderStatus;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+/// One execution of part or all of an order.
+///
+/// Generic over a provider metadata payload `M`, which is flattened into the
+/// serialized representation. Use the [`Fill`] alias for the standard shape
+/// (no extra metadata).
+///
+/// **Collision warning:** provider metadata is flattened into the same object
+/// as paft fields. Metadata field names must not collide with paft field
+/// names; prefer provider-specific prefixes when in doubt.
+pub struct GenericFill<M = ()> {
+    /// Venue- or broker-assigned fill identifier.
+    pub fill_id: Option<String>,
+    /// Identifier of the order this fill belongs to.
+    pub order_id: Option<String>,
+    /// Instrument traded.
+    pub instrument: Instrument,
+    /// Direction of the fill.
+    pub side: OrderSide,
+    /// Units executed.
+    pub quantity: QuantityAmount,
+    /// Execution price per unit.
+    pub price: Price,
+    /// Commission and other charges for this fill.
+    pub fee: Option<Money>,
+    /// Execution time.
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub timestamp: DateTime<Utc>,
+    /// Provider-specific payload, flattened into the serialized form.
+    #[serde(flatten, default = "Default::default")]
+    pub provider: M,
+}
+
+/// Standard fill without provider metadata.
+pub type Fill = GenericFill<()>;
+
+impl<M: Default> GenericFill<M> {
+    /// Build a fill with no identifiers or fee. `provider` is initialised via
+    /// `M::default()`.
+    #[must_use]
+    pub fn new(
+        instrument: Instrument,
+        side: OrderSide,
+        quantity: QuantityAmount,
+        price: Price,
+        timestamp: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            fill_id: None,
+            order_id: None,
+            instrument,
+            side,
+            quantity,
+            price,
+            fee: None,
+            timestamp,
+            provider: M::default(),
+        }
+    }
+}
+
+impl<M> GenericFill<M> {
+    /// Gross traded value: price times quantity, before fees.
+    ///
+    /// # Errors
+    /// Returns [`OrderError::Money`] when the active decimal backend
+    /// overflows.
+    pub fn notional(&self) -> Result<MonetaryAmount, OrderError> {
+        Ok(self.price.try_total(&self.quantity)?)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+/// Execution state of one order: its status and the fills received so far.
+///
+/// Generic over an execution metadata payload `M` and a fill metadata
+/// payload `F`, both flattened into their serialized objects. Use the
+/// [`Execution`] alias for the standard shape (no extra metadata).
+///
+/// Fields are public so provider snapshots can be loaded as reported; use
+/// [`GenericExecution::apply_fill`] and [`GenericExecution::transition`] to
+/// update an execution with status-transition validation.
+///
+/// **Collision warning:** provider metadata is flattened into the same object
+/// as paft fields. Metadata field names must not collide with paft field
+/// names; prefer provider-specific prefixes when in doubt.
+pub struct GenericExecution<M = (), F = ()> {
+    /// Venue- or broker-assigned order identifier.
+    pub order_id: String,
+    /// Caller-assigned order identifier.
+    pub client_order_id: Option<String>,
+    /// Instrument ordered.
+    pub instrument: Instrument,
+    /// Order direction.
+    pub side: OrderSide,
+    /// Ordered quantity.
+    pub quantity: QuantityAmount,
+    /// Current order status.
+    pub status: OrderStatus,
+    /// Fills received, in arrival order.
+    pub fills: Vec<GenericFill<F>>,
+    /// Time of the latest status change or fill.
+    #[serde(default, with = "chrono::serde::ts_milliseconds_option")]
+    pub updated_at: Option<DateTime<Utc>>,
+    /// Provider-specific payload, flattened into the serialized form.
+    #[serde(flatten, default = "Default::default")]
+    pub provider: M,
+}
+
+/// Standard execution without provider metadata.
+pub type Execution = GenericExecution<(), ()>;
+
+impl<M: Default, F> GenericExecution<M, F> {
+    /// Start tracking an order in [`OrderStatus::PendingNew`] with no fills.
+    /// `provider` is initialised via `M::default()`.
+    #[must_use]
+    pub fn new(
+        order_id: impl Into<String>,
+        instrument: Instrument,
+        side: OrderSide,
+        quantity: QuantityAmount,
+    ) -> Self {
+        Self {
+            order_id: order_id.into(),
+            client_order_id: None,
+            instrument,
+            side,
+            quantity,
+            status: OrderStatus::PendingNew,
+            fills: Vec::new(),
+            updated_at: None,
+            provider: M::default(),
+        }
+    }
+
+    /// Start tracking a submitted `request` under the venue's `order_id`.
+    #[must_use]
+    pub fn from_request(order_id: impl Into<String>, request: &OrderRequest) -> Self {
+        let mut execution = Self::new(
+            order_id,
+            request.instrument().clone(),
+            request.side(),
+            request.quantity().clone(),
+        );
+        execution.client_order_id = request.client_order_id().map(str::to_owned);
+        execution
+    }
+}
+
+impl<M, F> GenericExecution<M, F> {
+    /// Total units filled across every fill.
+    ///
+    /// # Errors
+    /// Returns [`OrderError::Money`] when the active decimal backend
+    /// overflows.
+    pub fn filled_quantity(&self) -> Result<QuantityAmount, OrderError> {
+        let total = self.filled_decimal()?;
+        Ok(QuantityAmount::from_decimal(total).map_err(|_| MoneyError::ConversionError)?)
+    }
+
+    /// Units still to be filled; zero once the order is complete.
+    ///
+    /// # Errors
+    /// Returns [`OrderError::Overfill`] when the recorded fills exceed the
+    /// ordered quantity, or [`OrderError::Money`] when the active decimal
+    /// backend overflows.
+    pub fn remaining_quantity(&self) -> Result<QuantityAmount, OrderError> {
+        let filled = self.filled_decimal()?;
+        let remaining = checked(decimal::checked_sub(self.quantity.as_decimal(), &filled))?;
+        QuantityAmount::from_decimal(remaining).map_err(|_| OrderError::Overfill {
+            ordered: decimal::clone_decimal(self.quantity.as_decimal()),
+            filled,
+        })
+    }
+
+    /// Quantity-weighted average fill price, or `None` before the first fill.
+    ///
+    /// # Errors
+    /// Returns [`OrderError::Money`] when fills are priced in different
+    /// currencies or the active decimal backend overflows.
+    pub fn average_price(&self) -> Result<Option<Price>, OrderError> {
+        let Some((first, rest)) = self.fills.split_first() else {
+            return Ok(None);
+        };
+        let mut notional = first.notional()?;
+        for fill in rest {
+            notional = notional.try_add(&fill.notional()?)?;
+        }
+        let filled = self.filled_decimal()?;
+        if filled == decimal::zero() {
+            return Ok(None);
+        }
+        let unit = notional.try_div(&filled)?;
+        Ok(Some(Price::new(unit.amount(), unit.currency().clone())))
+    }
+
+    /// Move the order to `status`.
+    ///
+    /// # Errors
+    /// Returns [`OrderError::InvalidTransition`] when the current status
+    /// cannot move to `status`; the execution is left unchanged.
+    pub fn transition(&mut self, status: OrderStatus) -> Result<(), OrderError> {
+        self.status.validate_transition(&status)?;
+        self.status = status;
+        Ok(())
+    }
+
+    /// Record `fill` and move the order to
+    /// [`OrderStatus::PartiallyFilled`] or [`OrderStatus::Filled`].
+    ///
+    /// The update is atomic: on error the execution is left unchanged.
+    ///
+    /// # Errors
+    /// - [`OrderError::FillMismatch`] when the fill names another order,
+    ///   instrument, or side.
+    /// - [`OrderError::ZeroQuantity`] when the fill quantity is zero.
+    /// - [`OrderError::Overfill`] when the fill would exceed the ordered
+    ///   quantity.
+    /// - [`OrderError::InvalidTransition`] when the current status cannot
+    ///   receive fills, such as after a cancel.
+    /// - [`OrderError::Money`] when the active decimal backend overflows.
+    pub fn apply_fill(&mut self, fill: GenericFill<F>) -> Result<(), OrderError> {
+        let mismatch = |reason| Err(OrderError::FillMismatch { reason });
+        if fill
+            .order_id
+            .as_ref()
+            .is_some_and(|order_id| *order_id != self.order_id)
+        {
+            return mismatch("order id differs");
+        }
+        if fill.instrument != self.instrument {
+            return mismatch("instrument differs");
+        }
+        if fill.side != self.side {
+            return mismatch("side differs");
+        }
+        if *fill.quantity.as_decimal() == decimal::zero() {
+            return Err(OrderError::ZeroQuantity);
+        }
+
+        let filled = checked(decimal::checked_add(
+            &self.filled_decimal()?,
+            fill.quantity.as_decimal(),
+        ))?;
+        let ordered = self.quantity.as_decimal();
+        if filled > *ordered {
+            return Err(OrderError::Overfill {
+                ordered: decimal::clone_decimal(ordered),
+                filled,
+            });
+        }
+        let status = if filled == *ordered {
+            OrderStatus::Filled
+        } else {
+            OrderStatus::PartiallyFilled
+        };
+        self.status.validate_transition(&status)?;
+
+        self.status = status;
+        self.updated_at = Some(
+            self.updated_at
+                .map_or(fill.timestamp, |updated_at| updated_at.max(fill.timestamp)),
+        );
+        self.fills.push(fill);
+        Ok(())
+    }
+
+    fn filled_decimal(&self) -> Result<Decimal, OrderError> {
+        self.fills.iter().try_fold(decimal::zero(), |total, fill| {
+            checked(decimal::checked_add(&total, fill.quantity.as_decimal()))
+        })
+    }
+}
+
+fn checked(value: Option<Decimal>) -> Result<Decimal, OrderError> {
+    value.ok_or(OrderError::Money(MoneyError::ConversionError))
+}
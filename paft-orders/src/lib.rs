@@ -0,0 +1,26 @@
+//! Order requests and execution records for the paft ecosystem.
+//!
+//! This crate gives broker integrations one shared order shape on top of
+//! `paft-domain` instruments, `paft-money` prices, and `paft-market` tick
+//! sizes:
+//!
+//! - [`OrderRequest`] is built through [`OrderRequestBuilder`], which checks
+//!   the quantity, the prices each [`OrderType`] needs, the
+//!   [`TimeInForce`] expiry, and optionally a `TickSizeTable`.
+//! - [`OrderStatus`] is an open lifecycle enum with state-transition
+//!   validation.
+//! - [`GenericFill`] and [`GenericExecution`] record fills and keep an
+//!   order's status consistent with its filled quantity.
+
+#![forbid(unsafe_code)]
+#![warn(missing_docs)]
+
+pub mod error;
+pub mod execution;
+pub mod order;
+pub mod status;
+
+pub use error::OrderError;
+pub use execution::{Execution, Fill, GenericExecution, GenericFill};
+pub use order::{OrderRequest, OrderRequestBuilder, OrderSide, OrderType, TimeInForce};
+pub use status::{OrderStatus, OtherOrderStatus};
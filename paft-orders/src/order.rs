@@ -0,0 +1,506 @@
+//! Order request types under the `paft_orders::order` namespace.
+
+use chrono::{DateTime, Utc};
+use paft_domain::Instrument;
+use paft_market::TickSizeTable;
+use paft_money::{Price, PriceAmount, QuantityAmount};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::error::OrderError;
+
+/// Direction of an order or fill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OrderSide {
+    /// Buy the instrument.
+    Buy,
+    /// Sell the instrument.
+    Sell,
+}
+
+paft_core::string_enum_closed_with_code!(
+    OrderSide, "OrderSide",
+    type Error = OrderError;
+    invalid(input) => OrderError::InvalidEnumValue {
+        enum_name: "OrderSide",
+        value: input.to_string(),
+    };
+    { "BUY" => OrderSide::Buy, "SELL" => OrderSide::Sell },
+    { "B" => OrderSide::Buy, "S" => OrderSide::Sell }
+);
+
+paft_core::impl_display_via_code!(OrderSide);
+
+/// Pricing instruction of an order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum OrderType {
+    /// Execute immediately at the best available price.
+    #[default]
+    Market,
+    /// Execute at the limit price or better.
+    Limit,
+    /// Become a market order once the stop price trades.
+    Stop,
+    /// Become a limit order once the stop price trades.
+    StopLimit,
+}
+
+impl OrderType {
+    /// Returns `true` when orders of this type carry a limit price.
+    #[must_use]
+    pub const fn requires_limit_price(self) -> bool {
+        matches!(self, Self::Limit | Self::StopLimit)
+    }
+
+    /// Returns `true` when orders of this type carry a stop price.
+    #[must_use]
+    pub const fn requires_stop_price(self) -> bool {
+        matches!(self, Self::Stop | Self::StopLimit)
+    }
+}
+
+paft_core::string_enum_closed_with_code!(
+    OrderType, "OrderType",
+    type Error = OrderError;
+    invalid(input) => OrderError::InvalidEnumValue {
+        enum_name: "OrderType",
+        value: input.to_string(),
+    };
+    {
+        "MARKET" => OrderType::Market,
+        "LIMIT" => OrderType::Limit,
+        "STOP" => OrderType::Stop,
+        "STOP_LIMIT" => OrderType::StopLimit
+    },
+    {
+        "MKT" => OrderType::Market,
+        "LMT" => OrderType::Limit,
+        "STP" => OrderType::Stop,
+        "STOP_LOSS" => OrderType::Stop,
+        "STP_LMT" => OrderType::StopLimit
+    }
+);
+
+paft_core::impl_display_via_code!(OrderType);
+
+/// How long an order stays working before it expires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum TimeInForce {
+    /// Expires at the end of the trading day.
+    #[default]
+    Day,
+    /// Good till canceled.
+    Gtc,
+    /// Good till the request's `expire_at` timestamp.
+    Gtd,
+    /// Immediate or cancel: fill what is possible at once, cancel the rest.
+    Ioc,
+    /// Fill or kill: fill the whole quantity at once or cancel.
+    Fok,
+    /// Execute in the opening auction.
+    AtOpen,
+    /// Execute in the closing auction.
+    AtClose,
+}
+
+paft_core::string_enum_closed_with_code!(
+    TimeInForce, "TimeInForce",
+    type Error = OrderError;
+    invalid(input) => OrderError::InvalidEnumValue {
+        enum_name: "TimeInForce",
+        value: input.to_string(),
+    };
+    {
+        "DAY" => TimeInForce::Day,
+        "GTC" => TimeInForce::Gtc,
+        "GTD" => TimeInForce::Gtd,
+        "IOC" => TimeInForce::Ioc,
+        "FOK" => TimeInForce::Fok,
+        "OPG" => TimeInForce::AtOpen,
+        "CLS" => TimeInForce::AtClose
+    },
+    {
+        "GOOD_TILL_CANCEL" => TimeInForce::Gtc,
+        "GOOD_TILL_CANCELED" => TimeInForce::Gtc,
+        "GOOD_TILL_DATE" => TimeInForce::Gtd,
+        "IMMEDIATE_OR_CANCEL" => TimeInForce::Ioc,
+        "FILL_OR_KILL" => TimeInForce::Fok,
+        "AT_OPEN" => TimeInForce::AtOpen,
+        "AT_CLOSE" => TimeInForce::AtClose
+    }
+);
+
+paft_core::impl_display_via_code!(TimeInForce);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// Validated request to place an order.
+///
+/// Use [`OrderRequest::builder`] or the [`OrderRequest::market`] and
+/// [`OrderRequest::limit`] shorthands to create instances. Every request has
+/// a positive quantity, exactly the prices its [`OrderType`] needs, and an
+/// expiry only when its time in force is [`TimeInForce::Gtd`].
+pub struct OrderRequest {
+    /// Instrument to trade.
+    instrument: Instrument,
+    /// Order direction.
+    side: OrderSide,
+    /// Pricing instruction.
+    order_type: OrderType,
+    /// Units to trade.
+    quantity: QuantityAmount,
+    /// Limit price for limit and stop-limit orders.
+    limit_price: Option<Price>,
+    /// Trigger price for stop and stop-limit orders.
+    stop_price: Option<Price>,
+    /// Time in force.
+    time_in_force: TimeInForce,
+    /// Expiry of a good-till-date order.
+    #[serde(with = "chrono::serde::ts_milliseconds_option")]
+    expire_at: Option<DateTime<Utc>>,
+    /// Caller-assigned order identifier.
+    client_order_id: Option<String>,
+}
+
+/// Shadow type used for deserializing [`OrderRequest`].
+///
+/// Matches the serialized wire shape, then routes through
+/// [`OrderRequestBuilder::build`] so validation cannot be skipped.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct OrderRequestShadow {
+    instrument: Instrument,
+    side: OrderSide,
+    order_type: OrderType,
+    quantity: QuantityAmount,
+    limit_price: Option<Price>,
+    stop_price: Option<Price>,
+    time_in_force: TimeInForce,
+    #[serde(default, with = "chrono::serde::ts_milliseconds_option")]
+    expire_at: Option<DateTime<Utc>>,
+    client_order_id: Option<String>,
+}
+
+impl<'de> Deserialize<'de> for OrderRequest {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let shadow = OrderRequestShadow::deserialize(deserializer)?;
+
+        let builder = OrderRequestBuilder {
+            instrument: shadow.instrument,
+            side: shadow.side,
+            order_type: shadow.order_type,
+            quantity: shadow.quantity,
+            limit_price: shadow.limit_price,
+            stop_price: shadow.stop_price,
+            time_in_force: shadow.time_in_force,
+            expire_at: shadow.expire_at,
+            client_order_id: shadow.client_order_id,
+            tick_sizes: None,
+        };
+
+        builder.build().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Builder for creating validated `OrderRequest` instances.
+///
+/// Starts as a day market order; pricing setters such as
+/// [`OrderRequestBuilder::limit`] switch the order type and clear prices the
+/// new type does not take.
+#[derive(Debug, Clone)]
+pub struct OrderRequestBuilder {
+    instrument: Instrument,
+    side: OrderSide,
+    order_type: OrderType,
+    quantity: QuantityAmount,
+    limit_price: Option<Price>,
+    stop_price: Option<Price>,
+    time_in_force: TimeInForce,
+    expire_at: Option<DateTime<Utc>>,
+    client_order_id: Option<String>,
+    tick_sizes: Option<TickSizeTable>,
+}
+
+impl OrderRequestBuilder {
+    /// Create a builder for a day market order.
+    #[must_use]
+    pub const fn new(instrument: Instrument, side: OrderSide, quantity: QuantityAmount) -> Self {
+        Self {
+            instrument,
+            side,
+            order_type: OrderType::Market,
+            quantity,
+            limit_price: None,
+            stop_price: None,
+            time_in_force: TimeInForce::Day,
+            expire_at: None,
+            client_order_id: None,
+            tick_sizes: None,
+        }
+    }
+
+    /// Make this a market order.
+    #[must_use]
+    pub fn market(mut self) -> Self {
+        self.order_type = OrderType::Market;
+        self.limit_price = None;
+        self.stop_price = None;
+        self
+    }
+
+    /// Make this a limit order at `price`.
+    #[must_use]
+    pub fn limit(mut self, price: Price) -> Self {
+        self.order_type = OrderType::Limit;
+        self.limit_price = Some(price);
+        self.stop_price = None;
+        self
+    }
+
+    /// Make this a stop order triggered at `stop_price`.
+    #[must_use]
+    pub fn stop(mut self, stop_price: Price) -> Self {
+        self.order_type = OrderType::Stop;
+        self.limit_price = None;
+        self.stop_price = Some(stop_price);
+        self
+    }
+
+    /// Make this a stop-limit order triggered at `stop_price` and limited to
+    /// `limit_price`.
+    #[must_use]
+    pub fn stop_limit(mut self, stop_price: Price, limit_price: Price) -> Self {
+        self.order_type = OrderType::StopLimit;
+        self.limit_price = Some(limit_price);
+        self.stop_price = Some(stop_price);
+        self
+    }
+
+    /// Set the time in force.
+    ///
+    /// [`TimeInForce::Gtd`] also needs an expiry; prefer
+    /// [`OrderRequestBuilder::good_till`].
+    #[must_use]
+    pub const fn time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = time_in_force;
+        self
+    }
+
+    /// Keep the order working until `expire_at`.
+    #[must_use]
+    pub const fn good_till(mut self, expire_at: DateTime<Utc>) -> Self {
+        self.time_in_force = TimeInForce::Gtd;
+        self.expire_at = Some(expire_at);
+        self
+    }
+
+    /// Set the caller-assigned order identifier.
+    #[must_use]
+    pub fn client_order_id(mut self, client_order_id: impl Into<String>) -> Self {
+        self.client_order_id = Some(client_order_id.into());
+        self
+    }
+
+    /// Check the order's prices against `table` when building.
+    #[must_use]
+    pub fn tick_sizes(mut self, table: TickSizeTable) -> Self {
+        self.tick_sizes = Some(table);
+        self
+    }
+
+    /// Build the `OrderRequest` with validation.
+    ///
+    /// # Errors
+    /// - [`OrderError::ZeroQuantity`] when the quantity is zero.
+    /// - [`OrderError::MissingPrice`] or [`OrderError::UnexpectedPrice`] when
+    ///   the prices do not match the order type.
+    /// - [`OrderError::InvalidOrder`] when stop and limit prices use
+    ///   different currencies, the expiry does not match the time in force,
+    ///   or the client order id is blank.
+    /// - [`OrderError::Market`] when a tick-size table was supplied and a
+    ///   price is off tick.
+    pub fn build(self) -> Result<OrderRequest, OrderError> {
+        if *self.quantity.as_decimal() == paft_decimal::zero() {
+            return Err(OrderError::ZeroQuantity);
+        }
+        check_price(
+            self.order_type,
+            "limit_price",
+            self.limit_price.as_ref(),
+            self.order_type.requires_limit_price(),
+        )?;
+        check_price(
+            self.order_type,
+            "stop_price",
+            self.stop_price.as_ref(),
+            self.order_type.requires_stop_price(),
+        )?;
+        if let (Some(limit), Some(stop)) = (&self.limit_price, &self.stop_price)
+            && limit.currency() != stop.currency()
+        {
+            return Err(OrderError::InvalidOrder {
+                reason: "stop and limit prices must share a currency",
+            });
+        }
+        match (self.time_in_force, self.expire_at) {
+            (TimeInForce::Gtd, None) => {
+                return Err(OrderError::InvalidOrder {
+                    reason: "GTD orders require an expiry",
+                });
+            }
+            (time_in_force, Some(_)) if time_in_force != TimeInForce::Gtd => {
+                return Err(OrderError::InvalidOrder {
+                    reason: "only GTD orders take an expiry",
+                });
+            }
+            _ => {}
+        }
+        if self
+            .client_order_id
+            .as_ref()
+            .is_some_and(|id| id.trim().is_empty())
+        {
+            return Err(OrderError::InvalidOrder {
+                reason: "client order id must not be empty",
+            });
+        }
+
+        let request = OrderRequest {
+            instrument: self.instrument,
+            side: self.side,
+            order_type: self.order_type,
+            quantity: self.quantity,
+            limit_price: self.limit_price,
+            stop_price: self.stop_price,
+            time_in_force: self.time_in_force,
+            expire_at: self.expire_at,
+            client_order_id: self.client_order_id,
+        };
+        if let Some(table) = &self.tick_sizes {
+            request.validate_ticks(table)?;
+        }
+        Ok(request)
+    }
+}
+
+impl OrderRequest {
+    /// Create a new builder for constructing an `OrderRequest`.
+    #[must_use]
+    pub const fn builder(
+        instrument: Instrument,
+        side: OrderSide,
+        quantity: QuantityAmount,
+    ) -> OrderRequestBuilder {
+        OrderRequestBuilder::new(instrument, side, quantity)
+    }
+
+    /// Build a day market order.
+    ///
+    /// # Errors
+    /// Propagates errors from `OrderRequestBuilder::build`.
+    pub fn market(
+        instrument: Instrument,
+        side: OrderSide,
+        quantity: QuantityAmount,
+    ) -> Result<Self, OrderError> {
+        Self::builder(instrument, side, quantity).build()
+    }
+
+    /// Build a day limit order at `price`.
+    ///
+    /// # Errors
+    /// Propagates errors from `OrderRequestBuilder::build`.
+    pub fn limit(
+        instrument: Instrument,
+        side: OrderSide,
+        quantity: QuantityAmount,
+        price: Price,
+    ) -> Result<Self, OrderError> {
+        Self::builder(instrument, side, quantity)
+            .limit(price)
+            .build()
+    }
+
+    /// Get the instrument to trade.
+    #[must_use]
+    pub const fn instrument(&self) -> &Instrument {
+        &self.instrument
+    }
+
+    /// Get the order direction.
+    #[must_use]
+    pub const fn side(&self) -> OrderSide {
+        self.side
+    }
+
+    /// Get the pricing instruction.
+    #[must_use]
+    pub const fn order_type(&self) -> OrderType {
+        self.order_type
+    }
+
+    /// Get the units to trade.
+    #[must_use]
+    pub const fn quantity(&self) -> &QuantityAmount {
+        &self.quantity
+    }
+
+    /// Get the limit price of a limit or stop-limit order.
+    #[must_use]
+    pub const fn limit_price(&self) -> Option<&Price> {
+        self.limit_price.as_ref()
+    }
+
+    /// Get the trigger price of a stop or stop-limit order.
+    #[must_use]
+    pub const fn stop_price(&self) -> Option<&Price> {
+        self.stop_price.as_ref()
+    }
+
+    /// Get the time in force.
+    #[must_use]
+    pub const fn time_in_force(&self) -> TimeInForce {
+        self.time_in_force
+    }
+
+    /// Get the expiry of a good-till-date order.
+    #[must_use]
+    pub const fn expire_at(&self) -> Option<DateTime<Utc>> {
+        self.expire_at
+    }
+
+    /// Get the caller-assigned order identifier.
+    #[must_use]
+    pub fn client_order_id(&self) -> Option<&str> {
+        self.client_order_id.as_deref()
+    }
+
+    /// Validate that the order's prices are on `table`'s tick grid.
+    ///
+    /// # Errors
+    /// Returns [`OrderError::Market`] wrapping
+    /// [`paft_market::MarketError::PriceOffTick`] for the first off-tick
+    /// price, checking the limit price before the stop price.
+    pub fn validate_ticks(&self, table: &TickSizeTable) -> Result<(), OrderError> {
+        for price in [&self.limit_price, &self.stop_price].into_iter().flatten() {
+            table.validate_price(&PriceAmount::new(price.amount()))?;
+        }
+        Ok(())
+    }
+}
+
+const fn check_price(
+    order_type: OrderType,
+    field: &'static str,
+    price: Option<&Price>,
+    required: bool,
+) -> Result<(), OrderError> {
+    match (price, required) {
+        (None, true) => Err(OrderError::MissingPrice { order_type, field }),
+        (Some(_), false) => Err(OrderError::UnexpectedPrice { order_type, field }),
+        _ => Ok(()),
+    }
+}
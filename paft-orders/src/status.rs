@@ -0,0 +1,207 @@
+//! Order lifecycle status under the `paft_orders::status` namespace.
+
+use std::str::FromStr;
+
+use crate::error::OrderError;
+
+paft_core::other_string_code_type!(
+    /// Provider-specific order status not modeled by [`OrderStatus`].
+    pub struct OtherOrderStatus for OrderStatus;
+    type Error = OrderError;
+    parse(input) => OrderStatus::from_str(input);
+    invalid(input) => OrderError::InvalidEnumValue {
+        enum_name: "OrderStatus",
+        value: input.to_string(),
+    };
+);
+
+/// Lifecycle status of an order with canonical variants and extensible fallback.
+///
+/// Canonical/serde rules:
+/// - Emission uses a single canonical form per variant (UPPERCASE ASCII, no spaces)
+/// - Parser accepts a superset of tokens (aliases, case-insensitive)
+/// - `Other(s)` serializes to its canonical `code()` string (no escape prefix)
+/// - Unknown tokens normalize to `Other(UPPERCASE)`
+///
+/// [`OrderStatus::can_transition_to`] encodes which status changes a
+/// well-behaved venue can report. `Other` statuses are not checked, except
+/// that nothing follows a terminal status.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum OrderStatus {
+    /// Submitted but not yet acknowledged by the venue.
+    #[default]
+    PendingNew,
+    /// Acknowledged and working with nothing filled.
+    New,
+    /// Working with part of the quantity filled.
+    PartiallyFilled,
+    /// Completely filled.
+    Filled,
+    /// Cancel requested but not yet confirmed.
+    PendingCancel,
+    /// Canceled; any unfilled quantity will not execute.
+    Canceled,
+    /// Refused by the broker or venue.
+    Rejected,
+    /// Expired under its time in force.
+    Expired,
+    /// Replace requested but not yet confirmed.
+    PendingReplace,
+    /// Superseded by a replacement order.
+    Replaced,
+    /// Unknown or provider-specific status.
+    Other(OtherOrderStatus),
+}
+
+impl OrderStatus {
+    /// Attempts to parse a status, uppercasing unknown inputs into `Other`.
+    ///
+    /// # Errors
+    /// Returns `OrderError::InvalidEnumValue` when `input` is empty/whitespace.
+    pub fn try_from_str(input: &str) -> Result<Self, OrderError> {
+        Self::from_str(input)
+    }
+
+    /// Builds an unknown status, rejecting modeled statuses and aliases.
+    ///
+    /// # Errors
+    /// Returns an error if `input` is empty, cannot be canonicalized, or parses
+    /// to a modeled [`OrderStatus`] variant.
+    pub fn other(input: &str) -> Result<Self, OrderError> {
+        OtherOrderStatus::new(input).map(Self::Other)
+    }
+
+    /// Returns `true` when the order can no longer change.
+    #[must_use]
+    pub const fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            Self::Filled | Self::Canceled | Self::Rejected | Self::Expired | Self::Replaced
+        )
+    }
+
+    /// Returns `true` when the order may still execute. `Other` statuses
+    /// return `false`.
+    #[must_use]
+    pub const fn is_open(&self) -> bool {
+        matches!(
+            self,
+            Self::PendingNew
+                | Self::New
+                | Self::PartiallyFilled
+                | Self::PendingCancel
+                | Self::PendingReplace
+        )
+    }
+
+    /// Returns `true` when an order in this status may move to `next`.
+    ///
+    /// Repeating the current status is always allowed so that duplicate
+    /// venue reports are harmless.
+    #[must_use]
+    pub fn can_transition_to(&self, next: &Self) -> bool {
+        if self == next {
+            return true;
+        }
+        if self.is_terminal() {
+            return false;
+        }
+        match (self, next) {
+            (Self::Other(_), _) | (_, Self::Other(_)) => true,
+            (Self::PendingNew, next) => matches!(
+                next,
+                Self::New
+                    | Self::PartiallyFilled
+                    | Self::Filled
+                    | Self::PendingCancel
+                    | Self::Canceled
+                    | Self::Rejected
+                    | Self::Expired
+            ),
+            (Self::New, next) => matches!(
+                next,
+                Self::PartiallyFilled
+                    | Self::Filled
+                    | Self::PendingCancel
+                    | Self::Canceled
+                    | Self::Rejected
+                    | Self::Expired
+                    | Self::PendingReplace
+                    | Self::Replaced
+            ),
+            (Self::PartiallyFilled, next) => matches!(
+                next,
+                Self::Filled
+                    | Self::PendingCancel
+                    | Self::Canceled
+                    | Self::Expired
+                    | Self::PendingReplace
+                    | Self::Replaced
+            ),
+            (Self::PendingCancel, next) => matches!(
+                next,
+                Self::New | Self::PartiallyFilled | Self::Filled | Self::Canceled | Self::Expired
+            ),
+            (Self::PendingReplace, next) => matches!(
+                next,
+                Self::New
+                    | Self::PartiallyFilled
+                    | Self::Filled
+                    | Self::PendingCancel
+                    | Self::Canceled
+                    | Self::Expired
+                    | Self::Replaced
+            ),
+            _ => false,
+        }
+    }
+
+    /// Validate a change from this status to `next`.
+    ///
+    /// # Errors
+    /// Returns [`OrderError::InvalidTransition`] when
+    /// [`OrderStatus::can_transition_to`] is `false`.
+    pub fn validate_transition(&self, next: &Self) -> Result<(), OrderError> {
+        if self.can_transition_to(next) {
+            Ok(())
+        } else {
+            Err(OrderError::InvalidTransition {
+                from: self.clone(),
+                to: next.clone(),
+            })
+        }
+    }
+}
+
+// Centralized code() and string impls via macro
+paft_core::string_enum_with_code!(
+    OrderStatus, Other(OtherOrderStatus), "OrderStatus",
+    type Error = OrderError;
+    invalid(input) => OrderError::InvalidEnumValue {
+        enum_name: "OrderStatus",
+        value: input.to_string(),
+    };
+    {
+        "PENDING_NEW" => OrderStatus::PendingNew,
+        "NEW" => OrderStatus::New,
+        "PARTIALLY_FILLED" => OrderStatus::PartiallyFilled,
+        "FILLED" => OrderStatus::Filled,
+        "PENDING_CANCEL" => OrderStatus::PendingCancel,
+        "CANCELED" => OrderStatus::Canceled,
+        "REJECTED" => OrderStatus::Rejected,
+        "EXPIRED" => OrderStatus::Expired,
+        "PENDING_REPLACE" => OrderStatus::PendingReplace,
+        "REPLACED" => OrderStatus::Replaced
+    },
+    {
+        // Aliases
+        "ACCEPTED" => OrderStatus::New,
+        "OPEN" => OrderStatus::New,
+        "PARTIAL_FILL" => OrderStatus::PartiallyFilled,
+        "CANCELLED" => OrderStatus::Canceled,
+        "PENDING_CANCELLATION" => OrderStatus::PendingCancel
+    }
+);
+
+paft_core::impl_display_via_code!(OrderStatus);
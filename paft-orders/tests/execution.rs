@@ -0,0 +1,174 @@
+use chrono::{DateTime, Utc};
+use paft_decimal::Decimal;
+use paft_domain::{AssetKind, Instrument};
+use paft_money::{Currency, IsoCurrency, Price, QuantityAmount};
+use paft_orders::{
+    Execution, Fill, GenericExecution, GenericFill, OrderError, OrderRequest, OrderSide,
+    OrderStatus,
+};
+use serde::{Deserialize, Serialize};
+
+fn decimal(value: &str) -> Decimal {
+    value.parse().unwrap()
+}
+
+const fn usd() -> Currency {
+    Currency::Iso(IsoCurrency::USD)
+}
+
+fn qty(value: &str) -> QuantityAmount {
+    QuantityAmount::from_decimal(decimal(value)).unwrap()
+}
+
+fn aapl() -> Instrument {
+    Instrument::from_symbol("AAPL", AssetKind::Equity).unwrap()
+}
+
+const fn at(seconds: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(1_718_000_000 + seconds, 0).unwrap()
+}
+
+fn fill(quantity: &str, price: &str, seconds: i64) -> Fill {
+    Fill::new(
+        aapl(),
+        OrderSide::Buy,
+        qty(quantity),
+        Price::new(decimal(price), usd()),
+        at(seconds),
+    )
+}
+
+fn execution() -> Execution {
+    let request = OrderRequest::builder(aapl(), OrderSide::Buy, qty("10"))
+        .client_order_id("abc-1")
+        .build()
+        .unwrap();
+    let mut execution = Execution::from_request("O-1", &request);
+    execution.transition(OrderStatus::New).unwrap();
+    execution
+}
+
+#[test]
+fn status_parses_aliases_and_preserves_unknown_codes() {
+    assert_eq!("cancelled".parse(), Ok(OrderStatus::Canceled));
+    assert_eq!("Partially Filled".parse(), Ok(OrderStatus::PartiallyFilled));
+    assert_eq!(OrderStatus::PendingReplace.to_string(), "PENDING_REPLACE");
+
+    let held = OrderStatus::try_from_str("held").unwrap();
+    assert_eq!(held, OrderStatus::other("HELD").unwrap());
+    assert_eq!(held.code(), "HELD");
+    assert!(OrderStatus::other("open").is_err());
+    assert!(!held.is_open());
+    assert!(!held.is_terminal());
+}
+
+#[test]
+fn status_transitions_follow_the_order_lifecycle() {
+    assert!(OrderStatus::PendingNew.can_transition_to(&OrderStatus::Rejected));
+    assert!(OrderStatus::New.can_transition_to(&OrderStatus::PartiallyFilled));
+    assert!(OrderStatus::PartiallyFilled.can_transition_to(&OrderStatus::PartiallyFilled));
+    assert!(OrderStatus::PendingCancel.can_transition_to(&OrderStatus::Filled));
+    assert!(!OrderStatus::PartiallyFilled.can_transition_to(&OrderStatus::New));
+    assert!(!OrderStatus::New.can_transition_to(&OrderStatus::PendingNew));
+
+    let held = OrderStatus::other("HELD").unwrap();
+    assert!(OrderStatus::New.can_transition_to(&held));
+    assert!(held.can_transition_to(&OrderStatus::New));
+    assert!(!OrderStatus::Filled.can_transition_to(&held));
+
+    assert_eq!(
+        OrderStatus::Canceled.validate_transition(&OrderStatus::New),
+        Err(OrderError::InvalidTransition {
+            from: OrderStatus::Canceled,
+            to: OrderStatus::New,
+        })
+    );
+    assert_eq!(
+        OrderStatus::Filled
+            .validate_transition(&OrderStatus::Canceled)
+            .unwrap_err()
+            .to_string(),
+        "Order status cannot change from FILLED to CANCELED"
+    );
+}
+
+#[test]
+fn fills_advance_status_and_quantities() {
+    let mut execution = execution();
+    assert_eq!(execution.client_order_id.as_deref(), Some("abc-1"));
+    assert_eq!(execution.average_price(), Ok(None));
+
+    execution.apply_fill(fill("4", "100", 2)).unwrap();
+    assert_eq!(execution.status, OrderStatus::PartiallyFilled);
+    assert_eq!(execution.remaining_quantity(), Ok(qty("6")));
+
+    execution.apply_fill(fill("6", "105", 1)).unwrap();
+    assert_eq!(execution.status, OrderStatus::Filled);
+    assert_eq!(execution.filled_quantity(), Ok(qty("10")));
+    assert_eq!(execution.remaining_quantity(), Ok(qty("0")));
+    assert_eq!(execution.updated_at, Some(at(2)));
+    assert_eq!(
+        execution.average_price().unwrap().unwrap(),
+        Price::new(decimal("103"), usd())
+    );
+}
+
+#[test]
+fn invalid_fills_leave_the_execution_unchanged() {
+    let mut execution = execution();
+    execution.apply_fill(fill("4", "100", 1)).unwrap();
+    let before = execution.clone();
+
+    assert_eq!(
+        execution.apply_fill(fill("7", "100", 2)),
+        Err(OrderError::Overfill {
+            ordered: decimal("10"),
+            filled: decimal("11"),
+        })
+    );
+    let mut sell = fill("1", "100", 2);
+    sell.side = OrderSide::Sell;
+    assert!(matches!(
+        execution.apply_fill(sell),
+        Err(OrderError::FillMismatch { .. })
+    ));
+    let mut other_order = fill("1", "100", 2);
+    other_order.order_id = Some("O-2".to_string());
+    assert!(execution.apply_fill(other_order).is_err());
+    assert_eq!(execution, before);
+
+    execution.transition(OrderStatus::Canceled).unwrap();
+    assert!(matches!(
+        execution.apply_fill(fill("1", "100", 3)),
+        Err(OrderError::InvalidTransition { .. })
+    ));
+    assert_eq!(execution.fills.len(), 1);
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct VenueMeta {
+    venue_liquidity: Option<String>,
+}
+
+#[test]
+fn executions_round_trip_with_flattened_provider_metadata() {
+    let mut execution: GenericExecution<(), VenueMeta> =
+        GenericExecution::new("O-1", aapl(), OrderSide::Buy, qty("10"));
+    let mut fill: GenericFill<VenueMeta> = GenericFill::new(
+        aapl(),
+        OrderSide::Buy,
+        qty("10"),
+        Price::new(decimal("100"), usd()),
+        at(0),
+    );
+    fill.provider.venue_liquidity = Some("ADDED".to_string());
+    execution.apply_fill(fill).unwrap();
+
+    let value = serde_json::to_value(&execution).unwrap();
+    assert_eq!(value["status"], "FILLED");
+    assert_eq!(value["fills"][0]["venue_liquidity"], "ADDED");
+    assert_eq!(
+        serde_json::from_value::<GenericExecution<(), VenueMeta>>(value).unwrap(),
+        execution
+    );
+}
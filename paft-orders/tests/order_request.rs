@@ -0,0 +1,157 @@
+use chrono::{DateTime, Utc};
+use paft_decimal::Decimal;
+use paft_domain::{AssetKind, Instrument};
+use paft_market::{MarketError, TickSizeTable};
+use paft_money::{Currency, IsoCurrency, Price, QuantityAmount};
+use paft_orders::{OrderError, OrderRequest, OrderSide, OrderType, TimeInForce};
+use serde_json::json;
+
+fn decimal(value: &str) -> Decimal {
+    value.parse().unwrap()
+}
+
+const fn usd() -> Currency {
+    Currency::Iso(IsoCurrency::USD)
+}
+
+fn price(value: &str) -> Price {
+    Price::new(decimal(value), usd())
+}
+
+fn qty(value: &str) -> QuantityAmount {
+    QuantityAmount::from_decimal(decimal(value)).unwrap()
+}
+
+fn aapl() -> Instrument {
+    Instrument::from_symbol("AAPL", AssetKind::Equity).unwrap()
+}
+
+const fn expiry() -> DateTime<Utc> {
+    DateTime::from_timestamp_millis(1_718_000_000_000).unwrap()
+}
+
+#[test]
+fn enums_parse_aliases() {
+    assert_eq!("b".parse(), Ok(OrderSide::Buy));
+    assert_eq!("stp lmt".parse(), Ok(OrderType::StopLimit));
+    assert_eq!("good till canceled".parse(), Ok(TimeInForce::Gtc));
+    assert_eq!(TimeInForce::AtOpen.to_string(), "OPG");
+    assert_eq!(TimeInForce::default(), TimeInForce::Day);
+    assert_eq!(
+        "trailing".parse::<OrderType>(),
+        Err(OrderError::InvalidEnumValue {
+            enum_name: "OrderType",
+            value: "trailing".to_string(),
+        })
+    );
+}
+
+#[test]
+fn builder_produces_each_order_type() {
+    let market = OrderRequest::market(aapl(), OrderSide::Buy, qty("10")).unwrap();
+    assert_eq!(market.order_type(), OrderType::Market);
+    assert_eq!(market.time_in_force(), TimeInForce::Day);
+    assert_eq!(market.limit_price(), None);
+
+    let limit = OrderRequest::limit(aapl(), OrderSide::Sell, qty("5"), price("190.25")).unwrap();
+    assert_eq!(limit.limit_price(), Some(&price("190.25")));
+    assert_eq!(limit.stop_price(), None);
+
+    let stop_limit = OrderRequest::builder(aapl(), OrderSide::Sell, qty("5"))
+        .limit(price("200"))
+        .stop_limit(price("180"), price("179.5"))
+        .good_till(expiry())
+        .client_order_id("abc-1")
+        .build()
+        .unwrap();
+    assert_eq!(stop_limit.order_type(), OrderType::StopLimit);
+    assert_eq!(stop_limit.stop_price(), Some(&price("180")));
+    assert_eq!(stop_limit.limit_price(), Some(&price("179.5")));
+    assert_eq!(stop_limit.time_in_force(), TimeInForce::Gtd);
+    assert_eq!(stop_limit.expire_at(), Some(expiry()));
+    assert_eq!(stop_limit.client_order_id(), Some("abc-1"));
+
+    let stop = OrderRequest::builder(aapl(), OrderSide::Sell, qty("5"))
+        .stop(price("180"))
+        .time_in_force(TimeInForce::Gtc)
+        .build()
+        .unwrap();
+    assert_eq!(stop.order_type(), OrderType::Stop);
+    assert_eq!(stop.limit_price(), None);
+}
+
+#[test]
+fn builder_rejects_inconsistent_orders() {
+    let builder = || OrderRequest::builder(aapl(), OrderSide::Buy, qty("10"));
+
+    assert_eq!(
+        OrderRequest::market(aapl(), OrderSide::Buy, qty("0")),
+        Err(OrderError::ZeroQuantity)
+    );
+    assert_eq!(
+        builder().time_in_force(TimeInForce::Gtd).build(),
+        Err(OrderError::InvalidOrder {
+            reason: "GTD orders require an expiry",
+        })
+    );
+    assert!(builder().client_order_id(" ").build().is_err());
+
+    let euro = Price::new(decimal("180"), Currency::Iso(IsoCurrency::EUR));
+    assert_eq!(
+        builder().stop_limit(euro, price("181")).build(),
+        Err(OrderError::InvalidOrder {
+            reason: "stop and limit prices must share a currency",
+        })
+    );
+}
+
+#[test]
+fn builder_checks_prices_against_tick_sizes() {
+    let table = TickSizeTable::us_sub_penny();
+    let builder =
+        || OrderRequest::builder(aapl(), OrderSide::Buy, qty("10")).tick_sizes(table.clone());
+
+    assert!(builder().limit(price("190.25")).build().is_ok());
+    assert!(builder().limit(price("0.1234")).build().is_ok());
+    assert!(matches!(
+        builder().limit(price("190.255")).build(),
+        Err(OrderError::Market(MarketError::PriceOffTick { .. }))
+    ));
+    assert!(matches!(
+        builder().stop_limit(price("190.001"), price("190")).build(),
+        Err(OrderError::Market(MarketError::PriceOffTick { .. }))
+    ));
+
+    let unchecked =
+        OrderRequest::limit(aapl(), OrderSide::Buy, qty("10"), price("190.255")).unwrap();
+    assert!(unchecked.validate_ticks(&table).is_err());
+}
+
+#[test]
+fn deserialization_routes_through_builder_validation() {
+    let request = OrderRequest::builder(aapl(), OrderSide::Buy, qty("10"))
+        .limit(price("190.25"))
+        .good_till(expiry())
+        .build()
+        .unwrap();
+    let value = serde_json::to_value(&request).unwrap();
+    assert_eq!(value["order_type"], "LIMIT");
+    assert_eq!(value["time_in_force"], "GTD");
+    assert_eq!(value["expire_at"], json!(1_718_000_000_000_i64));
+    assert_eq!(
+        serde_json::from_value::<OrderRequest>(value.clone()).unwrap(),
+        request
+    );
+
+    let mut missing_limit = value.clone();
+    missing_limit["limit_price"] = json!(null);
+    let err = serde_json::from_value::<OrderRequest>(missing_limit).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("LIMIT orders require a limit_price")
+    );
+
+    let mut unknown = value;
+    unknown["route"] = json!("SMART");
+    assert!(serde_json::from_value::<OrderRequest>(unknown).is_err());
+}
@@ -0,0 +1,267 @@
+//! Provider-neutral account snapshots under the `paft_portfolio::account`
+//! namespace.
+//!
+//! A [`GenericAccountSnapshot`] is an instant-in-time view of one brokerage
+//! account: cash balances per currency, buying power, margin requirements,
+//! and positions keyed by instrument.
+
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+#[cfg(feature = "dataframe")]
+use df_derive_macros::ToDataFrame;
+use paft_domain::Instrument;
+use paft_money::{Currency, Money, MoneyBag, Price, QuantityAmount, RateSource};
+use serde::{Deserialize, Serialize};
+
+use crate::error::PortfolioError;
+use crate::position::Position;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "dataframe", derive(ToDataFrame))]
+/// One instrument held in an account, as reported by the provider.
+///
+/// Valuation fields are optional to accommodate partially populated data
+/// from upstream sources.
+///
+/// Generic over a provider metadata payload `M`, which is flattened into the
+/// serialized representation. Use the [`AccountPosition`] alias for the
+/// standard shape (no extra metadata).
+///
+/// **Collision warning:** provider metadata is flattened into the same object
+/// as paft fields. Metadata field names must not collide with paft field
+/// names; prefer provider-specific prefixes when in doubt.
+pub struct GenericAccountPosition<M = ()> {
+    /// Instrument held.
+    #[cfg_attr(feature = "dataframe", df_derive(as_string))]
+    pub instrument: Instrument,
+    /// Units held.
+    pub quantity: QuantityAmount,
+    /// Average cost per unit.
+    pub average_cost: Option<Price>,
+    /// Total cost of the units held.
+    pub cost_basis: Option<Money>,
+    /// Value of the units held at the provider's mark.
+    pub market_value: Option<Money>,
+    /// Market value less cost basis.
+    pub unrealized_pnl: Option<Money>,
+    /// Provider-specific payload, flattened into the serialized form.
+    #[serde(flatten, default = "Default::default")]
+    pub provider: M,
+}
+
+/// Standard account position without provider metadata.
+pub type AccountPosition = GenericAccountPosition<()>;
+
+impl<M: Default> GenericAccountPosition<M> {
+    /// Build a position for `instrument` with all valuation fields unset.
+    /// `provider` is initialised via `M::default()`.
+    #[must_use]
+    pub fn new(instrument: Instrument, quantity: QuantityAmount) -> Self {
+        Self {
+            instrument,
+            quantity,
+            average_cost: None,
+            cost_basis: None,
+            market_value: None,
+            unrealized_pnl: None,
+            provider: M::default(),
+        }
+    }
+
+    /// Summarize a tax-lot [`Position`]: its quantity, cost basis, and
+    /// average cost. Market valuation fields are left unset.
+    ///
+    /// # Errors
+    /// Returns [`PortfolioError::Money`] when the cost basis cannot be
+    /// rounded to the currency's minor units.
+    pub fn from_position(position: &Position) -> Result<Self, PortfolioError> {
+        let mut summary = Self::new(position.instrument().clone(), position.quantity().clone());
+        summary.average_cost = position.average_cost()?;
+        summary.cost_basis = Some(position.cost_basis().to_money()?);
+        Ok(summary)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "dataframe", derive(ToDataFrame))]
+/// Instant-in-time view of one brokerage account.
+///
+/// `cash` holds one balance per currency and `positions` one entry per
+/// instrument; [`GenericAccountSnapshot::validate`] checks both, along with
+/// the currency of the account-level amounts. Those amounts are optional to
+/// accommodate partially populated data from upstream sources.
+///
+/// Generic over an account metadata payload `M` and a position metadata
+/// payload `P`, both flattened into their serialized objects. Use the
+/// [`AccountSnapshot`] alias for the standard shape (no extra metadata).
+///
+/// **Collision warning:** provider metadata is flattened into the same object
+/// as paft fields. Metadata field names must not collide with paft field
+/// names; prefer provider-specific prefixes when in doubt.
+pub struct GenericAccountSnapshot<M = (), P = ()> {
+    /// Provider-assigned account identifier.
+    pub account_id: String,
+    /// Timestamp (UTC) when this snapshot was taken.
+    #[serde(default, with = "chrono::serde::ts_milliseconds_option")]
+    pub as_of: Option<DateTime<Utc>>,
+    /// Currency the account reports equity, buying power, and margin in.
+    #[cfg_attr(feature = "dataframe", df_derive(as_str))]
+    pub base_currency: Currency,
+    /// Settled cash, one balance per currency; negative for debit balances.
+    pub cash: Vec<Money>,
+    /// Net liquidation value of cash and positions.
+    pub equity: Option<Money>,
+    /// Amount available to open new positions.
+    pub buying_power: Option<Money>,
+    /// Margin required to hold the current positions.
+    pub initial_margin: Option<Money>,
+    /// Equity below which the account receives a margin call.
+    pub maintenance_margin: Option<Money>,
+    /// Positions held, one entry per instrument.
+    pub positions: Vec<GenericAccountPosition<P>>,
+    /// Provider-specific payload, flattened into the serialized form.
+    #[serde(flatten, default = "Default::default")]
+    pub provider: M,
+}
+
+/// Standard account snapshot without provider metadata.
+pub type AccountSnapshot = GenericAccountSnapshot<(), ()>;
+
+impl<M: Default, P> GenericAccountSnapshot<M, P> {
+    /// Build an empty snapshot for `account_id` with all optional fields
+    /// unset. `provider` is initialised via `M::default()`.
+    #[must_use]
+    pub fn new(account_id: impl Into<String>, base_currency: Currency) -> Self {
+        Self {
+            account_id: account_id.into(),
+            as_of: None,
+            base_currency,
+            cash: Vec::new(),
+            equity: None,
+            buying_power: None,
+            initial_margin: None,
+            maintenance_margin: None,
+            positions: Vec::new(),
+            provider: M::default(),
+        }
+    }
+}
+
+impl<M, P> GenericAccountSnapshot<M, P> {
+    /// The cash balance held in `currency`.
+    #[must_use]
+    pub fn cash_balance(&self, currency: &Currency) -> Option<&Money> {
+        self.cash
+            .iter()
+            .find(|balance| balance.currency() == currency)
+    }
+
+    /// Set the cash balance for `balance`'s currency, replacing any existing
+    /// balance in that currency.
+    pub fn set_cash_balance(&mut self, balance: Money) {
+        match self
+            .cash
+            .iter_mut()
+            .find(|existing| existing.currency() == balance.currency())
+        {
+            Some(existing) => *existing = balance,
+            None => self.cash.push(balance),
+        }
+    }
+
+    /// The position held in `instrument`.
+    #[must_use]
+    pub fn position(&self, instrument: &Instrument) -> Option<&GenericAccountPosition<P>> {
+        self.positions
+            .iter()
+            .find(|position| position.instrument == *instrument)
+    }
+
+    /// Set the position for `position`'s instrument, replacing any existing
+    /// position in that instrument.
+    pub fn set_position(&mut self, position: GenericAccountPosition<P>) {
+        match self
+            .positions
+            .iter_mut()
+            .find(|existing| existing.instrument == position.instrument)
+        {
+            Some(existing) => *existing = position,
+            None => self.positions.push(position),
+        }
+    }
+
+    /// Sum every cash balance in the base currency, converting through
+    /// `rates`.
+    ///
+    /// # Errors
+    /// Returns [`PortfolioError::Money`] when `rates` lacks a rate for a
+    /// balance currency or the sum overflows the active decimal backend.
+    pub fn total_cash<R>(&self, rates: &R) -> Result<Money, PortfolioError>
+    where
+        R: RateSource + ?Sized,
+    {
+        let bag = MoneyBag::from_amounts(&self.cash)?;
+        Ok(bag.collapse(&self.base_currency, rates)?)
+    }
+
+    /// Equity in excess of the maintenance margin, when both are reported.
+    ///
+    /// # Errors
+    /// Returns [`PortfolioError::Money`] when the two amounts use different
+    /// currencies or the subtraction overflows.
+    pub fn excess_liquidity(&self) -> Result<Option<Money>, PortfolioError> {
+        let (Some(equity), Some(margin)) = (&self.equity, &self.maintenance_margin) else {
+            return Ok(None);
+        };
+        Ok(Some(equity.try_sub(margin)?))
+    }
+
+    /// Validate that balances and positions are keyed uniquely and that the
+    /// account-level amounts are in the base currency.
+    ///
+    /// # Errors
+    /// - [`PortfolioError::InvalidAccountSnapshot`] when two cash balances
+    ///   share a currency or two positions share an instrument.
+    /// - [`PortfolioError::CurrencyMismatch`] when equity, buying power, or
+    ///   a margin requirement is not in `base_currency`.
+    pub fn validate(&self) -> Result<(), PortfolioError> {
+        let mut currencies = HashSet::new();
+        if !self
+            .cash
+            .iter()
+            .all(|balance| currencies.insert(balance.currency()))
+        {
+            return Err(PortfolioError::InvalidAccountSnapshot {
+                reason: "cash balances must have one entry per currency",
+            });
+        }
+        let mut instruments = HashSet::new();
+        if !self
+            .positions
+            .iter()
+            .all(|position| instruments.insert(&position.instrument))
+        {
+            return Err(PortfolioError::InvalidAccountSnapshot {
+                reason: "positions must have one entry per instrument",
+            });
+        }
+        for amount in [
+            &self.equity,
+            &self.buying_power,
+            &self.initial_margin,
+            &self.maintenance_margin,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if amount.currency() != &self.base_currency {
+                return Err(PortfolioError::CurrencyMismatch {
+                    expected: self.base_currency.clone(),
+                    found: amount.currency().clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
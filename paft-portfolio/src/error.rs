@@ -0,0 +1,87 @@
+//! Error types specific to `paft-portfolio`.
+
+use paft_decimal::{Decimal, DecimalConstraintError};
+use paft_money::{Currency, MoneyError};
+use thiserror::Error;
+
+/// Errors returned when building or updating portfolio records.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PortfolioError {
+    /// String value did not match any modeled portfolio enum code.
+    #[error("{enum_name}: invalid enum value '{value}'")]
+    InvalidEnumValue {
+        /// Enum type that rejected the value.
+        enum_name: &'static str,
+        /// Rejected input value.
+        value: String,
+    },
+
+    /// An amount is denominated in a different currency than the record.
+    #[error("Currency mismatch: expected {expected}, found {found}")]
+    CurrencyMismatch {
+        /// Currency of the record.
+        expected: Currency,
+        /// Currency of the rejected amount.
+        found: Currency,
+    },
+
+    /// Market data refers to a different instrument than the position.
+    #[error("Instrument mismatch: expected {expected}, found {found}")]
+    InstrumentMismatch {
+        /// Display key of the position's instrument.
+        expected: String,
+        /// Display key of the rejected instrument.
+        found: String,
+    },
+
+    /// Trade quantity must be greater than zero.
+    #[error("Trade quantity must be greater than zero")]
+    ZeroQuantity,
+
+    /// A sale exceeds the quantity held.
+    #[error("Cannot relieve {requested} units from a position holding {held}")]
+    InsufficientQuantity {
+        /// Quantity held before the sale.
+        held: Decimal,
+        /// Quantity the sale tried to relieve.
+        requested: Decimal,
+    },
+
+    /// Quote carries no last price to value the position with.
+    #[error("Quote has no last price")]
+    MissingMarketPrice,
+
+    /// Corporate action kind that positions do not model.
+    #[error("Unsupported corporate action")]
+    UnsupportedAction,
+
+    /// Restored position parts are inconsistent.
+    #[error("Invalid position: {reason}")]
+    InvalidPosition {
+        /// Validation failure reason.
+        reason: &'static str,
+    },
+
+    /// Ledger entry lacks fields its activity kind requires.
+    #[error("Invalid ledger entry: {reason}")]
+    InvalidLedgerEntry {
+        /// Validation failure reason.
+        reason: &'static str,
+    },
+
+    /// Account snapshot has duplicate balances or positions.
+    #[error("Invalid account snapshot: {reason}")]
+    InvalidAccountSnapshot {
+        /// Validation failure reason.
+        reason: &'static str,
+    },
+
+    /// A computed quantity violated its decimal constraint.
+    #[error(transparent)]
+    Quantity(#[from] DecimalConstraintError),
+
+    /// Monetary arithmetic failed.
+    #[error(transparent)]
+    Money(#[from] MoneyError),
+}
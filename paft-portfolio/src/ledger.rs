@@ -0,0 +1,263 @@
+//! Provider-agnostic account activity under the `paft_portfolio::ledger`
+//! namespace.
+//!
+//! A [`GenericLedgerEntry`] records one brokerage activity: a trade, a cash
+//! distribution, a charge, a transfer, an FX conversion, or a corporate
+//! action adjustment. Activity kinds providers report that paft does not
+//! model are preserved through [`ActivityKind::Other`].
+
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+use paft_domain::Instrument;
+use paft_market::Action;
+use paft_money::{Money, Price, QuantityAmount};
+use serde::{Deserialize, Serialize};
+
+use crate::error::PortfolioError;
+use crate::position::{Trade, TradeSide};
+
+paft_core::other_string_code_type!(
+    /// Provider-specific activity kind not modeled by [`ActivityKind`].
+    pub struct OtherActivityKind for ActivityKind;
+    type Error = PortfolioError;
+    parse(input) => ActivityKind::from_str(input);
+    invalid(input) => PortfolioError::InvalidEnumValue {
+        enum_name: "ActivityKind",
+        value: input.to_string(),
+    };
+);
+
+/// Kind of account activity with canonical variants and extensible fallback.
+///
+/// Canonical/serde rules:
+/// - Emission uses a single canonical form per variant (UPPERCASE ASCII, no spaces)
+/// - Parser accepts a superset of tokens (aliases, case-insensitive)
+/// - `Other(s)` serializes to its canonical `code()` string (no escape prefix)
+/// - Unknown tokens normalize to `Other(UPPERCASE)`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ActivityKind {
+    /// Purchase of an instrument.
+    Buy,
+    /// Sale of an instrument.
+    Sell,
+    /// Cash dividend or other distribution on a held instrument.
+    Dividend,
+    /// Interest credited or charged on cash or margin balances.
+    Interest,
+    /// Account or transaction fee charged separately from a trade.
+    Fee,
+    /// Tax withheld at source, such as dividend withholding tax.
+    TaxWithholding,
+    /// Cash transferred into the account.
+    Deposit,
+    /// Cash transferred out of the account.
+    Withdrawal,
+    /// Exchange of one currency for another.
+    FxConversion,
+    /// Position adjustment from a split, merger, spin-off, or similar event.
+    CorporateAction,
+    /// Unknown or provider-specific activity kind.
+    Other(OtherActivityKind),
+}
+
+impl ActivityKind {
+    /// Attempts to parse an activity kind, uppercasing unknown inputs into `Other`.
+    ///
+    /// # Errors
+    /// Returns `PortfolioError::InvalidEnumValue` when `input` is empty/whitespace.
+    pub fn try_from_str(input: &str) -> Result<Self, PortfolioError> {
+        Self::from_str(input)
+    }
+
+    /// Builds an unknown activity kind, rejecting modeled kinds and aliases.
+    ///
+    /// # Errors
+    /// Returns an error if `input` is empty, cannot be canonicalized, or parses
+    /// to a modeled [`ActivityKind`] variant.
+    pub fn other(input: &str) -> Result<Self, PortfolioError> {
+        OtherActivityKind::new(input).map(Self::Other)
+    }
+
+    /// Trade direction of [`ActivityKind::Buy`] and [`ActivityKind::Sell`].
+    #[must_use]
+    pub const fn trade_side(&self) -> Option<TradeSide> {
+        match self {
+            Self::Buy => Some(TradeSide::Buy),
+            Self::Sell => Some(TradeSide::Sell),
+            _ => None,
+        }
+    }
+}
+
+// Centralized code() and string impls via macro
+paft_core::string_enum_with_code!(
+    ActivityKind, Other(OtherActivityKind), "ActivityKind",
+    type Error = PortfolioError;
+    invalid(input) => PortfolioError::InvalidEnumValue {
+        enum_name: "ActivityKind",
+        value: input.to_string(),
+    };
+    {
+        "BUY" => ActivityKind::Buy,
+        "SELL" => ActivityKind::Sell,
+        "DIVIDEND" => ActivityKind::Dividend,
+        "INTEREST" => ActivityKind::Interest,
+        "FEE" => ActivityKind::Fee,
+        "TAX_WITHHOLDING" => ActivityKind::TaxWithholding,
+        "DEPOSIT" => ActivityKind::Deposit,
+        "WITHDRAWAL" => ActivityKind::Withdrawal,
+        "FX_CONVERSION" => ActivityKind::FxConversion,
+        "CORPORATE_ACTION" => ActivityKind::CorporateAction
+    },
+    {
+        // Aliases
+        "PURCHASE" => ActivityKind::Buy,
+        "SALE" => ActivityKind::Sell,
+        "DIV" => ActivityKind::Dividend,
+        "CASH_DIVIDEND" => ActivityKind::Dividend,
+        "COMMISSION" => ActivityKind::Fee,
+        "WITHHOLDING_TAX" => ActivityKind::TaxWithholding,
+        "WITHHOLDING" => ActivityKind::TaxWithholding,
+        "FX" => ActivityKind::FxConversion,
+        "CURRENCY_CONVERSION" => ActivityKind::FxConversion
+    }
+);
+
+paft_core::impl_display_via_code!(ActivityKind);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+/// One account activity as reported by a brokerage.
+///
+/// `amount` is the net cash movement in the account: positive for inflows
+/// such as sale proceeds, dividends, and deposits, and negative for outflows
+/// such as purchases, fees, withholding, and withdrawals. The remaining
+/// fields are optional; [`GenericLedgerEntry::validate`] checks the ones each
+/// kind needs.
+///
+/// Generic over a provider metadata payload `M`, which is flattened into the
+/// serialized representation. Use the [`LedgerEntry`] alias for the
+/// standard shape (no extra metadata).
+///
+/// **Collision warning:** provider metadata is flattened into the same object
+/// as paft fields. Metadata field names must not collide with paft field
+/// names; prefer provider-specific prefixes when in doubt.
+pub struct GenericLedgerEntry<M = ()> {
+    /// Provider-assigned activity identifier.
+    pub id: Option<String>,
+    /// Activity kind.
+    pub kind: ActivityKind,
+    /// Date the activity took effect; the trade date for trades.
+    pub date: NaiveDate,
+    /// Settlement date, when it differs from `date`.
+    pub settlement_date: Option<NaiveDate>,
+    /// Instrument the activity relates to.
+    pub instrument: Option<Instrument>,
+    /// Units traded or adjusted.
+    pub quantity: Option<QuantityAmount>,
+    /// Execution price per unit for trades.
+    pub price: Option<Price>,
+    /// Net cash movement; negative for outflows.
+    pub amount: Money,
+    /// Commissions and other charges included in `amount`.
+    pub fees: Option<Money>,
+    /// Taxes included in `amount`.
+    pub tax: Option<Money>,
+    /// Amount in the other currency of an FX conversion.
+    pub counter_amount: Option<Money>,
+    /// Corporate action behind an adjustment or distribution.
+    pub action: Option<Action>,
+    /// Free-form provider description.
+    pub description: Option<String>,
+    /// Provider-specific payload, flattened into the serialized form.
+    #[serde(flatten, default = "Default::default")]
+    pub provider: M,
+}
+
+/// Standard ledger entry without provider metadata.
+pub type LedgerEntry = GenericLedgerEntry<()>;
+
+impl<M: Default> GenericLedgerEntry<M> {
+    /// Build an entry of `kind` moving `amount` on `date`, with all optional
+    /// fields unset. `provider` is initialised via `M::default()`.
+    #[must_use]
+    pub fn new(kind: ActivityKind, date: NaiveDate, amount: Money) -> Self {
+        Self {
+            id: None,
+            kind,
+            date,
+            settlement_date: None,
+            instrument: None,
+            quantity: None,
+            price: None,
+            amount,
+            fees: None,
+            tax: None,
+            counter_amount: None,
+            action: None,
+            description: None,
+            provider: M::default(),
+        }
+    }
+}
+
+impl<M> GenericLedgerEntry<M> {
+    /// Validate that the fields the entry's kind relies on are present.
+    ///
+    /// # Errors
+    /// - [`PortfolioError::InvalidLedgerEntry`] when a trade lacks its
+    ///   instrument, quantity, or price, a dividend or corporate action
+    ///   lacks its instrument, or an FX conversion lacks a counter amount in
+    ///   another currency.
+    /// - [`PortfolioError::ZeroQuantity`] when a trade quantity is zero.
+    pub fn validate(&self) -> Result<(), PortfolioError> {
+        let invalid = |reason| Err(PortfolioError::InvalidLedgerEntry { reason });
+        match &self.kind {
+            ActivityKind::Buy | ActivityKind::Sell => {
+                let (Some(_), Some(quantity), Some(_)) =
+                    (&self.instrument, &self.quantity, &self.price)
+                else {
+                    return invalid("trades require an instrument, quantity, and price");
+                };
+                if *quantity.as_decimal() == paft_decimal::zero() {
+                    return Err(PortfolioError::ZeroQuantity);
+                }
+            }
+            ActivityKind::Dividend | ActivityKind::CorporateAction if self.instrument.is_none() => {
+                return invalid("dividends and corporate actions require an instrument");
+            }
+            ActivityKind::FxConversion => match &self.counter_amount {
+                None => return invalid("FX conversions require a counter amount"),
+                Some(counter) if counter.currency() == self.amount.currency() => {
+                    return invalid("FX conversions must exchange two different currencies");
+                }
+                Some(_) => {}
+            },
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Convert a buy or sell into a [`Trade`] for [`crate::Position`]
+    /// accounting; other kinds return `None`.
+    ///
+    /// # Errors
+    /// Returns the errors of [`GenericLedgerEntry::validate`].
+    pub fn to_trade(&self) -> Result<Option<Trade>, PortfolioError> {
+        let Some(side) = self.kind.trade_side() else {
+            return Ok(None);
+        };
+        self.validate()?;
+        let (Some(quantity), Some(price)) = (&self.quantity, &self.price) else {
+            return Ok(None);
+        };
+        Ok(Some(Trade {
+            side,
+            date: self.date,
+            quantity: quantity.clone(),
+            price: price.clone(),
+            fees: self.fees.clone(),
+        }))
+    }
+}
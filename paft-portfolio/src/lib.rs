@@ -0,0 +1,34 @@
+//! Position and tax-lot accounting for the paft ecosystem.
+//!
+//! This crate builds per-instrument positions from buy and sell trades on
+//! top of `paft-money` amounts and `paft-market` quotes and corporate
+//! actions:
+//!
+//! - [`Position`] tracks open [`TaxLot`]s and closed [`RealizedLot`]s for one
+//!   instrument in one currency.
+//! - [`LotMethod`] selects FIFO, LIFO, highest-cost, or average-cost lot
+//!   relief.
+//! - Realized P&L comes from closed lots; unrealized P&L and market value are
+//!   measured against a `Quote`.
+//! - Splits and dividends are applied from market `Action`s.
+//! - [`GenericLedgerEntry`] records provider-agnostic account activity, from
+//!   trades and distributions to fees, transfers, and FX conversions, with an
+//!   open [`ActivityKind`] for kinds paft does not model.
+//! - [`GenericAccountSnapshot`] captures an account's cash balances per
+//!   currency, buying power, margin requirements, and positions keyed by
+//!   instrument, with optional `DataFrame` export.
+
+#![forbid(unsafe_code)]
+#![warn(missing_docs)]
+
+pub mod account;
+pub mod error;
+pub mod ledger;
+pub mod position;
+
+pub use account::{
+    AccountPosition, AccountSnapshot, GenericAccountPosition, GenericAccountSnapshot,
+};
+pub use error::PortfolioError;
+pub use ledger::{ActivityKind, GenericLedgerEntry, LedgerEntry, OtherActivityKind};
+pub use position::{LotMethod, Position, RealizedLot, TaxLot, Trade, TradeSide};
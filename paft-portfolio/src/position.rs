@@ -0,0 +1,676 @@
+//! Positions and tax lots under the `paft_portfolio::position` namespace.
+//!
+//! A [`Position`] tracks one instrument held in one currency. Buys open
+//! [`TaxLot`]s; sells relieve them in [`LotMethod`] order and record one
+//! [`RealizedLot`] per lot they touch. Splits and cash distributions are
+//! applied from market [`Action`]s.
+
+use chrono::NaiveDate;
+use paft_decimal::{self as decimal, Decimal};
+use paft_domain::Instrument;
+use paft_market::{Action, GenericQuote};
+use paft_money::{Currency, MonetaryAmount, Money, MoneyError, Price, QuantityAmount};
+use serde::{Deserialize, Deserializer, Serialize, de};
+
+use crate::error::PortfolioError;
+
+/// Order in which sells relieve open tax lots.
+///
+/// Canonical codes are UPPERCASE (`"FIFO"`, `"HIGHEST_COST"`, ...); parsing
+/// is case-insensitive and accepts common abbreviations such as `"HIFO"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum LotMethod {
+    /// First in, first out: the oldest lots are sold first.
+    #[default]
+    Fifo,
+    /// Last in, first out: the newest lots are sold first.
+    Lifo,
+    /// The lots with the highest unit cost are sold first.
+    HighestCost,
+    /// Every sale is costed at the position's average unit cost; lots keep
+    /// their dates and are relieved oldest first.
+    AverageCost,
+}
+
+paft_core::string_enum_closed_with_code!(
+    LotMethod, "LotMethod",
+    type Error = PortfolioError;
+    invalid(input) => PortfolioError::InvalidEnumValue {
+        enum_name: "LotMethod",
+        value: input.to_string(),
+    };
+    {
+        "FIFO" => LotMethod::Fifo,
+        "LIFO" => LotMethod::Lifo,
+        "HIGHEST_COST" => LotMethod::HighestCost,
+        "AVERAGE_COST" => LotMethod::AverageCost
+    },
+    {
+        "HIFO" => LotMethod::HighestCost,
+        "AVERAGE" => LotMethod::AverageCost,
+        "AVCO" => LotMethod::AverageCost
+    }
+);
+
+paft_core::impl_display_via_code!(LotMethod);
+
+/// Direction of a [`Trade`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TradeSide {
+    /// Opens or adds to a position.
+    Buy,
+    /// Reduces a position.
+    Sell,
+}
+
+paft_core::string_enum_closed_with_code!(
+    TradeSide, "TradeSide",
+    type Error = PortfolioError;
+    invalid(input) => PortfolioError::InvalidEnumValue {
+        enum_name: "TradeSide",
+        value: input.to_string(),
+    };
+    { "BUY" => TradeSide::Buy, "SELL" => TradeSide::Sell },
+    { "B" => TradeSide::Buy, "S" => TradeSide::Sell }
+);
+
+paft_core::impl_display_via_code!(TradeSide);
+
+/// Buy or sell transaction applied to a [`Position`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Trade {
+    /// Trade direction.
+    pub side: TradeSide,
+    /// Trade date; buys open lots on this date and sells close them.
+    pub date: NaiveDate,
+    /// Units traded.
+    pub quantity: QuantityAmount,
+    /// Execution price per unit.
+    pub price: Price,
+    /// Commissions and other charges, in the price currency.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fees: Option<Money>,
+}
+
+impl Trade {
+    /// Build a buy of `quantity` units at `price` without fees.
+    #[must_use]
+    pub const fn buy(date: NaiveDate, quantity: QuantityAmount, price: Price) -> Self {
+        Self {
+            side: TradeSide::Buy,
+            date,
+            quantity,
+            price,
+            fees: None,
+        }
+    }
+
+    /// Build a sell of `quantity` units at `price` without fees.
+    #[must_use]
+    pub const fn sell(date: NaiveDate, quantity: QuantityAmount, price: Price) -> Self {
+        Self {
+            side: TradeSide::Sell,
+            date,
+            quantity,
+            price,
+            fees: None,
+        }
+    }
+
+    /// Attach the fees charged on this trade.
+    #[must_use]
+    pub fn with_fees(mut self, fees: Money) -> Self {
+        self.fees = Some(fees);
+        self
+    }
+
+    /// Gross trade value, `price × quantity`.
+    ///
+    /// # Errors
+    /// Returns [`PortfolioError::Money`] when the active decimal backend
+    /// overflows.
+    pub fn gross(&self) -> Result<MonetaryAmount, PortfolioError> {
+        Ok(self.price.try_total(&self.quantity)?)
+    }
+
+    /// Net cash value: the gross value plus fees for a buy (its cost) and
+    /// minus fees for a sell (its proceeds).
+    ///
+    /// # Errors
+    /// - [`PortfolioError::CurrencyMismatch`] when the fees are not in the
+    ///   price currency.
+    /// - [`PortfolioError::Money`] when the active decimal backend overflows.
+    pub fn net_amount(&self) -> Result<MonetaryAmount, PortfolioError> {
+        let gross = self.gross()?;
+        let Some(fees) = &self.fees else {
+            return Ok(gross);
+        };
+        ensure_currency(self.price.currency(), fees.currency())?;
+        let fees = MonetaryAmount::from(fees);
+        Ok(match self.side {
+            TradeSide::Buy => gross.try_add(&fees)?,
+            TradeSide::Sell => gross.try_sub(&fees)?,
+        })
+    }
+}
+
+/// Open tax lot: units acquired on one date and their remaining cost.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaxLot {
+    /// Acquisition date.
+    pub opened: NaiveDate,
+    /// Units still held.
+    pub quantity: QuantityAmount,
+    /// Cost of the units still held, fees included.
+    pub cost: MonetaryAmount,
+}
+
+impl TaxLot {
+    /// Build a lot of `quantity` units costing `cost` in total.
+    #[must_use]
+    pub const fn new(opened: NaiveDate, quantity: QuantityAmount, cost: MonetaryAmount) -> Self {
+        Self {
+            opened,
+            quantity,
+            cost,
+        }
+    }
+
+    /// Cost per unit held.
+    ///
+    /// # Errors
+    /// Returns [`PortfolioError::Money`] when the lot is empty or the active
+    /// decimal backend overflows.
+    pub fn unit_cost(&self) -> Result<Price, PortfolioError> {
+        let unit = self.cost.try_div(self.quantity.as_decimal())?;
+        Ok(Price::new(unit.amount(), unit.currency().clone()))
+    }
+}
+
+/// Units of one tax lot closed by a sale.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RealizedLot {
+    /// Acquisition date of the relieved lot.
+    pub opened: NaiveDate,
+    /// Date of the closing sale.
+    pub closed: NaiveDate,
+    /// Units closed.
+    pub quantity: QuantityAmount,
+    /// Cost relieved from the lot.
+    pub cost: MonetaryAmount,
+    /// Share of the sale's net proceeds.
+    pub proceeds: MonetaryAmount,
+}
+
+impl RealizedLot {
+    /// Realized gain (negative for a loss), `proceeds − cost`.
+    ///
+    /// # Errors
+    /// Returns [`PortfolioError::Money`] when the amounts use different
+    /// currencies or the active decimal backend overflows.
+    pub fn gain(&self) -> Result<MonetaryAmount, PortfolioError> {
+        Ok(self.proceeds.try_sub(&self.cost)?)
+    }
+}
+
+/// Holding of one instrument in one currency, tracked as tax lots.
+///
+/// Apply trades in chronological order; lots opened later than a sale are
+/// still eligible for relief. Every update is atomic: a trade or action
+/// that fails leaves the position unchanged.
+///
+/// Deserialization validates the lots and amounts through
+/// [`Position::from_parts`] and recomputes the held quantity and cost basis.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Position {
+    instrument: Instrument,
+    currency: Currency,
+    method: LotMethod,
+    lots: Vec<TaxLot>,
+    realized: Vec<RealizedLot>,
+    income: MonetaryAmount,
+    #[serde(skip)]
+    quantity: QuantityAmount,
+    #[serde(skip)]
+    cost_basis: MonetaryAmount,
+}
+
+impl Position {
+    /// Build an empty position in `instrument`, costed in `currency` and
+    /// relieved with `method`.
+    #[must_use]
+    pub fn new(instrument: Instrument, currency: Currency, method: LotMethod) -> Self {
+        Self {
+            instrument,
+            income: MonetaryAmount::zero(currency.clone()),
+            cost_basis: MonetaryAmount::zero(currency.clone()),
+            currency,
+            method,
+            lots: Vec::new(),
+            realized: Vec::new(),
+            quantity: zero_quantity(),
+        }
+    }
+
+    /// Build a position by applying `trades` in order to an empty position.
+    ///
+    /// # Errors
+    /// Returns the first error raised by [`Position::apply_trade`].
+    pub fn from_trades<'a>(
+        instrument: Instrument,
+        currency: Currency,
+        method: LotMethod,
+        trades: impl IntoIterator<Item = &'a Trade>,
+    ) -> Result<Self, PortfolioError> {
+        let mut position = Self::new(instrument, currency, method);
+        for trade in trades {
+            position.apply_trade(trade)?;
+        }
+        Ok(position)
+    }
+
+    /// Restore a position from previously recorded lots and history.
+    ///
+    /// # Errors
+    /// - [`PortfolioError::CurrencyMismatch`] when a lot, realized lot, or
+    ///   the income is not in `currency`.
+    /// - [`PortfolioError::InvalidPosition`] when an open lot is empty.
+    /// - [`PortfolioError::Money`] when the active decimal backend overflows.
+    pub fn from_parts(
+        instrument: Instrument,
+        currency: Currency,
+        method: LotMethod,
+        lots: Vec<TaxLot>,
+        realized: Vec<RealizedLot>,
+        income: MonetaryAmount,
+    ) -> Result<Self, PortfolioError> {
+        for lot in &lots {
+            ensure_currency(&currency, lot.cost.currency())?;
+            if is_zero(lot.quantity.as_decimal()) {
+                return Err(PortfolioError::InvalidPosition {
+                    reason: "open lots must hold a positive quantity",
+                });
+            }
+        }
+        for lot in &realized {
+            ensure_currency(&currency, lot.cost.currency())?;
+            ensure_currency(&currency, lot.proceeds.currency())?;
+        }
+        ensure_currency(&currency, income.currency())?;
+
+        let mut position = Self::new(instrument, currency, method);
+        position.lots = lots;
+        position.realized = realized;
+        position.income = income;
+        position.refresh()?;
+        Ok(position)
+    }
+
+    /// Instrument held.
+    #[must_use]
+    pub const fn instrument(&self) -> &Instrument {
+        &self.instrument
+    }
+
+    /// Currency of every cost, proceeds, and income amount.
+    #[must_use]
+    pub const fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    /// Lot relief method applied to sales.
+    #[must_use]
+    pub const fn method(&self) -> LotMethod {
+        self.method
+    }
+
+    /// Open lots, in the order they were opened.
+    #[must_use]
+    pub fn lots(&self) -> &[TaxLot] {
+        &self.lots
+    }
+
+    /// Closed lot portions, in the order they were realized.
+    #[must_use]
+    pub fn realized(&self) -> &[RealizedLot] {
+        &self.realized
+    }
+
+    /// Dividends and capital gain distributions received.
+    #[must_use]
+    pub const fn income(&self) -> &MonetaryAmount {
+        &self.income
+    }
+
+    /// Units held across all open lots.
+    #[must_use]
+    pub const fn quantity(&self) -> &QuantityAmount {
+        &self.quantity
+    }
+
+    /// Total cost of the open lots.
+    #[must_use]
+    pub const fn cost_basis(&self) -> &MonetaryAmount {
+        &self.cost_basis
+    }
+
+    /// Returns `true` when no units are held.
+    #[must_use]
+    pub const fn is_flat(&self) -> bool {
+        self.lots.is_empty()
+    }
+
+    /// Average cost per unit held, or `None` for a flat position.
+    ///
+    /// # Errors
+    /// Returns [`PortfolioError::Money`] when the active decimal backend
+    /// overflows.
+    pub fn average_cost(&self) -> Result<Option<Price>, PortfolioError> {
+        if self.is_flat() {
+            return Ok(None);
+        }
+        let unit = self.cost_basis.try_div(self.quantity.as_decimal())?;
+        Ok(Some(Price::new(unit.amount(), self.currency.clone())))
+    }
+
+    /// Total realized gain across every closed lot portion.
+    ///
+    /// # Errors
+    /// Returns [`PortfolioError::Money`] when the active decimal backend
+    /// overflows.
+    pub fn realized_pnl(&self) -> Result<MonetaryAmount, PortfolioError> {
+        self.realized
+            .iter()
+            .try_fold(MonetaryAmount::zero(self.currency.clone()), |total, lot| {
+                Ok(total.try_add(&lot.gain()?)?)
+            })
+    }
+
+    /// Value of the units held at the quote's last price.
+    ///
+    /// # Errors
+    /// - [`PortfolioError::InstrumentMismatch`] when the quote is for another
+    ///   instrument.
+    /// - [`PortfolioError::CurrencyMismatch`] when the quote is in another
+    ///   currency.
+    /// - [`PortfolioError::MissingMarketPrice`] when the quote has no price.
+    /// - [`PortfolioError::Money`] when the active decimal backend overflows.
+    pub fn market_value<Q, L>(
+        &self,
+        quote: &GenericQuote<Q, L>,
+    ) -> Result<MonetaryAmount, PortfolioError> {
+        if quote.instrument != self.instrument {
+            return Err(PortfolioError::InstrumentMismatch {
+                expected: self.instrument.to_string(),
+                found: quote.instrument.to_string(),
+            });
+        }
+        ensure_currency(&self.currency, &quote.currency)?;
+        let price = quote
+            .price
+            .as_ref()
+            .ok_or(PortfolioError::MissingMarketPrice)?;
+        Ok(price
+            .with_currency(self.currency.clone())
+            .try_total(&self.quantity)?)
+    }
+
+    /// Unrealized gain (negative for a loss) of the open lots at the quote's
+    /// last price, `market value − cost basis`.
+    ///
+    /// # Errors
+    /// Returns the errors of [`Position::market_value`].
+    pub fn unrealized_pnl<Q, L>(
+        &self,
+        quote: &GenericQuote<Q, L>,
+    ) -> Result<MonetaryAmount, PortfolioError> {
+        Ok(self.market_value(quote)?.try_sub(&self.cost_basis)?)
+    }
+
+    /// Apply a buy or sell.
+    ///
+    /// Buys open a lot costing `price × quantity + fees`. Sells relieve
+    /// `quantity` units in [`LotMethod`] order; each relieved lot portion
+    /// takes a pro-rata share of the lot's cost and of the sale's
+    /// `price × quantity − fees` proceeds.
+    ///
+    /// # Errors
+    /// - [`PortfolioError::ZeroQuantity`] when the trade quantity is zero.
+    /// - [`PortfolioError::CurrencyMismatch`] when the price or fees are not
+    ///   in the position currency.
+    /// - [`PortfolioError::InsufficientQuantity`] when a sale exceeds the
+    ///   units held.
+    /// - [`PortfolioError::Money`] when the active decimal backend overflows.
+    pub fn apply_trade(&mut self, trade: &Trade) -> Result<(), PortfolioError> {
+        if is_zero(trade.quantity.as_decimal()) {
+            return Err(PortfolioError::ZeroQuantity);
+        }
+        ensure_currency(&self.currency, trade.price.currency())?;
+        let amount = trade.net_amount()?;
+
+        let mut next = self.clone();
+        match trade.side {
+            TradeSide::Buy => {
+                next.lots
+                    .push(TaxLot::new(trade.date, trade.quantity.clone(), amount));
+            }
+            TradeSide::Sell => next.relieve(trade, &amount)?,
+        }
+        next.refresh()?;
+        *self = next;
+        Ok(())
+    }
+
+    /// Apply a corporate action to the lots held before its date.
+    ///
+    /// Splits scale those lots' quantities by `numerator / denominator` and
+    /// leave their cost unchanged. Dividends and capital gain distributions
+    /// add the per-share amount times those lots' quantity to
+    /// [`Position::income`].
+    ///
+    /// # Errors
+    /// - [`PortfolioError::CurrencyMismatch`] when a distribution is not in
+    ///   the position currency.
+    /// - [`PortfolioError::UnsupportedAction`] for action kinds positions do
+    ///   not model.
+    /// - [`PortfolioError::Money`] when the active decimal backend overflows.
+    pub fn apply_action(&mut self, action: &Action) -> Result<(), PortfolioError> {
+        let mut next = self.clone();
+        match action {
+            Action::Split {
+                date,
+                numerator,
+                denominator,
+            } => {
+                let numerator = Decimal::from(numerator.get());
+                let denominator = Decimal::from(denominator.get());
+                for lot in next.lots.iter_mut().filter(|lot| lot.opened < *date) {
+                    let scaled =
+                        checked(decimal::checked_mul(lot.quantity.as_decimal(), &numerator))?;
+                    lot.quantity = QuantityAmount::from_decimal(checked(decimal::checked_div(
+                        &scaled,
+                        &denominator,
+                    ))?)?;
+                }
+            }
+            Action::Dividend { date, amount } => next.distribute(*date, amount)?,
+            Action::CapitalGain { date, gain } => next.distribute(*date, gain)?,
+            _ => return Err(PortfolioError::UnsupportedAction),
+        }
+        next.refresh()?;
+        *self = next;
+        Ok(())
+    }
+
+    fn distribute(&mut self, date: NaiveDate, per_share: &Price) -> Result<(), PortfolioError> {
+        ensure_currency(&self.currency, per_share.currency())?;
+        let eligible = self
+            .lots
+            .iter()
+            .filter(|lot| lot.opened < date)
+            .try_fold(decimal::zero(), |total, lot| {
+                checked(decimal::checked_add(&total, lot.quantity.as_decimal()))
+            })?;
+        let paid = per_share.try_total(&QuantityAmount::from_decimal(eligible)?)?;
+        self.income = self.income.try_add(&paid)?;
+        Ok(())
+    }
+
+    fn relieve(&mut self, trade: &Trade, proceeds: &MonetaryAmount) -> Result<(), PortfolioError> {
+        let requested = trade.quantity.as_decimal();
+        if requested > self.quantity.as_decimal() {
+            return Err(PortfolioError::InsufficientQuantity {
+                held: decimal::clone_decimal(self.quantity.as_decimal()),
+                requested: decimal::clone_decimal(requested),
+            });
+        }
+        if self.method == LotMethod::AverageCost {
+            self.pool_costs()?;
+        }
+
+        let mut remaining = decimal::clone_decimal(requested);
+        let mut unallocated = proceeds.clone();
+        for index in self.relief_order()? {
+            if is_zero(&remaining) {
+                break;
+            }
+            let lot = &mut self.lots[index];
+            let held = decimal::clone_decimal(lot.quantity.as_decimal());
+            let (take, cost) = if remaining >= held {
+                (decimal::clone_decimal(&held), lot.cost.clone())
+            } else {
+                let cost = lot.cost.try_mul(&remaining)?.try_div(&held)?;
+                (decimal::clone_decimal(&remaining), cost)
+            };
+            remaining = checked(decimal::checked_sub(&remaining, &take))?;
+            // The last portion takes whatever is left so the portions always
+            // sum to the sale's proceeds.
+            let share = if is_zero(&remaining) {
+                unallocated.clone()
+            } else {
+                proceeds.try_mul(&take)?.try_div(requested)?
+            };
+            unallocated = unallocated.try_sub(&share)?;
+
+            lot.quantity =
+                QuantityAmount::from_decimal(checked(decimal::checked_sub(&held, &take))?)?;
+            lot.cost = lot.cost.try_sub(&cost)?;
+            self.realized.push(RealizedLot {
+                opened: lot.opened,
+                closed: trade.date,
+                quantity: QuantityAmount::from_decimal(take)?,
+                cost,
+                proceeds: share,
+            });
+        }
+        self.lots.retain(|lot| !is_zero(lot.quantity.as_decimal()));
+        Ok(())
+    }
+
+    /// Lot indices in the order sales relieve them.
+    fn relief_order(&self) -> Result<Vec<usize>, PortfolioError> {
+        let mut order: Vec<usize> = (0..self.lots.len()).collect();
+        match self.method {
+            LotMethod::Fifo | LotMethod::AverageCost => {
+                order.sort_by_key(|&index| self.lots[index].opened);
+            }
+            LotMethod::Lifo => {
+                order.reverse();
+                order.sort_by_key(|&index| std::cmp::Reverse(self.lots[index].opened));
+            }
+            LotMethod::HighestCost => {
+                let unit_costs = self
+                    .lots
+                    .iter()
+                    .map(|lot| Ok(lot.unit_cost()?.amount()))
+                    .collect::<Result<Vec<Decimal>, PortfolioError>>()?;
+                order.sort_by(|&a, &b| unit_costs[b].cmp(&unit_costs[a]));
+            }
+        }
+        Ok(order)
+    }
+
+    /// Restate every lot's cost at the position's average unit cost.
+    fn pool_costs(&mut self) -> Result<(), PortfolioError> {
+        let total = decimal::clone_decimal(self.quantity.as_decimal());
+        let mut unallocated = self.cost_basis.clone();
+        let last = self.lots.len().saturating_sub(1);
+        for (index, lot) in self.lots.iter_mut().enumerate() {
+            lot.cost = if index == last {
+                unallocated.clone()
+            } else {
+                self.cost_basis
+                    .try_mul(lot.quantity.as_decimal())?
+                    .try_div(&total)?
+            };
+            unallocated = unallocated.try_sub(&lot.cost)?;
+        }
+        Ok(())
+    }
+
+    /// Recompute the cached quantity and cost basis from the open lots.
+    fn refresh(&mut self) -> Result<(), PortfolioError> {
+        let mut quantity = decimal::zero();
+        let mut cost_basis = MonetaryAmount::zero(self.currency.clone());
+        for lot in &self.lots {
+            quantity = checked(decimal::checked_add(&quantity, lot.quantity.as_decimal()))?;
+            cost_basis = cost_basis.try_add(&lot.cost)?;
+        }
+        self.quantity = QuantityAmount::from_decimal(quantity)?;
+        self.cost_basis = cost_basis;
+        Ok(())
+    }
+}
+
+impl<'de> Deserialize<'de> for Position {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct PositionShadow {
+            instrument: Instrument,
+            currency: Currency,
+            method: LotMethod,
+            lots: Vec<TaxLot>,
+            realized: Vec<RealizedLot>,
+            income: MonetaryAmount,
+        }
+
+        let shadow = PositionShadow::deserialize(deserializer)?;
+        Self::from_parts(
+            shadow.instrument,
+            shadow.currency,
+            shadow.method,
+            shadow.lots,
+            shadow.realized,
+            shadow.income,
+        )
+        .map_err(de::Error::custom)
+    }
+}
+
+fn ensure_currency(expected: &Currency, found: &Currency) -> Result<(), PortfolioError> {
+    if expected == found {
+        Ok(())
+    } else {
+        Err(PortfolioError::CurrencyMismatch {
+            expected: expected.clone(),
+            found: found.clone(),
+        })
+    }
+}
+
+fn checked(value: Option<Decimal>) -> Result<Decimal, PortfolioError> {
+    value.ok_or(PortfolioError::Money(MoneyError::ConversionError))
+}
+
+fn zero_quantity() -> QuantityAmount {
+    QuantityAmount::from_decimal(decimal::zero()).expect("zero is non-negative")
+}
+
+fn is_zero(value: &Decimal) -> bool {
+    *value == decimal::zero()
+}
@@ -0,0 +1,167 @@
+use chrono::NaiveDate;
+use paft_decimal::Decimal;
+use paft_domain::{AssetKind, Instrument};
+use paft_money::{Currency, ExchangeRate, IsoCurrency, Money, Price, QuantityAmount, RateBook};
+use paft_portfolio::{
+    AccountPosition, AccountSnapshot, GenericAccountPosition, GenericAccountSnapshot, LotMethod,
+    PortfolioError, Position, Trade,
+};
+use serde::{Deserialize, Serialize};
+
+fn decimal(value: &str) -> Decimal {
+    value.parse().unwrap()
+}
+
+const fn usd() -> Currency {
+    Currency::Iso(IsoCurrency::USD)
+}
+
+const fn eur() -> Currency {
+    Currency::Iso(IsoCurrency::EUR)
+}
+
+fn money(value: &str, currency: Currency) -> Money {
+    Money::new(decimal(value), currency).unwrap()
+}
+
+fn qty(value: &str) -> QuantityAmount {
+    QuantityAmount::from_decimal(decimal(value)).unwrap()
+}
+
+fn instrument(symbol: &str) -> Instrument {
+    Instrument::from_symbol(symbol, AssetKind::Equity).unwrap()
+}
+
+fn account() -> AccountSnapshot {
+    let mut account = AccountSnapshot::new("U-1", usd());
+    account.cash = vec![money("1500", usd()), money("200", eur())];
+    account.equity = Some(money("12000", usd()));
+    account.buying_power = Some(money("24000", usd()));
+    account.initial_margin = Some(money("5000", usd()));
+    account.maintenance_margin = Some(money("2500", usd()));
+    account.positions = vec![
+        AccountPosition::new(instrument("AAPL"), qty("10")),
+        AccountPosition::new(instrument("MSFT"), qty("5")),
+    ];
+    account
+}
+
+#[test]
+fn balances_and_positions_are_keyed_for_lookup() {
+    let mut account = account();
+    assert_eq!(account.validate(), Ok(()));
+    assert_eq!(account.cash_balance(&eur()), Some(&money("200", eur())));
+    assert_eq!(account.cash_balance(&Currency::Iso(IsoCurrency::JPY)), None);
+    assert_eq!(
+        account.position(&instrument("MSFT")).unwrap().quantity,
+        qty("5")
+    );
+
+    account.set_cash_balance(money("-50", eur()));
+    account.set_position(AccountPosition::new(instrument("AAPL"), qty("12")));
+    account.set_position(AccountPosition::new(instrument("NVDA"), qty("1")));
+    assert_eq!(account.cash.len(), 2);
+    assert_eq!(account.cash_balance(&eur()), Some(&money("-50", eur())));
+    assert_eq!(account.positions.len(), 3);
+    assert_eq!(
+        account.position(&instrument("AAPL")).unwrap().quantity,
+        qty("12")
+    );
+}
+
+#[test]
+fn derived_amounts_use_the_base_currency() {
+    let account = account();
+    assert_eq!(account.excess_liquidity(), Ok(Some(money("9500", usd()))));
+
+    let rates =
+        RateBook::from_rates([ExchangeRate::new(eur(), usd(), decimal("1.1")).unwrap()]).unwrap();
+    assert_eq!(account.total_cash(&rates).unwrap().format(), "1720 USD");
+    assert!(matches!(
+        account.total_cash(&RateBook::default()),
+        Err(PortfolioError::Money(_))
+    ));
+
+    let empty = AccountSnapshot::new("U-2", usd());
+    assert_eq!(empty.excess_liquidity(), Ok(None));
+}
+
+#[test]
+fn validate_rejects_duplicate_keys_and_foreign_currency_totals() {
+    let mut duplicate_cash = account();
+    duplicate_cash.cash.push(money("1", usd()));
+    assert_eq!(
+        duplicate_cash.validate(),
+        Err(PortfolioError::InvalidAccountSnapshot {
+            reason: "cash balances must have one entry per currency",
+        })
+    );
+
+    let mut duplicate_position = account();
+    duplicate_position
+        .positions
+        .push(AccountPosition::new(instrument("AAPL"), qty("1")));
+    assert!(duplicate_position.validate().is_err());
+
+    let mut foreign = account();
+    foreign.buying_power = Some(money("24000", eur()));
+    assert_eq!(
+        foreign.validate(),
+        Err(PortfolioError::CurrencyMismatch {
+            expected: usd(),
+            found: eur(),
+        })
+    );
+}
+
+#[test]
+fn positions_summarize_tax_lot_positions() {
+    let day = |d| NaiveDate::from_ymd_opt(2024, 1, d).unwrap();
+    let trades = [
+        Trade::buy(day(2), qty("10"), Price::new(decimal("100"), usd())),
+        Trade::buy(day(3), qty("10"), Price::new(decimal("110"), usd())),
+    ];
+    let position =
+        Position::from_trades(instrument("AAPL"), usd(), LotMethod::Fifo, &trades).unwrap();
+
+    let summary = AccountPosition::from_position(&position).unwrap();
+    assert_eq!(summary.quantity, qty("20"));
+    assert_eq!(summary.cost_basis, Some(money("2100", usd())));
+    assert_eq!(
+        summary.average_cost,
+        Some(Price::new(decimal("105"), usd()))
+    );
+    assert_eq!(summary.market_value, None);
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct BrokerAccount {
+    broker_account_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct BrokerPosition {
+    broker_conid: Option<u64>,
+}
+
+#[test]
+fn snapshots_round_trip_with_flattened_provider_metadata() {
+    let mut account: GenericAccountSnapshot<BrokerAccount, BrokerPosition> =
+        GenericAccountSnapshot::new("U-1", usd());
+    account.provider.broker_account_type = Some("MARGIN".to_string());
+    account.cash.push(money("1500", usd()));
+    let mut position: GenericAccountPosition<BrokerPosition> =
+        GenericAccountPosition::new(instrument("AAPL"), qty("10"));
+    position.provider.broker_conid = Some(265_598);
+    position.market_value = Some(money("1900", usd()));
+    account.set_position(position);
+
+    let value = serde_json::to_value(&account).unwrap();
+    assert_eq!(value["broker_account_type"], "MARGIN");
+    assert_eq!(value["positions"][0]["broker_conid"], 265_598);
+    assert_eq!(
+        serde_json::from_value::<GenericAccountSnapshot<BrokerAccount, BrokerPosition>>(value)
+            .unwrap(),
+        account
+    );
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "dataframe")]
+use chrono::{TimeZone, Utc};
+use paft_decimal::Decimal;
+use paft_domain::{AssetKind, Instrument};
+use paft_money::{Currency, IsoCurrency, Money, QuantityAmount};
+use paft_portfolio::{AccountPosition, AccountSnapshot};
+use paft_utils::dataframe::{ToDataFrame, ToDataFrameVec};
+
+const fn usd() -> Currency {
+    Currency::Iso(IsoCurrency::USD)
+}
+
+fn money(value: i64) -> Money {
+    Money::new(Decimal::from(value), usd()).unwrap()
+}
+
+fn position(symbol: &str, quantity: i64) -> AccountPosition {
+    let mut position = AccountPosition::new(
+        Instrument::from_symbol(symbol, AssetKind::Equity).unwrap(),
+        QuantityAmount::from_decimal(Decimal::from(quantity)).unwrap(),
+    );
+    position.market_value = Some(money(quantity * 100));
+    position
+}
+
+#[test]
+fn account_snapshot_to_dataframe() {
+    let mut account = AccountSnapshot::new("U-1", usd());
+    account.as_of = Some(Utc.timestamp_opt(1_700_000_000, 0).unwrap());
+    account.cash = vec![money(1500)];
+    account.equity = Some(money(3000));
+    account.buying_power = Some(money(6000));
+    account.positions = vec![position("AAPL", 10), position("MSFT", 5)];
+
+    let df = account.to_dataframe().unwrap();
+    assert_eq!(df.height(), 1);
+    let columns = df.get_column_names();
+    assert!(columns.iter().any(|c| c.as_str() == "account_id"));
+    assert!(columns.iter().any(|c| c.as_str() == "base_currency"));
+}
+
+#[test]
+fn account_positions_to_dataframe() {
+    let positions = [position("AAPL", 10), position("MSFT", 5)];
+    let df = positions.to_dataframe().unwrap();
+    assert_eq!(df.height(), 2);
+    let columns = df.get_column_names();
+    assert!(columns.iter().any(|c| c.as_str() == "instrument"));
+    assert!(columns.iter().any(|c| c.as_str() == "quantity.amount"));
+    assert!(
+        columns
+            .iter()
+            .any(|c| c.as_str() == "market_value.currency")
+    );
+}
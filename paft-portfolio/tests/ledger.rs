@@ -0,0 +1,156 @@
+use std::num::NonZeroU32;
+
+use chrono::NaiveDate;
+use paft_decimal::Decimal;
+use paft_domain::{AssetKind, Instrument};
+use paft_market::Action;
+use paft_money::{Currency, IsoCurrency, Money, Price, QuantityAmount};
+use paft_portfolio::{
+    ActivityKind, GenericLedgerEntry, LedgerEntry, LotMethod, PortfolioError, Position, TradeSide,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+fn decimal(value: &str) -> Decimal {
+    value.parse().unwrap()
+}
+
+const fn usd() -> Currency {
+    Currency::Iso(IsoCurrency::USD)
+}
+
+fn money(value: &str, currency: Currency) -> Money {
+    Money::new(decimal(value), currency).unwrap()
+}
+
+const fn date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 6, 3).unwrap()
+}
+
+fn aapl() -> Instrument {
+    Instrument::from_symbol("AAPL", AssetKind::Equity).unwrap()
+}
+
+fn buy() -> LedgerEntry {
+    let mut entry = LedgerEntry::new(ActivityKind::Buy, date(), money("-1001", usd()));
+    entry.instrument = Some(aapl());
+    entry.quantity = Some(QuantityAmount::from_decimal(decimal("10")).unwrap());
+    entry.price = Some(Price::new(decimal("100"), usd()));
+    entry.fees = Some(money("1", usd()));
+    entry
+}
+
+#[test]
+fn activity_kinds_parse_aliases_and_preserve_unknown_codes() {
+    assert_eq!("purchase".parse(), Ok(ActivityKind::Buy));
+    assert_eq!("Withholding Tax".parse(), Ok(ActivityKind::TaxWithholding));
+    assert_eq!("fx".parse(), Ok(ActivityKind::FxConversion));
+    assert_eq!(
+        ActivityKind::CorporateAction.to_string(),
+        "CORPORATE_ACTION"
+    );
+    assert_eq!(ActivityKind::Sell.trade_side(), Some(TradeSide::Sell));
+    assert_eq!(ActivityKind::Fee.trade_side(), None);
+
+    let journal = ActivityKind::try_from_str("journal").unwrap();
+    assert_eq!(journal, ActivityKind::other("JOURNAL").unwrap());
+    assert_eq!(journal.code(), "JOURNAL");
+    assert_eq!(serde_json::to_value(&journal).unwrap(), json!("JOURNAL"));
+    assert!(ActivityKind::other("deposit").is_err());
+    assert_eq!(
+        ActivityKind::try_from_str("  "),
+        Err(PortfolioError::InvalidEnumValue {
+            enum_name: "ActivityKind",
+            value: "  ".to_string(),
+        })
+    );
+}
+
+#[test]
+fn validate_checks_the_fields_each_kind_needs() {
+    assert_eq!(buy().validate(), Ok(()));
+
+    let mut missing_price = buy();
+    missing_price.price = None;
+    assert_eq!(
+        missing_price.validate(),
+        Err(PortfolioError::InvalidLedgerEntry {
+            reason: "trades require an instrument, quantity, and price",
+        })
+    );
+    let mut zero = buy();
+    zero.quantity = Some(QuantityAmount::from_decimal(decimal("0")).unwrap());
+    assert_eq!(zero.validate(), Err(PortfolioError::ZeroQuantity));
+
+    let dividend = LedgerEntry::new(ActivityKind::Dividend, date(), money("2.4", usd()));
+    assert!(dividend.validate().is_err());
+    let mut split = LedgerEntry::new(ActivityKind::CorporateAction, date(), money("0", usd()));
+    split.instrument = Some(aapl());
+    split.action = Some(Action::Split {
+        date: date(),
+        numerator: NonZeroU32::new(4).unwrap(),
+        denominator: NonZeroU32::new(1).unwrap(),
+    });
+    assert_eq!(split.validate(), Ok(()));
+
+    let mut fx = LedgerEntry::new(ActivityKind::FxConversion, date(), money("-1000", usd()));
+    assert!(fx.validate().is_err());
+    fx.counter_amount = Some(money("920", usd()));
+    assert_eq!(
+        fx.validate().unwrap_err().to_string(),
+        "Invalid ledger entry: FX conversions must exchange two different currencies"
+    );
+    fx.counter_amount = Some(money("920", Currency::Iso(IsoCurrency::EUR)));
+    assert_eq!(fx.validate(), Ok(()));
+
+    let deposit = LedgerEntry::new(ActivityKind::Deposit, date(), money("5000", usd()));
+    assert_eq!(deposit.validate(), Ok(()));
+    assert_eq!(deposit.to_trade(), Ok(None));
+}
+
+#[test]
+fn trade_entries_feed_positions() {
+    let mut sell = buy();
+    sell.kind = ActivityKind::Sell;
+    sell.quantity = Some(QuantityAmount::from_decimal(decimal("4")).unwrap());
+    sell.price = Some(Price::new(decimal("110"), usd()));
+    sell.amount = money("439", usd());
+
+    let trades = [
+        buy().to_trade().unwrap().unwrap(),
+        sell.to_trade().unwrap().unwrap(),
+    ];
+    assert_eq!(trades[1].side, TradeSide::Sell);
+    let position = Position::from_trades(aapl(), usd(), LotMethod::Fifo, &trades).unwrap();
+    // Cost 1001 for 10 units; 4 units relieve 400.4 against 439 proceeds.
+    assert_eq!(position.realized_pnl().unwrap().format(), "38.6 USD");
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct BrokerMeta {
+    broker_activity_code: Option<String>,
+}
+
+#[test]
+fn entries_round_trip_with_flattened_provider_metadata() {
+    let mut entry: GenericLedgerEntry<BrokerMeta> = GenericLedgerEntry::new(
+        ActivityKind::other("STOCK_LOAN_INCOME").unwrap(),
+        date(),
+        money("3.12", usd()),
+    );
+    entry.id = Some("A-1".to_string());
+    entry.provider.broker_activity_code = Some("SLI".to_string());
+
+    let value = serde_json::to_value(&entry).unwrap();
+    assert_eq!(value["kind"], "STOCK_LOAN_INCOME");
+    assert_eq!(value["broker_activity_code"], "SLI");
+    assert_eq!(
+        serde_json::from_value::<GenericLedgerEntry<BrokerMeta>>(value).unwrap(),
+        entry
+    );
+
+    let buy = buy();
+    let restored: LedgerEntry =
+        serde_json::from_value(serde_json::to_value(&buy).unwrap()).unwrap();
+    assert_eq!(restored, buy);
+}
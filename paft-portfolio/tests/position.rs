@@ -0,0 +1,239 @@
+use std::num::NonZeroU32;
+
+use chrono::NaiveDate;
+use paft_decimal::Decimal;
+use paft_domain::{AssetKind, Instrument};
+use paft_market::{Action, Quote};
+use paft_money::{
+    Currency, IsoCurrency, MonetaryAmount, Money, Price, PriceAmount, QuantityAmount,
+};
+use paft_portfolio::{LotMethod, PortfolioError, Position, Trade};
+
+fn decimal(value: &str) -> Decimal {
+    value.parse().unwrap()
+}
+
+fn qty(value: &str) -> QuantityAmount {
+    QuantityAmount::from_decimal(decimal(value)).unwrap()
+}
+
+const fn usd() -> Currency {
+    Currency::Iso(IsoCurrency::USD)
+}
+
+fn price(value: &str) -> Price {
+    Price::new(decimal(value), usd())
+}
+
+fn amount(value: &str) -> MonetaryAmount {
+    MonetaryAmount::new(decimal(value), usd())
+}
+
+const fn day(d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, 1, d).unwrap()
+}
+
+fn aapl() -> Instrument {
+    Instrument::from_symbol("AAPL", AssetKind::Equity).unwrap()
+}
+
+/// Three buys at rising then falling prices: 10 @ 100, 10 @ 120, 10 @ 110.
+fn buys() -> Vec<Trade> {
+    vec![
+        Trade::buy(day(2), qty("10"), price("100")),
+        Trade::buy(day(3), qty("10"), price("120")),
+        Trade::buy(day(4), qty("10"), price("110")),
+    ]
+}
+
+fn gain_after_selling_15(method: LotMethod) -> (MonetaryAmount, MonetaryAmount) {
+    let mut trades = buys();
+    trades.push(Trade::sell(day(5), qty("15"), price("130")));
+    let position = Position::from_trades(aapl(), usd(), method, &trades).unwrap();
+    assert_eq!(position.quantity(), &qty("15"));
+    (
+        position.realized_pnl().unwrap(),
+        position.cost_basis().clone(),
+    )
+}
+
+#[test]
+fn lot_methods_relieve_lots_in_their_own_order() {
+    // Sale proceeds are 15 × 130 = 1950.
+    assert_eq!(
+        gain_after_selling_15(LotMethod::Fifo),
+        (amount("350"), amount("1700"))
+    );
+    assert_eq!(
+        gain_after_selling_15(LotMethod::Lifo),
+        (amount("250"), amount("1600"))
+    );
+    assert_eq!(
+        gain_after_selling_15(LotMethod::HighestCost),
+        (amount("200"), amount("1550"))
+    );
+    assert_eq!(
+        gain_after_selling_15(LotMethod::AverageCost),
+        (amount("300"), amount("1650"))
+    );
+
+    let mut trades = buys();
+    trades.push(Trade::sell(day(5), qty("15"), price("130")));
+    let fifo = Position::from_trades(aapl(), usd(), LotMethod::Fifo, &trades).unwrap();
+    let realized = fifo.realized();
+    assert_eq!(realized.len(), 2);
+    assert_eq!((realized[0].opened, realized[1].opened), (day(2), day(3)));
+    assert_eq!(realized[1].quantity, qty("5"));
+    assert_eq!(realized[1].cost, amount("600"));
+    assert_eq!(realized[1].proceeds, amount("650"));
+    assert_eq!(fifo.lots().len(), 2);
+}
+
+#[test]
+fn fees_raise_cost_and_reduce_proceeds() {
+    let fee = Money::new(decimal("5"), usd()).unwrap();
+    let trades = [
+        Trade::buy(day(2), qty("10"), price("100")).with_fees(fee.clone()),
+        Trade::sell(day(9), qty("4"), price("110")).with_fees(fee),
+    ];
+    let position = Position::from_trades(aapl(), usd(), LotMethod::Fifo, &trades).unwrap();
+
+    // Cost 1005 for 10 units; 4 units relieve 402 against 440 − 5 proceeds.
+    assert_eq!(position.realized_pnl().unwrap(), amount("33"));
+    assert_eq!(position.cost_basis(), &amount("603"));
+    assert_eq!(position.average_cost().unwrap(), Some(price("100.5")));
+
+    let mut quote = Quote::new(aapl(), usd());
+    quote.price = Some(PriceAmount::new(decimal("120")));
+    assert_eq!(position.market_value(&quote).unwrap(), amount("720"));
+    assert_eq!(position.unrealized_pnl(&quote).unwrap(), amount("117"));
+}
+
+#[test]
+fn invalid_updates_leave_the_position_unchanged() {
+    let mut position = Position::from_trades(aapl(), usd(), LotMethod::Fifo, &buys()).unwrap();
+    let before = position.clone();
+
+    assert_eq!(
+        position.apply_trade(&Trade::sell(day(9), qty("31"), price("100"))),
+        Err(PortfolioError::InsufficientQuantity {
+            held: decimal("30"),
+            requested: decimal("31"),
+        })
+    );
+    assert_eq!(
+        position.apply_trade(&Trade::buy(day(9), qty("0"), price("100"))),
+        Err(PortfolioError::ZeroQuantity)
+    );
+    assert_eq!(
+        position.apply_trade(&Trade::buy(
+            day(9),
+            qty("1"),
+            Price::new(decimal("90"), Currency::Iso(IsoCurrency::EUR)),
+        )),
+        Err(PortfolioError::CurrencyMismatch {
+            expected: usd(),
+            found: Currency::Iso(IsoCurrency::EUR),
+        })
+    );
+    assert_eq!(position, before);
+
+    let mut quote = Quote::new(aapl(), usd());
+    assert_eq!(
+        position.market_value(&quote),
+        Err(PortfolioError::MissingMarketPrice)
+    );
+    quote.instrument = Instrument::from_symbol("MSFT", AssetKind::Equity).unwrap();
+    quote.price = Some(PriceAmount::new(decimal("400")));
+    assert!(matches!(
+        position.unrealized_pnl(&quote),
+        Err(PortfolioError::InstrumentMismatch { .. })
+    ));
+
+    // Selling everything closes the position.
+    position
+        .apply_trade(&Trade::sell(day(9), qty("30"), price("100")))
+        .unwrap();
+    assert!(position.is_flat());
+    assert_eq!(position.average_cost().unwrap(), None);
+    assert_eq!(position.realized_pnl().unwrap(), amount("-300"));
+}
+
+#[test]
+fn splits_and_dividends_apply_to_lots_held_before_the_action() {
+    let mut position = Position::from_trades(
+        aapl(),
+        usd(),
+        LotMethod::Fifo,
+        &[
+            Trade::buy(day(2), qty("10"), price("100")),
+            Trade::buy(day(10), qty("5"), price("60")),
+        ],
+    )
+    .unwrap();
+
+    position
+        .apply_action(&Action::Split {
+            date: day(8),
+            numerator: NonZeroU32::new(2).unwrap(),
+            denominator: NonZeroU32::new(1).unwrap(),
+        })
+        .unwrap();
+    assert_eq!(position.lots()[0].quantity, qty("20"));
+    assert_eq!(position.lots()[0].cost, amount("1000"));
+    assert_eq!(position.lots()[1].quantity, qty("5"));
+    assert_eq!(position.quantity(), &qty("25"));
+
+    position
+        .apply_action(&Action::Dividend {
+            date: day(10),
+            amount: price("0.25"),
+        })
+        .unwrap();
+    position
+        .apply_action(&Action::CapitalGain {
+            date: day(20),
+            gain: price("0.1"),
+        })
+        .unwrap();
+    // 20 eligible shares × 0.25, then 25 × 0.10.
+    assert_eq!(position.income(), &amount("7.5"));
+
+    assert_eq!(
+        position.apply_action(&Action::Dividend {
+            date: day(20),
+            amount: Price::new(decimal("1"), Currency::Iso(IsoCurrency::EUR)),
+        }),
+        Err(PortfolioError::CurrencyMismatch {
+            expected: usd(),
+            found: Currency::Iso(IsoCurrency::EUR),
+        })
+    );
+}
+
+#[test]
+fn positions_round_trip_through_validated_serde() {
+    let mut trades = buys();
+    trades.push(Trade::sell(day(5), qty("15"), price("130")));
+    let position = Position::from_trades(aapl(), usd(), LotMethod::HighestCost, &trades).unwrap();
+
+    let value = serde_json::to_value(&position).unwrap();
+    assert_eq!(value["method"], "HIGHEST_COST");
+    assert!(value.get("quantity").is_none());
+    let restored: Position = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(restored, position);
+    assert_eq!(restored.quantity(), &qty("15"));
+
+    let mut empty_lot = value;
+    empty_lot["lots"][0]["quantity"] = "0".into();
+    assert!(serde_json::from_value::<Position>(empty_lot).is_err());
+
+    assert_eq!("hifo".parse(), Ok(LotMethod::HighestCost));
+    assert_eq!(
+        "specific".parse::<LotMethod>(),
+        Err(PortfolioError::InvalidEnumValue {
+            enum_name: "LotMethod",
+            value: "specific".to_string(),
+        })
+    );
+}
@@ -20,6 +20,11 @@ pub enum Error {
     #[error(transparent)]
     Domain(#[from] paft_domain::DomainError),
 
+    /// Error originating from field-level merging of multi-source records.
+    #[cfg(feature = "domain")]
+    #[error(transparent)]
+    Merge(#[from] paft_domain::MergeError),
+
     /// Error originating from `paft-market`.
     #[cfg(feature = "market")]
     #[error(transparent)]
@@ -30,6 +35,11 @@ pub enum Error {
     #[error(transparent)]
     HistoryValidation(#[from] paft_market::HistoryValidationError),
 
+    /// Error originating from `paft-market` cross-provider reconciliation.
+    #[cfg(feature = "market")]
+    #[error(transparent)]
+    Reconciliation(#[from] paft_market::ReconciliationError),
+
     /// Error originating from `paft-fundamentals`.
     #[cfg(feature = "fundamentals")]
     #[error(transparent)]
@@ -40,6 +50,16 @@ pub enum Error {
     #[error(transparent)]
     Prediction(#[from] paft_prediction::PredictionError),
 
+    /// Error originating from `paft-portfolio`.
+    #[cfg(feature = "portfolio")]
+    #[error(transparent)]
+    Portfolio(#[from] paft_portfolio::PortfolioError),
+
+    /// Error originating from `paft-orders`.
+    #[cfg(feature = "orders")]
+    #[error(transparent)]
+    Orders(#[from] paft_orders::OrderError),
+
     /// Error originating from `paft-money` operations.
     #[error(transparent)]
     Money(#[from] paft_money::MoneyError),
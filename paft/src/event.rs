@@ -0,0 +1,318 @@
+//! Unified envelope for live market data updates.
+//!
+//! [`MarketEvent`] tags the streaming payloads defined across the workspace
+//! (quote, candle, and option updates, order book snapshots, prediction
+//! trades) so a single bus can carry them. [`MarketEventEnvelope`] adds the
+//! transport metadata every message needs: a sequence number, the source
+//! that produced it, and the local receive timestamp. [`SequenceGapDetector`]
+//! tracks sequence numbers per source and instrument to surface dropped or
+//! replayed messages.
+//!
+//! # Wire format
+//! The envelope is a plain object with the event adjacently tagged under
+//! `event`, so flattened provider metadata inside a payload can never collide
+//! with the tag:
+//!
+//! ```json
+//! {
+//!   "sequence": 42,
+//!   "source": "polygon",
+//!   "received_at": 1700000000123,
+//!   "event": { "kind": "quote_update", "data": { "instrument": "...", "ts": 1700000000000 } }
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "market")]
+use paft_market::{CandleUpdate, OptionContractKey, OptionUpdate, OrderBook, QuoteUpdate};
+#[cfg(feature = "prediction")]
+use paft_prediction::PredictionTrade;
+
+/// A live market data update carried on an event stream.
+///
+/// Variants are available when the facade feature providing their payload is
+/// enabled (`market` or `prediction`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "data", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum MarketEvent {
+    /// Streaming quote update.
+    #[cfg(feature = "market")]
+    QuoteUpdate(QuoteUpdate),
+    /// Streaming candle update.
+    #[cfg(feature = "market")]
+    CandleUpdate(CandleUpdate),
+    /// Streaming option contract update.
+    #[cfg(feature = "market")]
+    OptionUpdate(OptionUpdate),
+    /// Order book snapshot.
+    #[cfg(feature = "market")]
+    OrderBook(OrderBook),
+    /// Prediction market trade print.
+    #[cfg(feature = "prediction")]
+    PredictionTrade(PredictionTrade),
+}
+
+impl MarketEvent {
+    /// Canonical tag of this event, as used on the wire.
+    #[must_use]
+    pub const fn kind(&self) -> &'static str {
+        match self {
+            #[cfg(feature = "market")]
+            Self::QuoteUpdate(_) => "quote_update",
+            #[cfg(feature = "market")]
+            Self::CandleUpdate(_) => "candle_update",
+            #[cfg(feature = "market")]
+            Self::OptionUpdate(_) => "option_update",
+            #[cfg(feature = "market")]
+            Self::OrderBook(_) => "order_book",
+            #[cfg(feature = "prediction")]
+            Self::PredictionTrade(_) => "prediction_trade",
+        }
+    }
+
+    /// Identity key of the instrument this event describes.
+    ///
+    /// Uses the payload instrument's `unique_key`. Option updates use the
+    /// contract instrument when the provider supplied one and otherwise a key
+    /// composed from the underlying, expiration, side, and strike.
+    #[must_use]
+    pub fn instrument_key(&self) -> String {
+        match self {
+            #[cfg(feature = "market")]
+            Self::QuoteUpdate(update) => update.instrument.unique_key(),
+            #[cfg(feature = "market")]
+            Self::CandleUpdate(update) => update.instrument.unique_key(),
+            #[cfg(feature = "market")]
+            Self::OptionUpdate(update) => option_contract_key(&update.key),
+            #[cfg(feature = "market")]
+            Self::OrderBook(book) => book.instrument.unique_key(),
+            #[cfg(feature = "prediction")]
+            Self::PredictionTrade(trade) => trade.instrument.unique_key(),
+        }
+    }
+
+    /// Event timestamp reported by the provider, when the payload has one.
+    ///
+    /// Candle updates report the candle's open time.
+    #[must_use]
+    pub const fn event_ts(&self) -> Option<DateTime<Utc>> {
+        match self {
+            #[cfg(feature = "market")]
+            Self::QuoteUpdate(update) => Some(update.ts),
+            #[cfg(feature = "market")]
+            Self::CandleUpdate(update) => Some(update.candle.ts),
+            #[cfg(feature = "market")]
+            Self::OptionUpdate(update) => Some(update.ts),
+            #[cfg(feature = "market")]
+            Self::OrderBook(book) => book.as_of,
+            #[cfg(feature = "prediction")]
+            Self::PredictionTrade(trade) => Some(trade.ts),
+        }
+    }
+}
+
+#[cfg(feature = "market")]
+fn option_contract_key(key: &OptionContractKey) -> String {
+    if let Some(contract) = &key.contract_instrument {
+        return contract.unique_key();
+    }
+    format!(
+        "OPTION|{}|{}|{}|{} {}",
+        key.underlying.unique_key(),
+        key.expiration_date,
+        key.side,
+        paft_decimal::to_canonical_string(&key.strike.amount()),
+        key.strike.currency(),
+    )
+}
+
+#[cfg(feature = "market")]
+impl From<QuoteUpdate> for MarketEvent {
+    fn from(update: QuoteUpdate) -> Self {
+        Self::QuoteUpdate(update)
+    }
+}
+
+#[cfg(feature = "market")]
+impl From<CandleUpdate> for MarketEvent {
+    fn from(update: CandleUpdate) -> Self {
+        Self::CandleUpdate(update)
+    }
+}
+
+#[cfg(feature = "market")]
+impl From<OptionUpdate> for MarketEvent {
+    fn from(update: OptionUpdate) -> Self {
+        Self::OptionUpdate(update)
+    }
+}
+
+#[cfg(feature = "market")]
+impl From<OrderBook> for MarketEvent {
+    fn from(book: OrderBook) -> Self {
+        Self::OrderBook(book)
+    }
+}
+
+#[cfg(feature = "prediction")]
+impl From<PredictionTrade> for MarketEvent {
+    fn from(trade: PredictionTrade) -> Self {
+        Self::PredictionTrade(trade)
+    }
+}
+
+/// Transport envelope around a [`MarketEvent`].
+///
+/// Generic over the event payload `E` so streams carrying provider-specific
+/// event enums can reuse the envelope and its serde shape. Use the default
+/// `E = MarketEvent` for the standard event set.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MarketEventEnvelope<E = MarketEvent> {
+    /// Sequence number assigned by `source`, increasing by one per message
+    /// for each instrument.
+    pub sequence: u64,
+    /// Identifier of the feed or provider that produced the event.
+    pub source: String,
+    /// Local receive timestamp as Unix milliseconds.
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub received_at: DateTime<Utc>,
+    /// The wrapped event.
+    pub event: E,
+}
+
+impl<E> MarketEventEnvelope<E> {
+    /// Wrap `event` with its transport metadata.
+    #[must_use]
+    pub fn new(
+        sequence: u64,
+        source: impl Into<String>,
+        received_at: DateTime<Utc>,
+        event: impl Into<E>,
+    ) -> Self {
+        Self {
+            sequence,
+            source: source.into(),
+            received_at,
+            event: event.into(),
+        }
+    }
+}
+
+/// Outcome of feeding one sequence number to a [`SequenceGapDetector`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SequenceCheck {
+    /// First sequence number seen for the stream.
+    First,
+    /// The sequence number directly follows the previous one.
+    Contiguous,
+    /// One or more sequence numbers were skipped.
+    Gap {
+        /// The sequence number that was expected next.
+        expected: u64,
+        /// The sequence number that arrived.
+        received: u64,
+    },
+    /// The sequence number was already passed: a duplicate or an
+    /// out-of-order replay. The tracked position is left unchanged.
+    Stale {
+        /// Highest sequence number seen so far.
+        last: u64,
+        /// The sequence number that arrived.
+        received: u64,
+    },
+}
+
+impl SequenceCheck {
+    /// Number of messages skipped; zero unless this is a [`Self::Gap`].
+    #[must_use]
+    pub const fn missing(&self) -> u64 {
+        match self {
+            Self::Gap { expected, received } => *received - *expected,
+            Self::First | Self::Contiguous | Self::Stale { .. } => 0,
+        }
+    }
+
+    /// Whether the message continues the stream (`First` or `Contiguous`).
+    #[must_use]
+    pub const fn is_in_order(&self) -> bool {
+        matches!(self, Self::First | Self::Contiguous)
+    }
+}
+
+/// Detects sequence gaps per source and instrument.
+///
+/// Sequence numbers from different sources are unrelated, so streams are
+/// keyed by `(source, instrument key)`. A gap advances the tracked position
+/// to the received sequence number, so each gap is reported once.
+#[derive(Debug, Clone, Default)]
+pub struct SequenceGapDetector {
+    last: HashMap<(String, String), u64>,
+}
+
+impl SequenceGapDetector {
+    /// Build a detector with no tracked streams.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record `sequence` for the stream of `instrument_key` from `source`.
+    pub fn observe(&mut self, source: &str, instrument_key: &str, sequence: u64) -> SequenceCheck {
+        let key = (source.to_string(), instrument_key.to_string());
+        let Some(last) = self.last.get_mut(&key) else {
+            self.last.insert(key, sequence);
+            return SequenceCheck::First;
+        };
+        match last.checked_add(1) {
+            Some(expected) if sequence == expected => {
+                *last = sequence;
+                SequenceCheck::Contiguous
+            }
+            Some(expected) if sequence > expected => {
+                *last = sequence;
+                SequenceCheck::Gap {
+                    expected,
+                    received: sequence,
+                }
+            }
+            _ => SequenceCheck::Stale {
+                last: *last,
+                received: sequence,
+            },
+        }
+    }
+
+    /// Record the sequence number of `envelope`, keyed by its source and
+    /// [`MarketEvent::instrument_key`].
+    pub fn observe_event(&mut self, envelope: &MarketEventEnvelope) -> SequenceCheck {
+        self.observe(
+            &envelope.source,
+            &envelope.event.instrument_key(),
+            envelope.sequence,
+        )
+    }
+
+    /// Highest sequence number seen for the stream, if any.
+    #[must_use]
+    pub fn last_sequence(&self, source: &str, instrument_key: &str) -> Option<u64> {
+        self.last
+            .get(&(source.to_string(), instrument_key.to_string()))
+            .copied()
+    }
+
+    /// Forget a stream, e.g. after a resubscription resets its sequence.
+    pub fn reset(&mut self, source: &str, instrument_key: &str) {
+        self.last
+            .remove(&(source.to_string(), instrument_key.to_string()));
+    }
+
+    /// Forget every tracked stream.
+    pub fn clear(&mut self) {
+        self.last.clear();
+    }
+}
@@ -6,10 +6,14 @@
 //! Features
 //! - `domain`, `market`, `fundamentals`, `aggregates`: opt into the areas you need
 //! - `prediction`: prediction market identity, metadata, quotes, books, and trades
+//! - `portfolio`: positions, tax-lot accounting, account activity ledgers, and
+//!   account snapshots
+//! - `orders`: validated order requests, fills, and order status transitions
 //! - `bigdecimal`: change the money backend from `rust_decimal` to `bigdecimal`
 //! - `dataframe`: enable `DataFrame` export via Polars helpers
 //! - `panicking-money-ops`: opt‑in operator overloading for `Money` that panics on invalid input
 //! - `money-formatting`: locale‑aware money formatting and parsing
+//! - `metadata-json`, `metadata-toml`: load and export currency metadata files
 //! - `tracing`: lightweight instrumentation; zero‑cost when disabled
 //!
 //! # Quickstart
@@ -44,6 +48,8 @@
 #![warn(missing_docs)]
 
 pub mod error;
+#[cfg(any(feature = "market", feature = "prediction"))]
+pub mod event;
 pub use error::{Error, Result};
 pub use paft_utils::MAX_CANONICAL_TOKEN_LEN;
 
@@ -57,6 +63,9 @@ pub mod core {
 /// Namespaced access to `paft-domain` (feature-gated).
 #[cfg(feature = "domain")]
 pub mod domain {
+    pub use paft_domain::merge::{
+        self, FieldMerger, MergeError, MergeOrder, MergePolicy, MergeSource, Merged,
+    };
     pub use paft_domain::{
         AssetKind, CalendarPeriod, Canonical, CanonicalError, DomainError, Exchange, Figi, Horizon,
         Instrument, Isin, MAX_CANONICAL_TOKEN_LEN, MarketState, OtherAssetKind, OtherExchange,
@@ -71,7 +80,12 @@ pub mod domain {
 #[cfg(feature = "market")]
 pub mod market {
     pub use paft_market::error::{self, MarketError};
-    pub use paft_market::market::{action, news, options, orderbook, quote};
+    pub use paft_market::market::{action, news, options, orderbook, quote, tick_size};
+    pub use paft_market::reconciliation::{
+        self, Discrepancy, DiscrepancyKind, ReconciledField, ReconciliationConfig,
+        ReconciliationError, ReconciliationReport, ReconciliationSide, Tolerance,
+        reconcile_history, reconcile_quotes,
+    };
     pub use paft_market::requests;
     pub use paft_market::responses;
     pub use paft_market::{
@@ -85,21 +99,33 @@ pub mod market {
         HistoryValidationError, Interval, NewsArticle, NewsRequest, NewsTab, Ohlc, OhlcPriceBasis,
         OptionChain, OptionChainRequest, OptionContract, OptionContractKey,
         OptionExpirationsRequest, OptionExpirationsResponse, OptionGreeks, OptionSide,
-        OptionUpdate, OrderBook, PriceBasis, Quote, QuoteUpdate, Range, SearchRequest,
-        SearchRequestBuilder, SearchResponse, SearchResult, TimeSpec,
+        OptionUpdate, OrderBook, PriceBasis, Quote, QuoteFreshnessPolicy, QuoteUpdate, Range,
+        SearchRequest, SearchRequestBuilder, SearchResponse, SearchResult, TickBand, TickSizeTable,
+        TickSizeTables, TimeSpec,
     };
 }
 
 /// Namespaced access to `paft-money` types.
 pub mod money {
+    #[cfg(any(feature = "metadata-json", feature = "metadata-toml"))]
+    pub use paft_money::load_currency_metadata_file;
+    pub use paft_money::serde;
+    pub use paft_money::{
+        AllocationStrategy, AsOfMode, Compounding, ContractSpec, Currency, CurrencyMetadata,
+        CurrencyMetadataEntry, CurrencyMetadataFile, CurrencyRegistry, DayCount, ExchangeRate,
+        HistoricCurrency, HistoricalRates, IsoCurrency, Locale, MAX_DECIMAL_PRECISION,
+        MAX_MINOR_UNIT_DECIMALS, MetadataLoadError, MinorUnitError, MonetaryAmount, Money,
+        MoneyBag, MoneyError, MoneyParseError, OtherCurrency, Price, PriceAmount, Quantity,
+        QuantityAmount, QuantityUnit, RateBook, RateHistory, RateLeg, RateObservation,
+        RateResolution, RateSource, ResolvedRate, clear_currency_metadata, currency_metadata,
+        export_currency_metadata, load_currency_metadata, override_currency_metadata,
+        set_cash_increment, set_currency_metadata, set_historic_currency,
+        try_normalize_currency_code,
+    };
     #[cfg(feature = "money-formatting")]
-    pub use paft_money::LocalizedMoney;
     pub use paft_money::{
-        Currency, CurrencyMetadata, ExchangeRate, IsoCurrency, Locale, MAX_DECIMAL_PRECISION,
-        MAX_MINOR_UNIT_DECIMALS, MinorUnitError, MonetaryAmount, Money, MoneyError,
-        MoneyParseError, OtherCurrency, Price, PriceAmount, QuantityAmount,
-        clear_currency_metadata, currency_metadata, override_currency_metadata,
-        set_currency_metadata, try_normalize_currency_code,
+        CompactFormat, CompactStyle, CompactSuffix, CustomLocale, LocalFormat, LocalizedMoney,
+        MoneyParser, MoneyPattern, register_locale,
     };
     pub use paft_utils::MAX_CANONICAL_TOKEN_LEN;
 }
@@ -129,7 +155,10 @@ pub mod fundamentals {
         RecommendationGrade, RecommendationRow, RecommendationSummary, RevenueEstimate,
         RevisionPoint, ShareCount, TransactionType, TrendPoint, UpgradeDowngradeRow,
     };
-    pub use paft_fundamentals::{analysis, esg, holders, profile, statements, statistics};
+    pub use paft_fundamentals::{AsOfRow, Published, as_of_join};
+    pub use paft_fundamentals::{
+        analysis, esg, holders, point_in_time, profile, statements, statistics,
+    };
 }
 
 /// Namespaced access to `paft-aggregates` (feature-gated).
@@ -144,5 +173,26 @@ pub mod prediction {
     pub use paft_prediction::*;
 }
 
+/// Namespaced access to `paft-portfolio` (feature-gated).
+#[cfg(feature = "portfolio")]
+pub mod portfolio {
+    pub use paft_portfolio::{
+        AccountPosition, AccountSnapshot, ActivityKind, GenericAccountPosition,
+        GenericAccountSnapshot, GenericLedgerEntry, LedgerEntry, LotMethod, OtherActivityKind,
+        PortfolioError, Position, RealizedLot, TaxLot, Trade, TradeSide,
+    };
+    pub use paft_portfolio::{account, error, ledger, position};
+}
+
+/// Namespaced access to `paft-orders` (feature-gated).
+#[cfg(feature = "orders")]
+pub mod orders {
+    pub use paft_orders::{
+        Execution, Fill, GenericExecution, GenericFill, OrderError, OrderRequest,
+        OrderRequestBuilder, OrderSide, OrderStatus, OrderType, OtherOrderStatus, TimeInForce,
+    };
+    pub use paft_orders::{error, execution, order, status};
+}
+
 /// Frequently used types for convenient imports.
 pub mod prelude;
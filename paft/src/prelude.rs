@@ -69,14 +69,28 @@ pub use crate::market::{
     HistoryRequestBuilder, HistoryResponse, HistoryValidationError, Interval, NewsArticle,
     NewsRequest, NewsTab, Ohlc, OhlcPriceBasis, OptionChain, OptionChainRequest, OptionContract,
     OptionContractKey, OptionExpirationsRequest, OptionExpirationsResponse, OptionGreeks,
-    OptionSide, OptionUpdate, OrderBook, PriceBasis, Quote, QuoteUpdate, Range, SearchRequest,
-    SearchRequestBuilder, SearchResponse, SearchResult, TimeSpec,
+    OptionSide, OptionUpdate, OrderBook, PriceBasis, Quote, QuoteFreshnessPolicy, QuoteUpdate,
+    Range, SearchRequest, SearchRequestBuilder, SearchResponse, SearchResult, TimeSpec,
 };
 
 // Re-export aggregates snapshot types
 #[cfg(feature = "aggregates")]
 pub use crate::aggregates::{GenericSnapshot, Snapshot};
 
+// Re-export portfolio position, ledger, and account types
+#[cfg(feature = "portfolio")]
+pub use crate::portfolio::{
+    AccountPosition, AccountSnapshot, ActivityKind, GenericAccountPosition, GenericAccountSnapshot,
+    GenericLedgerEntry, LedgerEntry, LotMethod, Position, TaxLot, Trade, TradeSide,
+};
+
+// Re-export order request and execution types
+#[cfg(feature = "orders")]
+pub use crate::orders::{
+    Execution, Fill, GenericExecution, GenericFill, OrderRequest, OrderRequestBuilder, OrderSide,
+    OrderStatus, OrderType, TimeInForce,
+};
+
 #[cfg(feature = "prediction")]
 pub use crate::prediction::{
     BinaryMarket, BinaryMarketKey, BinaryOrderBook, BinaryOrderDirection, BinaryOutcome,
@@ -182,6 +182,10 @@ fn generic_market_exports_are_available_from_facade_and_prelude() {
     assert_export::<paft::market::GenericOrderBook>();
     assert_export::<paft::market::GenericQuote>();
     assert_export::<paft::market::GenericQuoteUpdate>();
+    assert_export::<paft::market::QuoteFreshnessPolicy>();
+    assert_export::<paft::market::ReconciliationReport>();
+    assert_export::<paft::market::Discrepancy>();
+    assert_export::<paft::market::reconciliation::DiscrepancyKind>();
     assert_export::<paft::market::GenericSearchResponse>();
     assert_export::<paft::market::GenericSearchResult>();
     assert_export::<paft::market::AdjustmentAnchor>();
@@ -208,6 +212,7 @@ fn generic_market_exports_are_available_from_facade_and_prelude() {
     assert_export::<paft::prelude::GenericOrderBook>();
     assert_export::<paft::prelude::GenericQuote>();
     assert_export::<paft::prelude::GenericQuoteUpdate>();
+    assert_export::<paft::prelude::QuoteFreshnessPolicy>();
     assert_export::<paft::prelude::GenericSearchResponse>();
     assert_export::<paft::prelude::GenericSearchResult>();
     assert_export::<paft::prelude::AdjustmentAnchor>();
@@ -268,6 +273,65 @@ fn history_validation_errors_convert_into_facade_result() {
     ));
 }
 
+#[cfg(feature = "market")]
+#[test]
+fn reconciliation_errors_convert_into_facade_result() {
+    use paft::market::{
+        Quote, ReconciliationConfig, ReconciliationError, Tolerance, reconcile_quotes,
+    };
+    use paft::prelude::{AssetKind, Currency, Error, Instrument, IsoCurrency, Result};
+
+    fn reconcile(left: &Quote, right: &Quote) -> Result<usize> {
+        let config = ReconciliationConfig::new(Tolerance::exact(), Tolerance::exact());
+        Ok(reconcile_quotes(left, right, &config)?.discrepancies.len())
+    }
+
+    let quote = |symbol| {
+        Quote::new(
+            Instrument::from_symbol(symbol, AssetKind::Equity).unwrap(),
+            Currency::Iso(IsoCurrency::USD),
+        )
+    };
+
+    assert_eq!(reconcile(&quote("AAPL"), &quote("AAPL")).unwrap(), 0);
+    assert!(matches!(
+        reconcile(&quote("AAPL"), &quote("MSFT")),
+        Err(Error::Reconciliation(
+            ReconciliationError::InstrumentMismatch { .. }
+        ))
+    ));
+}
+
+#[cfg(feature = "market")]
+#[test]
+fn merge_errors_convert_into_facade_result() {
+    use paft::domain::{MergeError, MergePolicy, MergeSource, Merged};
+    use paft::market::Quote;
+    use paft::prelude::{AssetKind, Currency, Error, Instrument, IsoCurrency, Result};
+
+    fn merge(sources: &[MergeSource<'_, Quote>]) -> Result<Merged<Quote>> {
+        Ok(Quote::merge(sources, &MergePolicy::precedence())?)
+    }
+
+    fn assert_export<T>() {}
+
+    assert_export::<paft::domain::FieldMerger<'_, Quote>>();
+    assert_export::<paft::domain::merge::MergeOrder>();
+
+    let usd = Quote::new(
+        Instrument::from_symbol("AAPL", AssetKind::Equity).unwrap(),
+        Currency::Iso(IsoCurrency::USD),
+    );
+    let mut eur = usd.clone();
+    eur.currency = Currency::Iso(IsoCurrency::EUR);
+
+    assert!(merge(&[MergeSource::new("a", &usd)]).is_ok());
+    assert!(matches!(
+        merge(&[MergeSource::new("a", &usd), MergeSource::new("b", &eur)]),
+        Err(Error::Merge(MergeError::CurrencyMismatch { .. }))
+    ));
+}
+
 #[cfg(feature = "aggregates")]
 #[test]
 fn generic_aggregate_exports_are_available_from_facade_and_prelude() {
@@ -277,6 +341,100 @@ fn generic_aggregate_exports_are_available_from_facade_and_prelude() {
     assert_export::<paft::prelude::GenericSnapshot>();
 }
 
+#[cfg(feature = "portfolio")]
+#[test]
+fn portfolio_positions_are_available_from_facade_and_prelude() {
+    use paft::money::{Currency, IsoCurrency, Price, QuantityAmount};
+    use paft::prelude::{AssetKind, Instrument, LotMethod, Position, Trade};
+
+    let date = chrono::NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+    let usd = Currency::Iso(IsoCurrency::USD);
+    let trade = Trade::buy(
+        date,
+        QuantityAmount::from_decimal(4.into()).unwrap(),
+        Price::new(25.into(), usd.clone()),
+    );
+    let position = Position::from_trades(
+        Instrument::from_symbol("AAPL", AssetKind::Equity).unwrap(),
+        usd,
+        LotMethod::Fifo,
+        [&trade],
+    )
+    .unwrap();
+    assert_eq!(position.cost_basis().format(), "100 USD");
+
+    let entry = paft::prelude::LedgerEntry::new(
+        paft::prelude::ActivityKind::Deposit,
+        date,
+        paft::money::Money::new(500.into(), Currency::Iso(IsoCurrency::USD)).unwrap(),
+    );
+    assert_eq!(entry.validate(), Ok(()));
+    assert_eq!(
+        "journal".parse::<paft::portfolio::ActivityKind>().unwrap(),
+        paft::portfolio::ActivityKind::other("JOURNAL").unwrap()
+    );
+
+    let err: paft::Error = paft::portfolio::PortfolioError::ZeroQuantity.into();
+    assert!(matches!(err, paft::Error::Portfolio(_)));
+}
+
+#[cfg(feature = "portfolio")]
+#[test]
+fn account_snapshots_are_available_from_facade_and_prelude() {
+    use paft::money::{Currency, IsoCurrency, Money, QuantityAmount};
+    use paft::prelude::{AccountPosition, AccountSnapshot, AssetKind, Instrument};
+
+    let usd = Currency::Iso(IsoCurrency::USD);
+    let mut account = AccountSnapshot::new("U-1", usd.clone());
+    account.cash.push(Money::new(1500.into(), usd).unwrap());
+    account.set_position(AccountPosition::new(
+        Instrument::from_symbol("AAPL", AssetKind::Equity).unwrap(),
+        QuantityAmount::from_decimal(4.into()).unwrap(),
+    ));
+    assert_eq!(account.validate(), Ok(()));
+
+    #[cfg(feature = "dataframe")]
+    {
+        use paft::dataframe::{ToDataFrame, ToDataFrameVec};
+        assert_eq!(account.to_dataframe().unwrap().height(), 1);
+        assert_eq!(account.positions.to_dataframe().unwrap().height(), 1);
+    }
+}
+
+#[cfg(feature = "orders")]
+#[test]
+fn order_exports_are_available_from_facade_and_prelude() {
+    use paft::money::{Currency, IsoCurrency, Price, QuantityAmount};
+    use paft::prelude::{
+        AssetKind, Execution, Fill, Instrument, OrderRequest, OrderSide, OrderStatus,
+    };
+
+    let aapl = Instrument::from_symbol("AAPL", AssetKind::Equity).unwrap();
+    let quantity = QuantityAmount::from_decimal(4.into()).unwrap();
+    let price = Price::new(25.into(), Currency::Iso(IsoCurrency::USD));
+    let request = OrderRequest::limit(
+        aapl.clone(),
+        OrderSide::Buy,
+        quantity.clone(),
+        price.clone(),
+    )
+    .unwrap();
+
+    let mut execution = Execution::from_request("O-1", &request);
+    let fill = Fill::new(
+        aapl,
+        OrderSide::Buy,
+        quantity,
+        price,
+        chrono::DateTime::from_timestamp(1_718_000_000, 0).unwrap(),
+    );
+    execution.apply_fill(fill).unwrap();
+    assert_eq!(execution.status, OrderStatus::Filled);
+
+    let err: paft::Error = paft::orders::OrderError::ZeroQuantity.into();
+    assert!(matches!(err, paft::Error::Orders(_)));
+}
+
 #[cfg(feature = "prediction")]
 #[test]
 fn prediction_exports_are_available_from_facade_and_prelude() {
@@ -360,3 +518,227 @@ fn analysis_helpers_are_available_from_facade_and_prelude() {
         PreludeRevisionPoint::new(Horizon::months(3).unwrap(), 2, 1);
     let _: PreludeRevisionPoint = revision_point;
 }
+
+#[test]
+fn rate_book_exports_are_available_from_facade() {
+    use paft::money::{
+        Currency, ExchangeRate, IsoCurrency, RateBook, RateLeg, RateResolution, ResolvedRate,
+    };
+
+    fn assert_export<T>() {}
+
+    assert_export::<RateLeg>();
+    assert_export::<ResolvedRate>();
+    assert_export::<paft::money::RateHistory>();
+    assert_export::<paft::money::RateObservation>();
+    assert_export::<paft::money::HistoricalRates<'static>>();
+    assert_export::<paft::money::MoneyBag>();
+    assert_export::<&dyn paft::money::RateSource>();
+    assert_eq!(
+        paft::money::AsOfMode::default(),
+        paft::money::AsOfMode::LastKnown
+    );
+    assert_eq!(
+        paft::money::AllocationStrategy::default(),
+        paft::money::AllocationStrategy::LargestRemainder
+    );
+
+    let usd = Currency::Iso(IsoCurrency::USD);
+    let eur = Currency::Iso(IsoCurrency::EUR);
+    let book =
+        RateBook::from_rates([
+            ExchangeRate::new(usd.clone(), eur.clone(), paft::Decimal::from(2)).unwrap(),
+        ])
+        .unwrap();
+    assert_eq!(
+        book.resolve(&eur, &usd).unwrap().method,
+        RateResolution::Inverse
+    );
+}
+
+#[cfg(feature = "money-formatting")]
+#[test]
+fn locale_registration_is_available_from_facade() {
+    use paft::money::{Currency, IsoCurrency, LocalFormat, Locale, Money, register_locale};
+
+    let locale =
+        register_locale("x-facade-test", LocalFormat::new('\'', ',', &[3]).unwrap()).unwrap();
+    assert!(matches!(locale, Locale::Custom(_)));
+    let usd = Money::from_canonical_str("1234.5", Currency::Iso(IsoCurrency::USD)).unwrap();
+    assert_eq!(usd.format_with_locale(locale).unwrap(), "$1'234,50");
+    assert_eq!(Locale::from_tag("pt-BR"), Some(Locale::PtBr));
+}
+
+#[cfg(feature = "money-formatting")]
+#[test]
+fn money_pattern_is_available_from_facade() {
+    use paft::money::{Currency, IsoCurrency, Locale, Money, MoneyPattern};
+
+    let pattern: MoneyPattern = "¤#,##0.00;(¤#,##0.00)".parse().unwrap();
+    let loss = Money::from_canonical_str("-12", Currency::Iso(IsoCurrency::USD)).unwrap();
+    assert_eq!(
+        loss.localized(Locale::EnUs)
+            .with_pattern(&pattern)
+            .into_string()
+            .unwrap(),
+        "($12.00)"
+    );
+}
+
+#[cfg(feature = "money-formatting")]
+#[test]
+fn compact_format_is_available_from_facade() {
+    use paft::money::{CompactFormat, CompactStyle, Currency, IsoCurrency, Locale, Money};
+
+    let cap = Money::from_canonical_str("2900000000000", Currency::Iso(IsoCurrency::USD)).unwrap();
+    let long = CompactFormat::new(Locale::EnUs).with_style(CompactStyle::Long);
+    assert_eq!(cap.format_compact(&long).unwrap(), "$2.9 trillion");
+}
+
+#[cfg(feature = "money-formatting")]
+#[test]
+fn money_parser_is_available_from_facade() {
+    use paft::money::{Currency, IsoCurrency, Money, MoneyParser};
+
+    let parser = MoneyParser::new().with_default_currency(Currency::Iso(IsoCurrency::EUR));
+    assert_eq!(parser.parse("(1.234,50)").unwrap().format(), "-1234.5 EUR");
+    assert_eq!(Money::parse_free_form("USD 12").unwrap().format(), "12 USD");
+}
+
+#[test]
+fn currency_metadata_loading_is_available_from_facade() {
+    use paft::money::{
+        CurrencyMetadataEntry, CurrencyMetadataFile, Locale, MetadataLoadError,
+        export_currency_metadata, load_currency_metadata,
+    };
+
+    let entry = CurrencyMetadataEntry {
+        code: "FXM".to_string(),
+        full_name: "Facade token".to_string(),
+        minor_units: 3,
+        symbol: "F".to_string(),
+        symbol_first: true,
+        default_locale: Locale::EnUs,
+        cash_increment: None,
+    };
+    assert_eq!(
+        load_currency_metadata(std::slice::from_ref(&entry), false).unwrap(),
+        1
+    );
+    assert!(export_currency_metadata().contains(&entry));
+    assert!(
+        CurrencyMetadataFile::effective()
+            .currencies
+            .contains(&entry)
+    );
+    assert!(matches!(
+        load_currency_metadata(&[entry.clone(), entry], false),
+        Err(MetadataLoadError::DuplicateCode { .. })
+    ));
+}
+
+#[test]
+fn currency_registry_is_available_from_facade() {
+    use paft::money::{Currency, CurrencyRegistry, Locale, Money};
+
+    let tenant = CurrencyRegistry::new();
+    tenant
+        .set_metadata("FXR", "Tenant token", 1, "T", true, Locale::EnUs)
+        .unwrap();
+    let money =
+        Money::from_canonical_str_in("2.5", Currency::try_from_str("FXR").unwrap(), &tenant)
+            .unwrap();
+    assert_eq!(money.minor_units(), 1);
+    assert!(CurrencyRegistry::global().metadata("FXR").is_none());
+}
+
+#[test]
+fn historic_currencies_are_available_from_facade() {
+    use chrono::NaiveDate;
+    use paft::money::{Currency, HistoricCurrency, Money};
+
+    let frf = Currency::try_from_str("FRF").unwrap();
+    let historic: HistoricCurrency = frf.historic().unwrap();
+    assert_eq!(historic.successor.code(), "EUR");
+    let balance = Money::from_canonical_str("655.96", frf)
+        .unwrap()
+        .redenominate(NaiveDate::from_ymd_opt(1999, 1, 4).unwrap())
+        .unwrap();
+    assert_eq!(balance.format(), "100 EUR");
+}
+
+#[test]
+fn day_counts_are_available_from_facade() {
+    use chrono::NaiveDate;
+    use paft::money::{Compounding, Currency, DayCount, IsoCurrency, Money};
+    use paft::{Decimal, RoundingStrategy};
+
+    let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+    let end = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+    assert_eq!(DayCount::Thirty360.days(start, end), 360);
+    let principal = Money::from_canonical_str("100", Currency::Iso(IsoCurrency::USD)).unwrap();
+    let interest = principal
+        .compound_interest(
+            &Decimal::from(1),
+            start,
+            end,
+            DayCount::ActActIsda,
+            Compounding::Annual,
+            RoundingStrategy::MidpointAwayFromZero,
+        )
+        .unwrap();
+    assert_eq!(interest.format(), "100 USD");
+}
+
+#[test]
+fn integer_unit_adapters_are_available_from_facade() {
+    use paft::money::{Currency, IsoCurrency, Money};
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Balance(#[serde(with = "paft::money::serde::minor_units")] Money);
+
+    let balance =
+        Balance(Money::from_canonical_str("123.45", Currency::Iso(IsoCurrency::USD)).unwrap());
+    let json = serde_json::to_string(&balance).unwrap();
+    assert_eq!(json, r#"{"minor":12345,"currency":"USD"}"#);
+    let back: Balance = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.0, balance.0);
+}
+
+#[test]
+fn contract_specs_are_available_from_facade() {
+    use paft::PositiveDecimal;
+    use paft::money::{
+        ContractSpec, Currency, IsoCurrency, Price, Quantity, QuantityAmount, QuantityUnit,
+    };
+
+    let spec = ContractSpec::new(PositiveDecimal::new(100.into()).unwrap());
+    let quantity = Quantity::new(
+        QuantityAmount::from_decimal(2.into()).unwrap(),
+        QuantityUnit::Contracts,
+    );
+    let price = Price::from_canonical_str("1.5", Currency::Iso(IsoCurrency::USD)).unwrap();
+    assert_eq!(
+        price.try_notional(&quantity, &spec).unwrap().format(),
+        "300 USD"
+    );
+}
+
+#[cfg(feature = "market")]
+#[test]
+fn tick_size_tables_are_available_from_facade() {
+    use paft::market::{MarketError, TickSizeTable};
+    use paft::money::PriceAmount;
+
+    let table = TickSizeTable::us_sub_penny();
+    let price = PriceAmount::new("1.005".parse().unwrap());
+    assert!(!table.is_on_tick(&price));
+    assert_eq!(
+        table.snap(&price, paft::RoundingStrategy::ToZero).unwrap(),
+        PriceAmount::new("1".parse().unwrap())
+    );
+    assert_eq!(
+        TickSizeTable::mifid(9),
+        Err(MarketError::InvalidLiquidityBand(9))
+    );
+}
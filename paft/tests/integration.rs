@@ -369,3 +369,32 @@ fn action_types_workflow() {
         assert_eq!(history_response, deserialized);
     }
 }
+
+#[cfg(feature = "fundamentals")]
+#[test]
+fn candles_join_point_in_time_fundamentals() {
+    use paft::fundamentals::{IncomeStatementRow, as_of_join};
+    use paft::prelude::{Money, ReportingPeriod};
+
+    let day = |d: u32| date(2024, 2, d).and_hms_opt(21, 0, 0).unwrap().and_utc();
+    let candles: Vec<Candle> = (1..=3)
+        .map(|d| Candle::new(day(d), usd(), ohlc("100", "101", "99", "100")))
+        .collect();
+    let q4 = IncomeStatementRow {
+        period: ReportingPeriod::quarterly(2023, 4).unwrap(),
+        // Filed after the close on Feb 1.
+        published_at: Some(date(2024, 2, 1).and_hms_opt(21, 30, 0).unwrap().and_utc()),
+        total_revenue: Some(Money::new(Decimal::from(1_000), usd()).unwrap()),
+        gross_profit: None,
+        operating_income: None,
+        net_income: None,
+        interest_expense: None,
+        income_tax_expense: None,
+        depreciation_and_amortization: None,
+    };
+    let rows = [q4];
+
+    let joined = as_of_join(candles.iter().map(|candle| candle.ts), &rows);
+    let known: Vec<_> = joined.iter().map(|entry| entry.row.is_some()).collect();
+    assert_eq!(known, vec![false, true, true]);
+}
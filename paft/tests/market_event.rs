@@ -0,0 +1,225 @@
+#![cfg(feature = "market")]
+
+use chrono::{DateTime, NaiveDate, Utc};
+use paft::Decimal;
+use paft::event::{MarketEvent, MarketEventEnvelope, SequenceCheck, SequenceGapDetector};
+use paft::market::{
+    BookLevel, Candle, CandleUpdate, Interval, Ohlc, OptionContractKey, OptionSide, OptionUpdate,
+    OrderBook, QuoteUpdate,
+};
+use paft::prelude::{AssetKind, Currency, Instrument, IsoCurrency, Price, PriceAmount};
+
+const fn usd() -> Currency {
+    Currency::Iso(IsoCurrency::USD)
+}
+
+const fn ts(millis: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp_millis(millis).unwrap()
+}
+
+fn amount(value: i64) -> PriceAmount {
+    PriceAmount::new(Decimal::from(value))
+}
+
+fn instrument(symbol: &str) -> Instrument {
+    Instrument::from_symbol(symbol, AssetKind::Equity).unwrap()
+}
+
+fn quote_update(symbol: &str) -> QuoteUpdate {
+    let mut update = QuoteUpdate::new(instrument(symbol), usd(), ts(1_700_000_000_000));
+    update.price = Some(amount(150));
+    update
+}
+
+fn option_key() -> OptionContractKey {
+    OptionContractKey::new(
+        instrument("AAPL"),
+        OptionSide::Call,
+        Price::new(Decimal::from(150), usd()),
+        NaiveDate::from_ymd_opt(2024, 6, 21).unwrap(),
+    )
+}
+
+fn market_events() -> Vec<MarketEvent> {
+    let candle = Candle::new(
+        ts(1_700_000_000_000),
+        usd(),
+        Ohlc::new(amount(100), amount(110), amount(95), amount(105)),
+    );
+    let mut option = OptionUpdate::new(option_key(), usd(), ts(1_700_000_000_500));
+    option.bid = Some(amount(3));
+    let mut book = OrderBook::new(instrument("AAPL"), usd());
+    book.bids = vec![BookLevel::new(amount(149), None)];
+    book.asks = vec![BookLevel::new(amount(151), None)];
+
+    vec![
+        quote_update("AAPL").into(),
+        CandleUpdate::new(instrument("AAPL"), Interval::I1m, candle, true).into(),
+        option.into(),
+        book.into(),
+    ]
+}
+
+#[test]
+fn envelopes_roundtrip_for_every_market_event() {
+    for (sequence, event) in (1..).zip(market_events()) {
+        let envelope: MarketEventEnvelope =
+            MarketEventEnvelope::new(sequence, "feed-a", ts(1_700_000_001_000), event);
+        let json = serde_json::to_string(&envelope).unwrap();
+        let back: MarketEventEnvelope = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, envelope);
+    }
+}
+
+#[test]
+fn envelope_wire_format_is_adjacently_tagged() {
+    let envelope: MarketEventEnvelope =
+        MarketEventEnvelope::new(7, "feed-a", ts(1_700_000_001_234), quote_update("AAPL"));
+    let json = serde_json::to_value(&envelope).unwrap();
+
+    assert_eq!(json["sequence"], 7);
+    assert_eq!(json["source"], "feed-a");
+    assert_eq!(json["received_at"], 1_700_000_001_234_i64);
+    assert_eq!(json["event"]["kind"], "quote_update");
+    assert_eq!(json["event"]["data"]["ts"], 1_700_000_000_000_i64);
+    assert_eq!(envelope.event.kind(), "quote_update");
+
+    let kinds: Vec<_> = market_events().iter().map(MarketEvent::kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            "quote_update",
+            "candle_update",
+            "option_update",
+            "order_book"
+        ]
+    );
+}
+
+#[test]
+fn unknown_event_kind_is_rejected() {
+    let json =
+        r#"{"sequence":1,"source":"feed-a","received_at":0,"event":{"kind":"trade","data":{}}}"#;
+    assert!(serde_json::from_str::<MarketEventEnvelope>(json).is_err());
+}
+
+#[test]
+fn instrument_keys_identify_the_payload_instrument() {
+    let events = market_events();
+    let aapl = instrument("AAPL").unique_key();
+    assert_eq!(events[0].instrument_key(), aapl);
+    assert_eq!(events[1].instrument_key(), aapl);
+    assert_eq!(events[3].instrument_key(), aapl);
+    assert_eq!(
+        events[2].instrument_key(),
+        format!("OPTION|{aapl}|2024-06-21|CALL|150 USD")
+    );
+
+    let mut key = option_key();
+    key.contract_instrument =
+        Some(Instrument::from_symbol("AAPL240621C00150000", AssetKind::Option).unwrap());
+    let event = MarketEvent::from(OptionUpdate::new(key, usd(), ts(0)));
+    assert_eq!(
+        event.instrument_key(),
+        "OPTION|SYMBOL|19:AAPL240621C00150000"
+    );
+
+    assert_eq!(events[0].event_ts(), Some(ts(1_700_000_000_000)));
+    assert_eq!(events[3].event_ts(), None);
+}
+
+#[test]
+fn gap_detector_tracks_each_source_and_instrument() {
+    let mut detector = SequenceGapDetector::new();
+    let aapl = instrument("AAPL").unique_key();
+    let msft = instrument("MSFT").unique_key();
+
+    assert_eq!(detector.observe("a", &aapl, 10), SequenceCheck::First);
+    assert_eq!(detector.observe("a", &aapl, 11), SequenceCheck::Contiguous);
+    assert_eq!(detector.observe("a", &msft, 1), SequenceCheck::First);
+    assert_eq!(detector.observe("b", &aapl, 500), SequenceCheck::First);
+
+    let gap = detector.observe("a", &aapl, 14);
+    assert_eq!(
+        gap,
+        SequenceCheck::Gap {
+            expected: 12,
+            received: 14
+        }
+    );
+    assert_eq!(gap.missing(), 2);
+    assert!(!gap.is_in_order());
+    assert_eq!(detector.observe("a", &aapl, 15), SequenceCheck::Contiguous);
+
+    let stale = detector.observe("a", &aapl, 12);
+    assert_eq!(
+        stale,
+        SequenceCheck::Stale {
+            last: 15,
+            received: 12
+        }
+    );
+    assert_eq!(stale.missing(), 0);
+    assert_eq!(detector.last_sequence("a", &aapl), Some(15));
+    assert_eq!(
+        detector.observe("a", &aapl, 15),
+        SequenceCheck::Stale {
+            last: 15,
+            received: 15
+        }
+    );
+
+    detector.reset("a", &aapl);
+    assert_eq!(detector.last_sequence("a", &aapl), None);
+    assert_eq!(detector.observe("a", &aapl, 1), SequenceCheck::First);
+    assert_eq!(detector.last_sequence("a", &msft), Some(1));
+    detector.clear();
+    assert_eq!(detector.last_sequence("a", &msft), None);
+}
+
+#[test]
+fn gap_detector_observes_envelopes() {
+    let mut detector = SequenceGapDetector::new();
+    let envelope = |sequence, symbol| -> MarketEventEnvelope {
+        MarketEventEnvelope::new(sequence, "feed-a", ts(0), quote_update(symbol))
+    };
+
+    assert!(detector.observe_event(&envelope(1, "AAPL")).is_in_order());
+    assert!(detector.observe_event(&envelope(1, "MSFT")).is_in_order());
+    assert!(detector.observe_event(&envelope(2, "AAPL")).is_in_order());
+    assert_eq!(detector.observe_event(&envelope(5, "MSFT")).missing(), 3);
+    assert_eq!(
+        detector.observe("feed-a", &u64::MAX.to_string(), u64::MAX),
+        SequenceCheck::First
+    );
+    assert!(matches!(
+        detector.observe("feed-a", &u64::MAX.to_string(), u64::MAX),
+        SequenceCheck::Stale { .. }
+    ));
+}
+
+#[cfg(feature = "prediction")]
+#[test]
+fn prediction_trade_envelope_roundtrip() {
+    use paft::prediction::{
+        NonZeroContractQuantity, OutcomeInstrument, OutcomePrice, PredictionTrade,
+    };
+
+    let trade = PredictionTrade {
+        instrument: OutcomeInstrument::new("POLYMARKET", "condition-1", "yes-token").unwrap(),
+        price: OutcomePrice::from_micros(410_000).unwrap(),
+        quantity: NonZeroContractQuantity::from_microcontracts(2_000_000).unwrap(),
+        action: None,
+        trade_id: Some("t-1".to_string()),
+        ts: ts(1_700_000_000_000),
+        provider: (),
+    };
+    let key = trade.instrument.unique_key();
+    let envelope: MarketEventEnvelope = MarketEventEnvelope::new(3, "feed-p", ts(0), trade);
+
+    let json = serde_json::to_value(&envelope).unwrap();
+    assert_eq!(json["event"]["kind"], "prediction_trade");
+    let back: MarketEventEnvelope = serde_json::from_value(json).unwrap();
+    assert_eq!(back, envelope);
+    assert_eq!(back.event.instrument_key(), key);
+}